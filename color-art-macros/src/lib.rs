@@ -0,0 +1,126 @@
+//! Compile-time checked color literals for [color-art](https://docs.rs/color-art).
+//!
+//! The [`color!`] macro parses its string at compile time and expands to a const
+//! [`Color`](https://docs.rs/color-art/latest/color_art/struct.Color.html).
+
+use color_art::{Color, ColorSpace, Error, Span};
+use proc_macro::TokenStream;
+use quote::quote;
+use std::{ops::Range, str::FromStr};
+use syn::{parse_macro_input, LitStr};
+
+/// Parses a color string at compile time and expands to a const `Color`.
+///
+/// Every string form supported by `Color::from_str` is accepted, e.g. hex colors, color names
+/// and the color space functions. An invalid color fails the build with an error that names
+/// the bad part of the string and its byte range, and points at it where the compiler allows.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::Color;
+/// use color_art_macros::color;
+///
+/// const PRIMARY: Color = color!("#1890ff");
+/// const ACCENT: Color = color!("hsla(330, 100%, 60%, 0.5)");
+///
+/// assert_eq!(PRIMARY.hex(), "#1890ff");
+/// assert_eq!(ACCENT.rgba(), "rgba(255, 51, 153, 0.5)");
+/// assert_eq!(color!("deeppink").hex(), "#ff1493");
+/// ```
+///
+/// ```compile_fail
+/// use color_art_macros::color;
+///
+/// let color = color!("#ff339");
+/// ```
+///
+/// ```compile_fail
+/// use color_art_macros::color;
+///
+/// let color = color!("rgb(256, 0, 0)");
+/// ```
+#[proc_macro]
+pub fn color(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+
+    let value = literal.value();
+    let color = match Color::from_str(&value) {
+        Ok(color) => color,
+        Err(err) => {
+            let span = err
+                .span()
+                .and_then(|span| source_range(&literal.token().to_string(), &value, span))
+                .and_then(|range| literal.token().subspan(range))
+                .unwrap_or_else(|| literal.span());
+            return syn::Error::new(span, error_message(&value, &err))
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let [r, g, b, alpha] = color.vec_of(ColorSpace::RGBA)[..] else {
+        unreachable!("RGBA has 4 values")
+    };
+    quote! {
+        ::color_art::Color::<f64>::new_const(#r, #g, #b, #alpha)
+    }
+    .into()
+}
+
+/// The message of the compile error, quoting the part of `value` the error points at.
+fn error_message(value: &str, err: &Error) -> String {
+    match err.span().and_then(|span| value.get(span.range())) {
+        Some(text) if !text.is_empty() => format!("invalid color: {} (`{}`)", err, text),
+        _ => format!("invalid color: {}", err),
+    }
+}
+
+/// The byte range of `span` of the string `value` in `source`, the source code of its literal.
+///
+/// Only plain strings without escapes and raw strings map byte for byte to their value.
+fn source_range(source: &str, value: &str, span: Span) -> Option<Range<usize>> {
+    let (open, close) = match source.strip_prefix('r') {
+        Some(raw) => {
+            let hashes = raw.len() - raw.trim_start_matches('#').len();
+            (hashes + 2, hashes + 1)
+        }
+        None => (1, 1),
+    };
+    let inner = source.get(open..source.len().checked_sub(close)?)?;
+    (inner == value && span.end <= value.len()).then(|| span.start + open..span.end + open)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_message() {
+        let value = "rgb(255, 300, 0)";
+        let err = Color::from_str(value).unwrap_err();
+        assert_eq!(
+            error_message(value, &err),
+            "invalid color: Green must be between 0 and 255, got 300 at 9..12 (`300`)"
+        );
+
+        let err = Error::ColorParserError("Invalid value".into());
+        assert_eq!(error_message("", &err), "invalid color: Invalid value");
+    }
+
+    #[test]
+    fn test_source_range() {
+        let span = Span::new(9, 12);
+        let value = "rgb(255, 300, 0)";
+        assert_eq!(
+            source_range("\"rgb(255, 300, 0)\"", value, span),
+            Some(10..13)
+        );
+        assert_eq!(
+            source_range("r#\"rgb(255, 300, 0)\"#", value, span),
+            Some(12..15)
+        );
+        // escapes do not map byte for byte
+        assert_eq!(source_range("\"\\x72gb(255, 300, 0)\"", value, span), None);
+    }
+}
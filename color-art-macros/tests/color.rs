@@ -0,0 +1,45 @@
+use color_art::{Color, ColorSpace};
+use color_art_macros::color;
+use std::str::FromStr;
+
+const RED: Color = color!("#f00");
+
+#[test]
+fn test_const_color() {
+    assert_eq!(RED, Color::new(255.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_color_forms() {
+    let inputs = [
+        "#1890ff33",
+        "rebeccapurple",
+        "水绿",
+        "rgb(255, 51, 153)",
+        "rgba(255, 51, 153, 0.5)",
+        "hsl(330, 100%, 60%)",
+        "hsv(330, 80%, 100%)",
+        "hwb(330, 20%, 0%)",
+        "cmyk(0, 0.8, 0.4, 0)",
+        "lab(60, 90, -90)",
+    ];
+    let colors = [
+        color!("#1890ff33"),
+        color!("rebeccapurple"),
+        color!("水绿"),
+        color!("rgb(255, 51, 153)"),
+        color!("rgba(255, 51, 153, 0.5)"),
+        color!("hsl(330, 100%, 60%)"),
+        color!("hsv(330, 80%, 100%)"),
+        color!("hwb(330, 20%, 0%)"),
+        color!("cmyk(0, 0.8, 0.4, 0)"),
+        color!("lab(60, 90, -90)"),
+    ];
+    for (input, color) in inputs.iter().zip(colors) {
+        let expected = Color::from_str(input).unwrap();
+        assert_eq!(
+            color.vec_of(ColorSpace::RGBA),
+            expected.vec_of(ColorSpace::RGBA)
+        );
+    }
+}
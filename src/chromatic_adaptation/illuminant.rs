@@ -0,0 +1,64 @@
+use super::white_point;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Standard illuminant enum.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Illuminant {
+    /// Incandescent / tungsten light.
+    A,
+    /// Average / north sky daylight.
+    C,
+    /// Horizon light, the ICC profile connection space white.
+    D50,
+    /// Mid-morning / mid-afternoon daylight.
+    D55,
+    /// Noon daylight, the white of sRGB.
+    D65,
+    /// North sky daylight.
+    D75,
+    /// Equal energy.
+    E,
+    /// Cool white fluorescent.
+    F2,
+    /// Broad-band daylight fluorescent.
+    F7,
+    /// Narrow tri-band fluorescent.
+    F11,
+    /// Custom white point as XYZ tristimulus values.
+    Custom([f64; 3]),
+}
+
+impl Illuminant {
+    /// Creates a custom illuminant from its x,y chromaticity coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::chromatic_adaptation::Illuminant;
+    ///
+    /// let illuminant = Illuminant::from_xy(0.3127, 0.329);
+    /// assert_eq!(illuminant.white_point(), Illuminant::D65.white_point());
+    /// ```
+    pub fn from_xy(x: f64, y: f64) -> Self {
+        Illuminant::Custom([x / y, 1.0, (1.0 - x - y) / y])
+    }
+    /// The XYZ white point of the illuminant, normalized to `Y = 1.0`.
+    pub fn white_point(&self) -> [f64; 3] {
+        match self {
+            Illuminant::A => white_point::A,
+            Illuminant::C => white_point::C,
+            Illuminant::D50 => white_point::D50,
+            Illuminant::D55 => white_point::D55,
+            Illuminant::D65 => white_point::D65,
+            Illuminant::D75 => white_point::D75,
+            Illuminant::E => white_point::E,
+            Illuminant::F2 => white_point::F2,
+            Illuminant::F7 => white_point::F7,
+            Illuminant::F11 => white_point::F11,
+            Illuminant::Custom(xyz) => *xyz,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chromatic adaptation transform enum.
+///
+/// Each method converts XYZ into a cone response space, where the white points are scaled,
+/// see [Bruce Lindbloom](http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html).
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AdaptationMethod {
+    /// Bradford transform, used by ICC profiles and CSS Color 4.
+    #[default]
+    Bradford,
+    /// CIECAM02 transform.
+    Cat02,
+    /// CIECAM16 transform.
+    Cat16,
+    /// Von Kries transform with the Hunt-Pointer-Estevez cone response.
+    VonKries,
+    /// Scaling of the XYZ values, the simplest and least accurate transform.
+    XyzScaling,
+}
+
+impl AdaptationMethod {
+    /// The cone response matrix of the method.
+    pub fn cone_response(&self) -> [[f64; 3]; 3] {
+        match self {
+            AdaptationMethod::Bradford => [
+                [0.8951, 0.2664, -0.1614],
+                [-0.7502, 1.7135, 0.0367],
+                [0.0389, -0.0685, 1.0296],
+            ],
+            AdaptationMethod::Cat02 => [
+                [0.7328, 0.4296, -0.1624],
+                [-0.7036, 1.6975, 0.0061],
+                [0.0030, 0.0136, 0.9834],
+            ],
+            AdaptationMethod::Cat16 => [
+                [0.401288, 0.650173, -0.051461],
+                [-0.250268, 1.204414, 0.045854],
+                [-0.002079, 0.048952, 0.953127],
+            ],
+            AdaptationMethod::VonKries => [
+                [0.40024, 0.7076, -0.08081],
+                [-0.2263, 1.16532, 0.0457],
+                [0.0, 0.0, 0.91822],
+            ],
+            AdaptationMethod::XyzScaling => [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+}
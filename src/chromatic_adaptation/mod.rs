@@ -0,0 +1,147 @@
+//! Chromatic adaptation of CIE XYZ colors between illuminants.
+//!
+//! # Examples
+//!
+//! ```
+//! use color_art::chromatic_adaptation::{adapt, AdaptationMethod, Illuminant};
+//!
+//! let xyz = [0.4123907992659595, 0.21263900587151036, 0.01933081871559185];
+//! let xyz = adapt(xyz, Illuminant::D65, Illuminant::D50, AdaptationMethod::Bradford);
+//! assert!((xyz[0] - 0.436065742824811).abs() < 1e-6);
+//! ```
+
+mod illuminant;
+mod method;
+pub mod white_point;
+
+pub use illuminant::Illuminant;
+pub use method::AdaptationMethod;
+
+use crate::utils::{invert_matrix3, multiply_matrix3, transform3};
+
+/// Computes the 3x3 matrix that adapts XYZ colors from one illuminant to another.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::chromatic_adaptation::{adaptation_matrix, AdaptationMethod, Illuminant};
+///
+/// let m = adaptation_matrix(Illuminant::D65, Illuminant::D65, AdaptationMethod::Cat16);
+/// assert!((m[0][0] - 1.0).abs() < 1e-12);
+/// assert!(m[0][1].abs() < 1e-12);
+/// ```
+pub fn adaptation_matrix(
+    from: Illuminant,
+    to: Illuminant,
+    method: AdaptationMethod,
+) -> [[f64; 3]; 3] {
+    let cone = method.cone_response();
+    let cone_inv = invert_matrix3(cone);
+
+    let source = transform3(cone, from.white_point());
+    let destination = transform3(cone, to.white_point());
+
+    let scale = [
+        [destination[0] / source[0], 0.0, 0.0],
+        [0.0, destination[1] / source[1], 0.0],
+        [0.0, 0.0, destination[2] / source[2]],
+    ];
+
+    multiply_matrix3(cone_inv, multiply_matrix3(scale, cone))
+}
+
+/// Adapts a CIE XYZ color seen under the `from` illuminant to the `to` illuminant.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::chromatic_adaptation::{adapt, white_point, AdaptationMethod, Illuminant};
+///
+/// // the white of the source illuminant becomes the white of the destination
+/// let xyz = adapt(white_point::D65, Illuminant::D65, Illuminant::A, AdaptationMethod::Cat02);
+/// assert!((xyz[0] - white_point::A[0]).abs() < 1e-12);
+/// assert!((xyz[2] - white_point::A[2]).abs() < 1e-12);
+/// ```
+pub fn adapt(
+    xyz: [f64; 3],
+    from: Illuminant,
+    to: Illuminant,
+    method: AdaptationMethod,
+) -> [f64; 3] {
+    if from == to {
+        return xyz;
+    }
+    transform3(adaptation_matrix(from, to, method), xyz)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_close(a: [f64; 3], b: [f64; 3]) -> bool {
+        a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-6)
+    }
+
+    #[test]
+    fn test_bradford_d65_to_d50() {
+        // the D65 to D50 Bradford matrix of CSS Color 4
+        let m = adaptation_matrix(Illuminant::D65, Illuminant::D50, AdaptationMethod::Bradford);
+        let expected = [
+            [
+                1.0479298208405488,
+                0.022946793341019088,
+                -0.05019222954313557,
+            ],
+            [
+                0.029627815688159344,
+                0.990434484573249,
+                -0.01707382502938514,
+            ],
+            [
+                -0.009243058152591178,
+                0.015055144896577895,
+                0.7518742899580008,
+            ],
+        ];
+        for i in 0..3 {
+            assert!(is_close(m[i], expected[i]));
+        }
+    }
+
+    #[test]
+    fn test_adapt_white_point() {
+        let methods = [
+            AdaptationMethod::Bradford,
+            AdaptationMethod::Cat02,
+            AdaptationMethod::Cat16,
+            AdaptationMethod::VonKries,
+            AdaptationMethod::XyzScaling,
+        ];
+        for method in methods {
+            let xyz = adapt(white_point::F11, Illuminant::F11, Illuminant::D75, method);
+            assert!(is_close(xyz, white_point::D75));
+
+            let custom = Illuminant::from_xy(0.3, 0.3);
+            let xyz = adapt(white_point::E, Illuminant::E, custom, method);
+            assert!(is_close(xyz, custom.white_point()));
+        }
+    }
+
+    #[test]
+    fn test_adapt_round_trip() {
+        let xyz = [0.2166, 0.146, 0.59437];
+        let d50 = adapt(
+            xyz,
+            Illuminant::D65,
+            Illuminant::D50,
+            AdaptationMethod::Cat16,
+        );
+        let d65 = adapt(
+            d50,
+            Illuminant::D50,
+            Illuminant::D65,
+            AdaptationMethod::Cat16,
+        );
+        assert!(is_close(xyz, d65));
+    }
+}
@@ -0,0 +1,25 @@
+//! Standard illuminant white points.
+//!
+//! CIE XYZ tristimulus values of the CIE 1931 2° standard observer,
+//! normalized to `Y = 1.0`, derived from the x,y chromaticities of each illuminant.
+
+/// Incandescent / tungsten light, 2856 K.
+pub const A: [f64; 3] = [0.44757 / 0.40745, 1.0, (1.0 - 0.44757 - 0.40745) / 0.40745];
+/// Average / north sky daylight, 6774 K (obsolete, superseded by D65).
+pub const C: [f64; 3] = [0.31006 / 0.31616, 1.0, (1.0 - 0.31006 - 0.31616) / 0.31616];
+/// Horizon light, 5003 K, the ICC profile connection space white.
+pub const D50: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+/// Mid-morning / mid-afternoon daylight, 5503 K.
+pub const D55: [f64; 3] = [0.33242 / 0.34743, 1.0, (1.0 - 0.33242 - 0.34743) / 0.34743];
+/// Noon daylight, 6504 K, the white of sRGB, Display P3 and Rec. 2020.
+pub const D65: [f64; 3] = [0.3127 / 0.329, 1.0, (1.0 - 0.3127 - 0.329) / 0.329];
+/// North sky daylight, 7504 K.
+pub const D75: [f64; 3] = [0.29902 / 0.31485, 1.0, (1.0 - 0.29902 - 0.31485) / 0.31485];
+/// Equal energy illuminant.
+pub const E: [f64; 3] = [1.0, 1.0, 1.0];
+/// Cool white fluorescent, 4230 K.
+pub const F2: [f64; 3] = [0.37208 / 0.37529, 1.0, (1.0 - 0.37208 - 0.37529) / 0.37529];
+/// Broad-band daylight fluorescent, 6500 K.
+pub const F7: [f64; 3] = [0.31292 / 0.32933, 1.0, (1.0 - 0.31292 - 0.32933) / 0.32933];
+/// Narrow tri-band fluorescent, 4000 K.
+pub const F11: [f64; 3] = [0.38052 / 0.37713, 1.0, (1.0 - 0.38052 - 0.37713) / 0.37713];
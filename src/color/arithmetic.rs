@@ -0,0 +1,142 @@
+//! Arithmetic operators on [`Color`].
+//!
+//! The operators work component-wise on the stored gamma encoded sRGB channels (`0.0..=255.0`)
+//! and the alpha (`0.0..=1.0`). The results are not clamped, sums and differences may leave
+//! the sRGB gamut, call [`Color::clamp`] to clip them. The alpha is combined like the channels,
+//! so that e.g. an average keeps the mean alpha, and the string methods clamp it to `0.0..=1.0`.
+//!
+//! Multiplying two colors multiplies the normalized channels, i.e. white is the identity
+//! and black the zero, like the `multiply` blend mode.
+//!
+//! Gamma encoded values are not proportional to light, e.g. adding two gray lamps with these
+//! operators overshoots. Convert to [`LinearRgb`](crate::LinearRgb) with [`Color::to_linear`]
+//! for physically correct light arithmetic.
+//!
+//! # Examples
+//!
+//! ```
+//! use color_art::Color;
+//!
+//! let color = Color::new(100.0, 50.0, 0.0, 0.5) + Color::new(200.0, 50.0, 0.0, 0.5);
+//! assert_eq!(color, Color::new(300.0, 100.0, 0.0, 1.0));
+//! assert_eq!(color.clamp().hex(), "#ff6400");
+//!
+//! let colors = [Color::new(255.0, 0.0, 0.0, 1.0), Color::new(0.0, 0.0, 255.0, 0.5)];
+//! let average = colors.into_iter().sum::<Color>() / 2.0;
+//! assert_eq!(average, Color::new(127.5, 0.0, 127.5, 0.75));
+//! assert_eq!(average * 2.0, 2.0 * average);
+//! ```
+
+use super::channel_ops::impl_channel_ops;
+use crate::{
+    utils::{lit, Float},
+    Color,
+};
+use core::ops::{Mul, MulAssign};
+
+impl_channel_ops!(Color);
+
+/// Multiplies the normalized channels and the alphas.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{color, Color};
+///
+/// let light = Color::new(255.0, 204.0, 102.0, 1.0);
+/// assert_eq!(color!(#fff).unwrap() * light, light);
+/// assert_eq!(color!(#808080).unwrap() * light, Color::new(128.0, 102.4, 51.2, 1.0));
+/// ```
+impl<T: Float> Mul for Color<T> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let rgb = self.zip_with(rhs, |a, b| a * b / lit(255.0)).rgb;
+        Color {
+            rgb,
+            alpha: self.alpha * rhs.alpha,
+        }
+    }
+}
+
+impl<T: Float> MulAssign for Color<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_add_sub() {
+        let a = Color::new(100.0, 150.0, 200.0, 0.5);
+        let b = Color::new(50.0, 150.0, 100.0, 0.25);
+        assert_eq!(a + b, Color::new(150.0, 300.0, 300.0, 0.75));
+        assert_eq!(a - b, Color::new(50.0, 0.0, 100.0, 0.25));
+        assert_eq!(b - a, Color::new(-50.0, 0.0, -100.0, -0.25));
+        assert!(!(a + b).is_in_gamut());
+
+        let mut c = a;
+        c += b;
+        c -= b;
+        assert_eq!(c, a);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_overflowed_strings() {
+        let a = Color::new(255.0, 0.0, 0.0, 0.8);
+        let sum = a + a;
+        assert_eq!(sum.alpha, 1.6);
+        assert_eq!(sum.hex(), "#f00");
+        assert_eq!(sum.hex_full(), "#ff0000");
+        assert_eq!(sum.rgba(), "rgba(255, 0, 0, 1)");
+
+        let sum = a + Color::new(0.0, 0.0, 0.0, 0.8);
+        assert_eq!(sum.hsla(), "hsla(0, 100%, 50%, 1)");
+        assert_eq!(sum.name(), "red");
+        assert_eq!(sum.css_color(PredefinedSpace::Srgb), "color(srgb 1 0 0)");
+
+        let difference = Color::new(0.0, 0.0, 0.0, 0.2) - Color::new(0.0, 0.0, 0.0, 0.5);
+        assert_eq!(difference.hex(), "#0000");
+        assert_eq!(difference.rgba(), "rgba(0, 0, 0, 0)");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_mul_div() {
+        let a = Color::new(100.0, 150.0, 200.0, 0.5);
+        assert_eq!(a * 2.0, Color::new(200.0, 300.0, 400.0, 1.0));
+        assert_eq!(a / 2.0, Color::new(50.0, 75.0, 100.0, 0.25));
+        assert_eq!(a * color!(#000).unwrap(), Color::new(0.0, 0.0, 0.0, 0.5));
+        assert_eq!(a * color!(#fff).unwrap(), a);
+
+        let mut c = a;
+        c *= 2.0_f64;
+        c /= 2.0;
+        c *= color!(#fff).unwrap();
+        assert_eq!(c, a);
+
+        let c: Color<f32> = Color::new(100.0, 150.0, 200.0, 0.5);
+        assert_eq!(0.5_f32 * c, Color::new(50.0, 75.0, 100.0, 0.25));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sum() {
+        let colors = [
+            color!(#f00).unwrap(),
+            color!(#0f0).unwrap(),
+            color!(#00f).unwrap(),
+        ];
+        let average = colors.iter().copied().sum::<Color>() / 3.0;
+        assert_eq!(average, Color::new(85.0, 85.0, 85.0, 1.0));
+        assert_eq!(
+            core::iter::empty::<Color>().sum::<Color>(),
+            Color::new(0, 0, 0, 0.0)
+        );
+    }
+}
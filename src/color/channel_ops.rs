@@ -0,0 +1,107 @@
+//! The component-wise operators shared by the color structs.
+
+/// Implements the component-wise operators of a color struct with `rgb: [T; 3]` and `alpha: T`
+/// fields, i.e. [`Color`](crate::Color), [`LinearRgb`](crate::LinearRgb) and
+/// [`PremultipliedColor`](crate::PremultipliedColor).
+///
+/// The channels and the alpha are added, subtracted, scaled and divided by a `T`, and summed up
+/// starting from a transparent black. The product of two colors differs between the structs,
+/// each of them implements its own `Mul` with the `zip_with` helper.
+macro_rules! impl_channel_ops {
+    ($name:ident) => {
+        impl<T: $crate::utils::Float> $name<T> {
+            fn zip_with(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+                let [r1, g1, b1] = self.rgb;
+                let [r2, g2, b2] = other.rgb;
+                $name {
+                    rgb: [f(r1, r2), f(g1, g2), f(b1, b2)],
+                    alpha: f(self.alpha, other.alpha),
+                }
+            }
+            fn map_with(self, f: impl Fn(T) -> T) -> Self {
+                $name {
+                    rgb: self.rgb.map(&f),
+                    alpha: f(self.alpha),
+                }
+            }
+        }
+
+        /// Adds the channels and the alphas.
+        impl<T: $crate::utils::Float> ::core::ops::Add for $name<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                self.zip_with(rhs, |a, b| a + b)
+            }
+        }
+
+        /// Subtracts the channels and the alphas.
+        impl<T: $crate::utils::Float> ::core::ops::Sub for $name<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.zip_with(rhs, |a, b| a - b)
+            }
+        }
+
+        /// Scales the channels and the alpha.
+        impl<T: $crate::utils::Float> ::core::ops::Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                self.map_with(|v| v * rhs)
+            }
+        }
+
+        /// Divides the channels and the alpha.
+        impl<T: $crate::utils::Float> ::core::ops::Div<T> for $name<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self::Output {
+                self.map_with(|v| v / rhs)
+            }
+        }
+
+        $crate::color::channel_ops::impl_channel_ops!(@scalar $name, f32, f64);
+
+        $crate::color::channel_ops::impl_channel_ops!(@assign $name;
+            AddAssign, add_assign, +, $name<T>;
+            SubAssign, sub_assign, -, $name<T>;
+            MulAssign, mul_assign, *, T;
+            DivAssign, div_assign, /, T
+        );
+
+        /// Adds up the colors, starting from a transparent black.
+        impl<T: $crate::utils::Float> ::core::iter::Sum for $name<T> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                let zero = $name {
+                    rgb: [T::ZERO; 3],
+                    alpha: T::ZERO,
+                };
+                iter.fold(zero, ::core::ops::Add::add)
+            }
+        }
+    };
+    (@scalar $name:ident, $($t:ty),*) => {
+        $(
+            impl ::core::ops::Mul<$name<$t>> for $t {
+                type Output = $name<$t>;
+
+                fn mul(self, rhs: $name<$t>) -> Self::Output {
+                    rhs * self
+                }
+            }
+        )*
+    };
+    (@assign $name:ident; $($trait:ident, $fn:ident, $op:tt, $rhs:ty);*) => {
+        $(
+            impl<T: $crate::utils::Float> ::core::ops::$trait<$rhs> for $name<T> {
+                fn $fn(&mut self, rhs: $rhs) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_channel_ops;
@@ -8,7 +8,7 @@ use crate::{Color, ColorSpace};
 /// use color_art::{Color, color};
 /// use std::str::FromStr;
 ///
-/// let color = color!(rgba(10, 20, 30, 0.8));
+/// let color = color!(rgba(10, 20, 30, 0.8)).unwrap();
 /// assert_eq!(color.red(), 10);
 /// assert_eq!(color.green(), 20);
 /// assert_eq!(color.blue(), 30);
@@ -38,23 +38,23 @@ impl Color {
     }
     /// Extracts the hue channel of color as a number between 0.0 and 360.0.
     pub fn hue(&self) -> f64 {
-        self.vec_of(ColorSpace::HSL)[0]
+        self.values_of(ColorSpace::HSL)[0]
     }
     /// Extracts the HSL saturation of color as a number between 0.0 and 1.0.
     pub fn saturation(&self) -> f64 {
-        self.vec_of(ColorSpace::HSL)[1]
+        self.values_of(ColorSpace::HSL)[1]
     }
     /// Extracts the HSL lightness of color as a number between 0.0 and 1.0.
     pub fn lightness(&self) -> f64 {
-        self.vec_of(ColorSpace::HSL)[2]
+        self.values_of(ColorSpace::HSL)[2]
     }
     /// Extracts the HWB whiteness of color as a number between 0.0 and 1.0.
     pub fn whiteness(&self) -> f64 {
-        self.vec_of(ColorSpace::HWB)[1]
+        self.values_of(ColorSpace::HWB)[1]
     }
     /// Extracts the HWB blackness of color as a number between 0.0 and 1.0.
     pub fn blackness(&self) -> f64 {
-        self.vec_of(ColorSpace::HWB)[2]
+        self.values_of(ColorSpace::HWB)[2]
     }
     /// Calculates the [relative luminance](https://www.w3.org/TR/2008/REC-WCAG20-20081211/#relativeluminancedef) of color.
     ///
@@ -70,7 +70,7 @@ impl Color {
     ///
     /// same as `luma()`
     pub fn luminance(&self) -> f64 {
-        let color = normalize_color(&self.rgb);
+        let color = normalize_color(self.rgb);
         let r = luminance_x(color[0]);
         let g = luminance_x(color[1]);
         let b = luminance_x(color[2]);
@@ -78,15 +78,15 @@ impl Color {
     }
     /// Extracts the hue channel of color in the HSV color space.
     pub fn hsv_hue(&self) -> f64 {
-        self.vec_of(ColorSpace::HSV)[0]
+        self.values_of(ColorSpace::HSV)[0]
     }
     /// Extracts the saturation channel of color in the HSV color space.
     pub fn hsv_saturation(&self) -> f64 {
-        self.vec_of(ColorSpace::HSV)[1]
+        self.values_of(ColorSpace::HSV)[1]
     }
     /// Extracts the value channel of color in the HSV color space.
     pub fn hsv_value(&self) -> f64 {
-        self.vec_of(ColorSpace::HSV)[2]
+        self.values_of(ColorSpace::HSV)[2]
     }
     /// Calculates the [gray](http://en.wikipedia.org/wiki/Grayscale) value of color.
     pub fn gray(&self) -> f64 {
@@ -103,14 +103,14 @@ fn luminance_x(x: f64) -> f64 {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::*;
     use std::{assert_eq, str::FromStr};
 
     #[test]
     fn test_color_channel() {
-        let color = color!(rgba(10, 20, 30, 0.8));
+        let color = color!(rgba(10, 20, 30, 0.8)).unwrap();
 
         assert_eq!(color.red(), 10);
         assert_eq!(color.green(), 20);
@@ -144,7 +144,7 @@ mod tests {
         assert_eq!(color.hsv_value(), 0.5);
         assert_eq!(color.gray(), 93.90375);
 
-        let color = color!(rgb(100, 200, 30));
+        let color = color!(rgb(100, 200, 30)).unwrap();
 
         assert_eq!(color.luma(), 0.44111615679100963);
         assert_eq!(color.luminance(), 0.44111615679100963);
@@ -1,62 +1,81 @@
 #[macro_export]
 #[doc(hidden)]
 macro_rules! color_args {
-    () => { vec![] };
-    ($arg:expr) => { vec![$arg] };
+    () => { $crate::__private::vec![] };
+    ($arg:expr) => { $crate::__private::vec![$arg] };
     (
         $arg:expr,
         $($rest:tt)*
     ) => {
         {
-            let mut t = vec![$arg];
+            let mut t = $crate::__private::vec![$arg];
             t.append(&mut $crate::color_args!($($rest)*));
             t
         }
     };
 }
 
+/// Creates a color from a hex literal or the values of a color space.
+///
+/// The values are checked like the ones of [`Color::from_rgb`](crate::Color::from_rgb) and
+/// friends, so the macro returns a `Result<Color, Error>`. An unsupported color space fails the
+/// build. For a string checked at compile time, see the `color!` macro of `color-art-macros`.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::color;
+///
+/// let color = color!(#1890ff).unwrap();
+/// assert_eq!(color.hex(), "#1890ff");
+///
+/// let color = color!(rgba(255, 255, 0, 0.5)).unwrap();
+/// assert_eq!(color.rgba(), "rgba(255, 255, 0, 0.5)");
+///
+/// let color = color!(hsl, 60.0, 1.0, 0.5).unwrap();
+/// assert_eq!(color.hex(), "#ff0");
+///
+/// assert!(color!(rgb(256, 0, 0)).is_err());
+/// assert!(color!(#ff339).is_err());
+/// ```
+///
+/// ```compile_fail
+/// use color_art::color;
+///
+/// let color = color!(lab, 50.0, 20.0, 30.0);
+/// ```
 #[macro_export]
 macro_rules! color {
     (#$hex:expr) => {
         {
-            let hex = format!("#{}", stringify!($hex));
-            $crate::Color::from_hex(&hex).unwrap()
+            let hex = $crate::__private::format!("#{}", stringify!($hex));
+            $crate::Color::from_hex(&hex)
         }
     };
     (rgb($r:expr, $g:expr, $b:expr)) => {
-        $crate::Color::from_rgb($r, $g, $b).unwrap()
+        $crate::Color::from_rgb($r, $g, $b)
     };
     (rgba($r:expr, $g:expr, $b:expr, $a:expr)) => {
-        $crate::Color::from_rgba($r, $g, $b, $a).unwrap()
+        $crate::Color::from_rgba($r, $g, $b, $a)
     };
-    (
-        $color_space:ident,
-        $($args:tt)*
-    ) => {
-        {
-            let args = $crate::color_args!($($args)*);
-            match stringify!($color_space).into() {
-                $crate::ColorSpace::RGB => {
-                    let r = args[0] as f64;
-                    let g = args[1] as f64;
-                    let b = args[2] as f64;
-                    $crate::Color::from_rgb(r, g, b).unwrap()
-                }
-                $crate::ColorSpace::HSL => {
-                    let h = args[0] as f64;
-                    let s = args[1] as f64;
-                    let l = args[2] as f64;
-                    $crate::Color::from_hsl(h, s, l).unwrap()
-                },
-                $crate::ColorSpace::HSV => {
-                    let h = args[0] as f64;
-                    let s = args[1] as f64;
-                    let v = args[2] as f64;
-                    $crate::Color::from_hsv(h, s, v).unwrap()
-                }
-                _ => todo!("Add more color spaces to color! macro"),
-            }
-        }
+    (rgb, $r:expr, $g:expr, $b:expr $(,)?) => {
+        $crate::Color::from_rgb($r as f64, $g as f64, $b as f64)
+    };
+    (hsl, $h:expr, $s:expr, $l:expr $(,)?) => {
+        $crate::Color::from_hsl($h as f64, $s as f64, $l as f64)
+    };
+    (hsv, $h:expr, $s:expr, $v:expr $(,)?) => {
+        $crate::Color::from_hsv($h as f64, $s as f64, $v as f64)
+    };
+    (RGB, $($args:tt)*) => { $crate::color!(rgb, $($args)*) };
+    (HSL, $($args:tt)*) => { $crate::color!(hsl, $($args)*) };
+    (HSV, $($args:tt)*) => { $crate::color!(hsv, $($args)*) };
+    ($($tokens:tt)*) => {
+        compile_error!(concat!(
+            "unsupported color! input `",
+            stringify!($($tokens)*),
+            "`, expected `#hex`, `rgb(r, g, b)`, `rgba(r, g, b, a)` or `rgb|hsl|hsv, values...`"
+        ))
     };
 }
 
@@ -73,25 +92,39 @@ mod tests {
 
     #[test]
     fn test_color_macro() {
-        let color = color!(rgb, 255, 255, 0);
+        let color = color!(rgb, 255, 255, 0).unwrap();
         assert_eq!(color.rgb(), "rgb(255, 255, 0)");
 
-        let color = color!(rgb(255, 255, 0));
+        let color = color!(rgb(255, 255, 0)).unwrap();
         assert_eq!(color.rgb(), "rgb(255, 255, 0)");
 
-        let color = color!(rgba(255, 255, 0, 0.5));
+        let color = color!(rgba(255, 255, 0, 0.5)).unwrap();
         assert_eq!(color.rgba(), "rgba(255, 255, 0, 0.5)");
 
-        let color = color!(hsl, 60.0, 1.0, 0.5);
+        let color = color!(hsl, 60.0, 1.0, 0.5).unwrap();
         assert_eq!(color.hsl(), "hsl(60, 100%, 50%)");
 
-        let color = color!(hsv, 60.0, 1.0, 1.0);
+        let color = color!(hsv, 60.0, 1.0, 1.0).unwrap();
         assert_eq!(color.hsv(), "hsv(60, 100%, 100%)");
 
-        let color = color!(#f00);
+        let color = color!(#f00).unwrap();
         assert_eq!(color.hex(), "#f00");
 
-        let color = color!(#abcdef);
+        let color = color!(#abcdef).unwrap();
         assert_eq!(color.hex(), "#abcdef");
+
+        let color = color!(HSV, 60, 1, 1).unwrap();
+        assert_eq!(color.hex(), "#ff0");
+    }
+
+    #[test]
+    fn test_color_macro_err() {
+        assert!(color!(rgb, 256, 0, 0).is_err());
+        assert!(color!(rgba(255, 255, 0, 1.5)).is_err());
+        assert!(color!(hsl, 60.0, 2.0, 0.5).is_err());
+        assert_eq!(
+            color!(#ff339).unwrap_err().to_string(),
+            "Hex color must have 3, 4, 6, 8, 9 or 12 digits, got 5 at 0..6"
+        );
     }
 }
@@ -0,0 +1,148 @@
+use crate::{delta_e, utils::Float, Color};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A hashable key of a [`Color`], quantized to 8 bits per channel.
+///
+/// Colors compare equal as keys when they have the same [`Color::to_rgba8`] value, so the
+/// float noise of a round trip does not matter. Use it for `HashSet` and `HashMap` keys.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{Color, ColorKey};
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(Color::new(255.0, 51.0, 153.0, 1.0).key());
+/// set.insert(Color::new(255.0, 51.000001, 153.0, 1.0).key());
+/// assert_eq!(set.len(), 1);
+///
+/// let key = ColorKey::from(Color::new(255, 51, 153, 1.0));
+/// assert_eq!(Color::from(key), Color::new(255, 51, 153, 1.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ColorKey(pub [u8; 4]);
+
+impl<T: Float> Color<T> {
+    /// Quantizes the color to 8 bits per channel.
+    ///
+    /// The channels are clamped into the sRGB gamut and rounded, the alpha is scaled to `0..=255`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.2, 50.6, 300.0, 0.5);
+    /// assert_eq!(color.to_rgba8(), [255, 51, 255, 128]);
+    /// ```
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        let [r, g, b] = self.rgb.map(|v| v.to_f64());
+        let alpha = self.alpha.to_f64() * 255.0;
+        [r, g, b, alpha].map(|v| v.clamp(0.0, 255.0).round() as u8)
+    }
+    /// The hashable [`ColorKey`] of the color.
+    pub fn key(&self) -> ColorKey {
+        ColorKey(self.to_rgba8())
+    }
+    /// Whether two colors are equal within `tolerance` RGB units.
+    ///
+    /// Every RGB channel may differ by `tolerance` (in `0..=255` units), the alpha by
+    /// `tolerance / 255`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 51.0, 153.0, 1.0);
+    /// assert!(color.approx_eq(&Color::new(254.6, 51.3, 153.0, 1.0), 0.5));
+    /// assert!(!color.approx_eq(&Color::new(254.0, 51.0, 153.0, 1.0), 0.5));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, tolerance: T) -> bool {
+        self.rgb
+            .iter()
+            .zip(other.rgb.iter())
+            .all(|(&a, &b)| (a - b).abs() <= tolerance)
+            && (self.alpha - other.alpha).abs() <= tolerance / T::from_f64(255.0)
+    }
+    /// Whether two colors are perceptually equal, i.e. their [`delta_e`] is at most `max_delta_e`.
+    ///
+    /// The alphas may differ by one 8 bit step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, Color};
+    ///
+    /// assert!(color!(#fefe0e).unwrap().approx_eq_delta_e(&color!(#ffff00).unwrap(), 1.0));
+    /// assert!(!color!(#fefe0e).unwrap().approx_eq_delta_e(&color!(#fff).unwrap(), 1.0));
+    /// ```
+    pub fn approx_eq_delta_e(&self, other: &Self, max_delta_e: T) -> bool {
+        (self.alpha - other.alpha).abs().to_f64() <= 1.0 / 255.0
+            && delta_e(self, other) <= max_delta_e
+    }
+}
+
+impl<T: Float> From<Color<T>> for ColorKey {
+    fn from(color: Color<T>) -> Self {
+        color.key()
+    }
+}
+
+impl<T: Float> From<ColorKey> for Color<T> {
+    fn from(key: ColorKey) -> Self {
+        let [r, g, b, alpha] = key.0.map(|v| T::from_f64(v as f64));
+        Color::new(r, g, b, alpha / T::from_f64(255.0))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::*;
+    use std::{collections::HashMap, str::FromStr};
+
+    #[test]
+    fn test_to_rgba8() {
+        assert_eq!(color!(#1890ff).unwrap().to_rgba8(), [24, 144, 255, 255]);
+        assert_eq!(Color::new(-3.0, 0.4, 255.5, 0.0).to_rgba8(), [0, 0, 255, 0]);
+        assert_eq!(
+            Color::<f32>::new(1.0, 2.0, 3.0, 0.2).to_rgba8(),
+            [1, 2, 3, 51]
+        );
+    }
+
+    #[test]
+    fn test_color_key() {
+        let color = color!(#7654cd).unwrap();
+        let round_trip = Color::from_str(&color.lab()).unwrap();
+        assert_ne!(color, round_trip);
+        assert_eq!(color.key(), round_trip.key());
+
+        let mut counts = HashMap::new();
+        for color in [color, round_trip, color!(#fff).unwrap()] {
+            *counts.entry(color.key()).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&color.key()], 2);
+
+        assert_eq!(
+            Color::<f64>::from(ColorKey([255, 0, 0, 255])),
+            color!(#f00).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let color = color!(#7654cd).unwrap();
+        let round_trip = Color::from_str(&color.lab()).unwrap();
+        assert!(color.approx_eq(&round_trip, 0.5));
+        assert!(color.approx_eq_delta_e(&round_trip, 0.1));
+
+        let faded = Color::new(118.0, 84.0, 205.0, 0.9);
+        assert!(!color.approx_eq(&faded, 1.0));
+        assert!(!color.approx_eq_delta_e(&faded, 1.0));
+    }
+}
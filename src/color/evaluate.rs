@@ -0,0 +1,40 @@
+use crate::{parser, Color, Error};
+use alloc::string::String;
+
+impl Color {
+    /// Evaluates an expression of the color functions of Less and Sass.
+    ///
+    /// The functions are `darken`, `lighten`, `saturate`, `desaturate`, `fade`, `fadein`,
+    /// `fadeout`, `spin`, `mix`, `tint`, `shade` and `greyscale`, with the Sass names
+    /// `fade-in`, `opacify`, `fade-out`, `transparentize`, `adjust-hue`, `grayscale` and the
+    /// `rgba($color, $alpha)` of Sass. The `relative` method of Less is supported, e.g.
+    /// `darken(@color, 10%, relative)`.
+    ///
+    /// `resolve` gets the name of a variable with its `$` or `@`, its value is an expression too.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let resolve = |name: &str| match name {
+    ///     "$brand" | "@brand" => Some("#0af".to_string()),
+    ///     _ => None,
+    /// };
+    ///
+    /// let color = Color::evaluate("darken(spin(#f00, 30), 10%)", resolve).unwrap();
+    /// assert_eq!(color.hex(), "#c60");
+    ///
+    /// let color = Color::evaluate("mix($brand, white, 25%)", resolve).unwrap();
+    /// assert_eq!(color.rgb(), "rgb(191, 234, 255)");
+    ///
+    /// let color = Color::evaluate("fadeout(@brand, 50%, relative)", resolve).unwrap();
+    /// assert_eq!(color.alpha(), 0.5);
+    /// ```
+    pub fn evaluate<F>(expr: &str, resolve: F) -> Result<Self, Error>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        parser::expr::evaluate(expr, &resolve)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{Color, Error};
+use alloc::format;
 
 impl Color {
     /// Returns the numeric representation of the hexadecimal color.
@@ -13,10 +14,13 @@ impl Color {
     /// ```
     pub fn from_num(num: u32) -> Result<Self, Error> {
         if num > 0xffffff {
-            return Err(Error::InvalidParamsError(format!(
-                "Invalid color number, must be between 0 and 16777215, but got {}",
-                num
-            )));
+            return Err(Error::InvalidParamsError(
+                format!(
+                    "Invalid color number, must be between 0 and 16777215, but got {}",
+                    num
+                )
+                .into(),
+            ));
         }
         let r = ((num >> 16) & 0xff) as f64;
         let g = ((num >> 8) & 0xff) as f64;
@@ -1,4 +1,5 @@
 use crate::{conversion, data::hex_of_name, Color, ColorSpace, Error};
+use alloc::format;
 
 impl Color {
     /// Create a color from RGB values.
@@ -64,12 +65,9 @@ impl Color {
     /// assert_eq!(color.hex(), "#f39");
     /// ```
     pub fn from_hsl(h: f64, s: f64, l: f64) -> Result<Self, Error> {
-        let hsl = vec![h, s, l];
+        let hsl = [h, s, l];
         ColorSpace::HSL.valid(&hsl)?;
-        let rgb = conversion::hsl::hsl2rgb(&hsl);
-        let r = rgb[0];
-        let g = rgb[1];
-        let b = rgb[2];
+        let [r, g, b] = conversion::hsl::hsl2rgb(hsl);
         Ok(Color::new(r, g, b, 1.0))
     }
     /// Create a color from HSV values.
@@ -83,12 +81,9 @@ impl Color {
     /// assert_eq!(color.hex(), "#ffa500");
     /// ```
     pub fn from_hsv(h: f64, s: f64, v: f64) -> Result<Self, Error> {
-        let hsv = vec![h, s, v];
+        let hsv = [h, s, v];
         ColorSpace::HSV.valid(&hsv)?;
-        let rgb = conversion::hsv::hsv2rgb(&hsv);
-        let r = rgb[0];
-        let g = rgb[1];
-        let b = rgb[2];
+        let [r, g, b] = conversion::hsv::hsv2rgb(hsv);
         Ok(Color::new(r, g, b, 1.0))
     }
     /// Create a color from CMYK values.
@@ -102,12 +97,9 @@ impl Color {
     /// assert_eq!(color.hex(), "#f39");
     /// ```
     pub fn from_cmyk(c: f64, m: f64, y: f64, k: f64) -> Result<Self, Error> {
-        let cmyk = vec![c, m, y, k];
+        let cmyk = [c, m, y, k];
         ColorSpace::CMYK.valid(&cmyk)?;
-        let rgb = conversion::cmyk::cmyk2rgb(&cmyk);
-        let r = rgb[0];
-        let g = rgb[1];
-        let b = rgb[2];
+        let [r, g, b] = conversion::cmyk::cmyk2rgb(cmyk);
         Ok(Color::new(r, g, b, 1.0))
     }
     /// Create a color from a hex string.
@@ -122,27 +114,21 @@ impl Color {
     ///
     /// let color = Color::from_hex("#ff339933").unwrap();
     /// assert_eq!(color.hex(), "#f393");
+    ///
+    /// // the X11 #rrrrggggbbbb
+    /// let color = Color::from_hex("#ffff33339999").unwrap();
+    /// assert_eq!(color.hex(), "#f39");
     /// ```
     pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
         ColorSpace::valid_hex(hex_str)?;
-        let color_vec = match hex_str.len() {
-            4 | 7 => conversion::hex::hex2rgb(hex_str),
-            5 | 9 => conversion::hex::hex2rgba(hex_str),
+        let [r, g, b, a] = match hex_str.len() {
+            4 | 5 | 7 | 9 | 10 | 13 => conversion::hex::hex2rgba(hex_str),
             _ => {
-                return Err(Error::InvalidParamsError(format!(
-                    "Got a error hex string of '{}'!",
-                    hex_str
-                )))
+                return Err(Error::InvalidParamsError(
+                    format!("Got a error hex string of '{}'!", hex_str).into(),
+                ))
             }
         };
-        let r = color_vec[0];
-        let g = color_vec[1];
-        let b = color_vec[2];
-        let a = if color_vec.len() == 4 {
-            color_vec[3]
-        } else {
-            1.0
-        };
         Ok(Color::new(r, g, b, a))
     }
     /// Create a color from a color name.
@@ -167,10 +153,9 @@ impl Color {
         let found = hex_of_name(name);
         match found {
             Some(hex) => Color::from_hex(hex),
-            None => Err(Error::InvalidParamsError(format!(
-                "Invalid color name: {}",
-                name
-            ))),
+            None => Err(Error::InvalidParamsError(
+                format!("Invalid color name: {}", name).into(),
+            )),
         }
     }
 }
@@ -1,5 +1,6 @@
-use crate::{conversion, data::hex_of_name, parser, Color, ColorSpace, Error};
-use std::str::FromStr;
+use crate::{conversion, data::hex_of_name, parser, Color, Error, ParseContext, Span};
+use alloc::string::String;
+use core::str::FromStr;
 
 impl FromStr for Color {
     type Err = Error;
@@ -38,63 +39,199 @@ impl FromStr for Color {
     /// let s = "水绿";
     /// let color = Color::from_str(s).unwrap();
     /// assert_eq!(color, Color::new(140, 194, 105, 1.0));
+    ///
+    /// // CSS Color 4 syntax
+    /// let s = "rgb(255 255 255 / 50%)";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(255, 255, 255, 0.5));
+    ///
+    /// let s = "hsl(0.5turn 100% 50% / none)";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(0, 255, 255, 0.0));
+    ///
+    /// let s = "color(srgb 1 1 1)";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(255, 255, 255, 1.0));
+    ///
+    /// // relative color syntax
+    /// let s = "rgb(from #0af calc(255 - r) calc(g / 2) b / 0.5)";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(255, 85, 255, 0.5));
+    ///
+    /// // X11 colors of `XParseColor`
+    /// let s = "rgb:ff/80/00";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(255, 128, 0, 1.0));
+    ///
+    /// let s = "rgbi:1.0/0.5/0.0";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(255.0, 127.5, 0.0, 1.0));
+    ///
+    /// // color-mix() of CSS Color 5
+    /// let s = "color-mix(in hsl longer hue, red, blue)";
+    /// let color = Color::from_str(s).unwrap();
+    /// assert_eq!(color, Color::new(0, 255, 0, 1.0));
+    /// ```
+    ///
+    /// The errors of the parser point at the offending part of the input, see [`Error::span`].
+    ///
+    /// ```rust
+    /// use color_art::{Color, Error, Span};
+    /// use std::str::FromStr;
+    ///
+    /// let err = Color::from_str(" hsl(40, 150%, 50%)").unwrap_err();
+    /// assert_eq!(err.span(), Some(Span::new(9, 13)));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Saturation must be between 0 and 1, got 1.5 at 9..13"
+    /// );
+    ///
+    /// let err = Color::from_str("rgbx(0, 0, 0)").unwrap_err();
+    /// assert!(matches!(err, Error::UnknownFunction { .. }));
     /// ```
     fn from_str(s: &str) -> Result<Self, Error> {
-        let input = s.trim().to_lowercase();
-
-        let (color_space, color_vec) = if input.starts_with('#') {
-            let hex_str = parser::hex::parse_hex_str(&input)?;
-            (ColorSpace::RGB, conversion::hex::hex2rgb(&hex_str))
-        } else if let Some(hex) = hex_of_name(&input) {
-            (ColorSpace::RGB, conversion::hex::hex2rgb(hex))
-        } else {
-            let mut parser = parser::Parser::new();
-            parser.tokenize(&input).validate()?;
-            (parser.color_space, parser.values)
-        };
-
-        let color_vec = convert_color_vec_by_color_space(&color_vec, &color_space);
+        Color::from_str_with_context(s, &ParseContext::default())
+    }
+}
 
-        let r = color_vec[0];
-        let g = color_vec[1];
-        let b = color_vec[2];
-        let alpha = if color_vec.len() > 3 {
-            color_vec[3]
-        } else {
-            1.0
-        };
+impl Color {
+    /// Creates a new [`Color`] from a string, resolving the keywords that depend on the document.
+    ///
+    /// These are `currentColor`, the system colors like `Canvas` and `light-dark()`, see
+    /// [`ParseContext`]. `transparent` is transparent black with any context.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, ColorScheme, ParseContext};
+    ///
+    /// let context = ParseContext {
+    ///     current_color: Some(Color::new(0, 170, 255, 1.0)),
+    ///     color_scheme: ColorScheme::Dark,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let color = Color::from_str_with_context("currentColor", &context).unwrap();
+    /// assert_eq!(color.hex(), "#0af");
+    ///
+    /// let color = Color::from_str_with_context("light-dark(#fff, #000)", &context).unwrap();
+    /// assert_eq!(color.hex(), "#000");
+    ///
+    /// let color = Color::from_str_with_context("CanvasText", &context).unwrap();
+    /// assert_eq!(color.hex(), "#fff");
+    ///
+    /// let s = "color-mix(in srgb, currentColor, transparent)";
+    /// let color = Color::from_str_with_context(s, &context).unwrap();
+    /// assert_eq!(color.rgba(), "rgba(0, 170, 255, 0.5)");
+    /// ```
+    pub fn from_str_with_context(s: &str, context: &ParseContext) -> Result<Self, Error> {
+        let offset = s.len() - s.trim_start().len();
+        // ASCII only, so the byte offsets of the errors match the input
+        let input = s.trim().to_ascii_lowercase();
+        parse(&input, context).map_err(|err| err.shift_span(offset))
+    }
 
-        Ok(Color::new(r, g, b, alpha))
+    /// Creates a new [`Color`] from a string, resolving its `var()` references.
+    ///
+    /// `resolve` gets the name of the variable with its dashes, e.g. `--brand`. Its value may
+    /// reference other variables, a `var(--name, fallback)` falls back when it returns `None`.
+    /// The spans of the errors point into the string after the substitution.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let resolve = |name: &str| match name {
+    ///     "--brand" => Some("hsl(200 100% 50%)".to_string()),
+    ///     _ => None,
+    /// };
+    ///
+    /// let color = Color::from_str_with_vars("var(--brand)", resolve).unwrap();
+    /// assert_eq!(color.hex(), "#0af");
+    ///
+    /// let s = "hsl(from var(--brand) h s calc(l - 20%))";
+    /// let color = Color::from_str_with_vars(s, resolve).unwrap();
+    /// assert_eq!(color.hsl(), "hsl(200, 100%, 30%)");
+    ///
+    /// let color = Color::from_str_with_vars("var(--accent, red)", resolve).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    /// assert!(Color::from_str_with_vars("var(--accent)", resolve).is_err());
+    /// ```
+    pub fn from_str_with_vars<F>(s: &str, resolve: F) -> Result<Self, Error>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let s = parser::var::substitute_vars(s, &resolve)?;
+        Color::from_str(&s)
     }
 }
 
-fn convert_color_vec_by_color_space(color_vec: &[f64], color_space: &ColorSpace) -> Vec<f64> {
-    match color_space {
-        ColorSpace::RGB | ColorSpace::RGBA | ColorSpace::HEX | ColorSpace::HEXA => {
-            color_vec.to_vec()
-        }
-        ColorSpace::HSI => conversion::hsi::hsi2rgb(color_vec),
-        ColorSpace::HSL => conversion::hsl::hsl2rgb(color_vec),
-        ColorSpace::HSV => conversion::hsv::hsv2rgb(color_vec),
-        ColorSpace::CMYK => conversion::cmyk::cmyk2rgb(color_vec),
-        ColorSpace::XYZ => conversion::xyz::xyz2rgb(color_vec),
-        ColorSpace::YIQ => conversion::yiq::yiq2rgb(color_vec),
-        ColorSpace::YUV => conversion::yuv::yuv2rgb(color_vec),
-        ColorSpace::YCbCr => conversion::ycbcr::ycbcr2rgb(color_vec),
-        ColorSpace::Lab => conversion::lab::lab2rgb(color_vec),
-        ColorSpace::HWB => conversion::hwb::hwb2rgb(color_vec),
-        ColorSpace::HSLA => {
-            let mut rgb = conversion::hsl::hsl2rgb(color_vec);
-            rgb.push(color_vec[3]);
-            rgb
+fn parse(input: &str, context: &ParseContext) -> Result<Color, Error> {
+    if input.starts_with('#') {
+        let hex_str = parser::hex::parse_hex_str(input)?;
+        let [r, g, b, alpha] = conversion::hex::hex2rgba(&hex_str);
+        return Ok(Color::new(r, g, b, alpha));
+    }
+    if let Some(hex) = hex_of_name(input) {
+        let [r, g, b] = conversion::hex::hex2rgb(hex);
+        return Ok(Color::new(r, g, b, 1.0));
+    }
+    if input.starts_with("rgb:") {
+        return parser::x11::parse_x11_rgb(input);
+    }
+    if input.starts_with("rgbi:") {
+        return parser::x11::parse_x11_rgbi(input);
+    }
+    if let Some(color) = parser::context::parse_keyword(input, context) {
+        return color;
+    }
+    if input.starts_with("color-mix(") {
+        return parser::mix::parse_color_mix(input, context);
+    }
+    if input.starts_with("light-dark(") {
+        return parser::context::parse_light_dark(input, context);
+    }
+
+    let mut parser = parser::Parser::with_context(*context);
+    parser.tokenize(input).validate()?;
+
+    let color = match parser.predefined {
+        Some(space) => {
+            let values = [parser.values[0], parser.values[1], parser.values[2]];
+            let [r, g, b] = conversion::predefined::predefined2rgb(space, values);
+            Color::new(r, g, b, 1.0)
         }
-        ColorSpace::Unknown => todo!(),
+        None => Color::from_values_of(parser.color_space, &parser.values),
+    };
+    let color = match parser.alpha {
+        Some(alpha) => Color { alpha, ..color },
+        None => color,
+    };
+    finite(color, Span::new(0, input.len()))
+}
+
+/// Rejects the colors whose values overflow in the conversion to RGB, e.g. `lab(50 1e300 0)`.
+fn finite(color: Color, span: Span) -> Result<Color, Error> {
+    match ["Red", "Green", "Blue"]
+        .iter()
+        .zip(color.rgb)
+        .find(|(_, value)| !value.is_finite())
+    {
+        Some((channel, value)) => Err(Error::ChannelOutOfRange {
+            channel: (*channel).into(),
+            value,
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            span,
+        }),
+        None => Ok(color),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Error;
+    use crate::{color, ColorSpace, Error, PredefinedSpace, Span};
 
     use super::*;
 
@@ -201,6 +338,15 @@ mod tests {
         assert_eq!(color, Color::new(0.0, 255.0, 255.0, 1.0));
     }
 
+    #[test]
+    fn test_color_from_hexa_str() {
+        let color = Color::from_str("#ff339980").unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 51, 153, 0.5)");
+
+        let color = Color::from_str("#f398").unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 51, 153, 0.53)");
+    }
+
     #[test]
     fn test_color_from_hex_str_err() {
         let s = "#gggggg";
@@ -208,7 +354,7 @@ mod tests {
         match color {
             Err(err) => assert_eq!(
                 err,
-                Error::ColorParserError("Invalid hex string of '#gggggg'".to_string())
+                Error::ColorParserError("Invalid hex string of '#gggggg'".into())
             ),
             _ => panic!("Should have failed"),
         }
@@ -216,7 +362,7 @@ mod tests {
         let s = "fff";
         let color = Color::from_str(s);
         match color {
-            Err(e) => assert_eq!(e.to_string(), "Invalid input"),
+            Err(e) => assert_eq!(e.to_string(), "Unknown color function 'fff' at 0..3"),
             _ => panic!("Should have failed"),
         }
     }
@@ -298,7 +444,25 @@ mod tests {
         assert_eq!(color.rgb(), "rgb(255, 0, 0)");
 
         let color = Color::from_str("xyz(0.70047, 0.723315, 1.048516)").unwrap();
-        assert_eq!(color.rgb(), "rgb(92, 122, 255)");
+        assert_eq!(color.rgb(), "rgb(209, 221, 255)");
+    }
+
+    #[test]
+    fn test_color_from_str_overflow() {
+        for s in [
+            "lab(50 1e300 0)",
+            "xyz(1e308, 1e308, 1e308)",
+            "color(display-p3 1e300 0 0)",
+        ] {
+            let err = Color::from_str(s).unwrap_err();
+            assert!(matches!(err, Error::ChannelOutOfRange { .. }), "{}", s);
+            assert_eq!(err.span(), Some(Span::new(0, s.len())));
+        }
+        let err = Color::from_str(" lab(50 1e300 0)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Red must be a finite number, got NaN at 1..16"
+        );
     }
 
     #[test]
@@ -326,4 +490,325 @@ mod tests {
         assert_eq!(color.rgb(), "rgb(255, 255, 0)");
         assert_eq!(color.hex(), "#ff0");
     }
+
+    #[test]
+    fn test_color_from_css4_str() {
+        let color = Color::from_str("rgb(255 0 0 / 50%)").unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 0, 0, 0.5)");
+
+        let color = Color::from_str("rgb(100% 20% 60%)").unwrap();
+        assert_eq!(color.hex(), "#f39");
+
+        let color = Color::from_str("rgba(255, 51, 153)").unwrap();
+        assert_eq!(color.hex(), "#f39");
+
+        let color = Color::from_str("rgb(255, 51, 153, 0.5)").unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 51, 153, 0.5)");
+
+        let color = Color::from_str("hsl(120deg 100% 50%)").unwrap();
+        assert_eq!(color.hex(), "#0f0");
+
+        let color = Color::from_str("hsl(120 100 50 / .5)").unwrap();
+        assert_eq!(color.rgba(), "rgba(0, 255, 0, 0.5)");
+
+        for hue in ["0.5turn", "200grad", "3.14159265358979rad", "+1.8e2deg"] {
+            let color = Color::from_str(&format!("hsl({} 100% 50%)", hue)).unwrap();
+            assert_eq!(color.hex(), "#0ff", "{}", hue);
+        }
+
+        let color = Color::from_str("hwb(none 0% 0%)").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color = Color::from_str("lab(50% 0% 0% / 1)").unwrap();
+        assert_eq!(color.vec_of(ColorSpace::Lab)[0].round(), 50.0);
+
+        // hues wrap around the color wheel
+        for (input, hex) in [
+            ("hsl(400 100% 50%)", "#fa0"),
+            ("hsl(-30, 100%, 50%)", "#ff0080"),
+            ("hwb(-1turn 0% 0%)", "#f00"),
+            ("hsl(calc(300 + 120) 100% 50%)", "#ff0"),
+        ] {
+            let color = Color::from_str(input).unwrap();
+            assert_eq!(color.hex(), hex, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_color_from_lch_oklab_str() {
+        for (input, hex) in [
+            ("lch(97.61 94.71 99.57)", "#ff0"),
+            ("lch(97.61% 63.14% 99.57deg / 50%)", "#ffff0080"),
+            ("lch(47.99 31.69 -163.55)", "#008080"),
+            ("oklab(0.968 -0.0714 0.1986)", "#ff0"),
+            ("oklab(96.8% -17.85% 49.65%)", "#ff0"),
+            ("oklch(0.968 0.211 109.77)", "#ff0"),
+            ("oklch(62.8% 64.43% 0.5102rad)", "#f00"),
+            ("oklch(0.5431 0.0927 554.77)", "#008080"),
+            ("oklch(1 0 none)", "#fff"),
+        ] {
+            let color = Color::from_str(input).unwrap();
+            assert_eq!(color.hex(), hex, "{}", input);
+        }
+
+        let color = color!(#7654cd).unwrap();
+        for space in [ColorSpace::Lch, ColorSpace::Oklab, ColorSpace::Oklch] {
+            let css = color.stringify(space);
+            assert_eq!(Color::from_str(&css).unwrap().hex(), "#7654cd", "{}", css);
+        }
+
+        let err = Color::from_str("oklch(1.5 0 0)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "L must be between 0 and 1, got 1.5 at 6..9"
+        );
+        let err = Color::from_str("lch(50 -10 0)").unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(7, 10)));
+        assert!(Color::from_str("oklch(0.5 0.1 50%)").is_err());
+    }
+
+    #[test]
+    fn test_color_from_css4_str_err() {
+        let err = Color::from_str("rgb(255 0 0 / 0.5 / 1)").unwrap_err();
+        assert_eq!(err.to_string(), "Only one slash is allowed at 18..19");
+
+        let err = Color::from_str("rgb(255 0 / 0.5 1)").unwrap_err();
+        assert!(matches!(err, Error::WrongArity { .. }));
+
+        let err = Color::from_str("rgb(255deg 0 0)").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid unit of '255deg' at 4..10");
+
+        let err = Color::from_str("hsl(120 100% 50% / 0.5turn)").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid unit of '0.5turn' at 19..26");
+
+        let err = Color::from_str("rgb(0 0 0 / 150%)").unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(12, 16)));
+    }
+
+    #[test]
+    fn test_color_from_str_syntax_err() {
+        let err = Color::from_str("rgb(255,0,0)extra").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unexpected 'extra' after the color at 12..17"
+        );
+
+        let err = Color::from_str("rgb(255 0 0) 1 2").unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(13, 16)));
+
+        let err = Color::from_str("rgb(255 0 0))").unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected ')' after the color at 12..13");
+
+        let err = Color::from_str("rgb(255 0 0").unwrap_err();
+        assert_eq!(err.to_string(), "Unmatched left parenthesis at 3..4");
+
+        let err = Color::from_str("rgb(((").unwrap_err();
+        assert_eq!(err.to_string(), "Unmatched left parenthesis at 5..6");
+
+        let err = Color::from_str("rgba(0,0,0,)").unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected trailing ',' at 10..11");
+
+        let err = Color::from_str("rgb(255 0 0, 0.5)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Mixed comma and space separators at 11..12"
+        );
+
+        let err = Color::from_str("rgb(255 0 #0 0)").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid value at 10..12");
+
+        let err = Color::from_str("(255 0 0)").unwrap_err();
+        assert_eq!(err.to_string(), "No color space found at 0..9");
+
+        let err = Color::from_str("rgb(from  ) 0 0)").unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(4, 8)));
+    }
+
+    #[test]
+    fn test_color_from_css_color_str() {
+        let color = Color::from_str("color(srgb 1 0.2 60%)").unwrap();
+        assert_eq!(color.hex(), "#f39");
+
+        let color = Color::from_str("color(display-p3 1 0 0 / 50%)").unwrap();
+        assert!(!color.is_in_gamut());
+        assert_eq!(color.alpha(), 0.5);
+        assert_eq!(
+            color.css_color(PredefinedSpace::DisplayP3),
+            "color(display-p3 1 0 0 / 0.5)"
+        );
+
+        let color = Color::from_str("color(xyz 0.412391 0.212639 0.019331)").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        for space in [
+            "srgb-linear",
+            "a98-rgb",
+            "prophoto-rgb",
+            "rec2020",
+            "xyz-d50",
+            "xyz-d65",
+        ] {
+            let space = PredefinedSpace::from_name(space).unwrap();
+            let css = color!(#7654cd).unwrap().css_color(space);
+            let color = Color::from_str(&css).unwrap();
+            assert_eq!(color.hex(), "#7654cd", "{}", css);
+            assert_eq!(color.css_color(space), css);
+        }
+    }
+
+    #[test]
+    fn test_color_from_css_color_str_err() {
+        let err = Color::from_str("color(display-p4 1 0 0)").unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(6, 16)));
+
+        let err = Color::from_str("color(rec2020 1 0)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "color(rec2020) requires 3 values, got 2 at 0..18"
+        );
+
+        let err = Color::from_str("color(srgb 1 0 0deg)").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid unit of '0deg' at 15..19");
+
+        assert!(Color::from_str("color()").is_err());
+    }
+
+    #[test]
+    fn test_color_from_relative_str() {
+        let color = Color::from_str("hsl(from red h s calc(l - 20%))").unwrap();
+        assert_eq!(color.hsl(), "hsl(0, 100%, 30%)");
+
+        let color = Color::from_str("hsl(from red calc(h + 0.5turn) s l)").unwrap();
+        assert_eq!(color.hex(), "#0ff");
+
+        let color = Color::from_str("hwb(from #f00 calc(h + 120) w b)").unwrap();
+        assert_eq!(color.hex(), "#0f0");
+
+        let color = Color::from_str("rgb(from rgb(0 0 0 / 0.5) calc(r + 255) g b)").unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 0, 0, 0.5)");
+
+        let color =
+            Color::from_str("rgb(from #f39 calc((r + g) / 2) calc(b * 1) 0 / calc(alpha / 2))")
+                .unwrap();
+        assert_eq!(color.rgba(), "rgba(153, 153, 0, 0.5)");
+
+        let color = Color::from_str("lab(from #7654cd l a b)").unwrap();
+        assert_eq!(color.hex(), "#7654cd");
+
+        // the complementary color of the hue
+        let color = Color::from_str("oklch(from #0af l c calc(h + 180))").unwrap();
+        assert_eq!(
+            color!(#0af).unwrap().oklch(),
+            "oklch(0.7072, 0.1679, 242.04)"
+        );
+        assert_eq!(color.oklch(), "oklch(0.7072, 0.1679, 62.04)");
+
+        for input in [
+            "lch(from #7654cd l c h)",
+            "oklab(from #7654cd l a b)",
+            "oklch(from #7654cd l c h / alpha)",
+            "oklch(from #7654cd calc(l * 1) c calc(h + 360))",
+        ] {
+            assert_eq!(
+                Color::from_str(input).unwrap().hex(),
+                "#7654cd",
+                "{}",
+                input
+            );
+        }
+
+        let color = Color::from_str("lch(from #0af calc(l - 10) c h)").unwrap();
+        assert_eq!(color.lch(), "lch(55.65, 55.02, 255.31)");
+
+        let color = Color::from_str("color(from #0af display-p3 r g b)").unwrap();
+        assert_eq!(color.hex(), "#0af");
+
+        let color =
+            Color::from_str("color(from red xyz-d65 calc(x / 2) calc(y / 2) calc(z / 2))").unwrap();
+        assert_eq!(color.hex(), "#bc0000");
+    }
+
+    #[test]
+    fn test_color_from_calc_str() {
+        let color = Color::from_str("rgb(calc(200 + 55) calc(10% * 2) calc(-1 * -0))").unwrap();
+        assert_eq!(color.rgb(), "rgb(255, 51, 0)");
+
+        let color = Color::from_str("hsl(calc(60 * 2) 100% calc(100% / 2))").unwrap();
+        assert_eq!(color.hex(), "#0f0");
+
+        let color = Color::from_str("rgba(calc(255), 0, 0, calc(1 / 4))").unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 0, 0, 0.25)");
+    }
+
+    #[test]
+    fn test_color_from_relative_str_err() {
+        let err = Color::from_str("rgb(r g b)").unwrap_err();
+        assert_eq!(err.to_string(), "Unknown channel keyword 'r' at 4..5");
+
+        let err = Color::from_str("hsl(from red h s x)").unwrap_err();
+        assert_eq!(err.to_string(), "Unknown channel keyword 'x' at 17..18");
+
+        let err = Color::from_str("rgb(from redd r g b)").unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(9, 13)));
+
+        let err = Color::from_str("hsl(from red h s calc(l - 80%))").unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(17, 30)));
+
+        assert!(Color::from_str("rgb(calc(1 +) 0 0)").is_err());
+        assert!(Color::from_str("rgb(calc(1 0 0)").is_err());
+        assert!(Color::from_str("rgb(from r g b)").is_err());
+    }
+
+    #[test]
+    fn test_color_from_str_with_vars() {
+        let resolve = |name: &str| match name {
+            "--brand" => Some("#0af".to_string()),
+            "--darker" => Some("calc(l - 20%)".to_string()),
+            _ => None,
+        };
+        let color =
+            Color::from_str_with_vars("hsl(from var(--brand) h s var(--darker))", resolve).unwrap();
+        assert_eq!(color.hex(), "#069");
+
+        let color = Color::from_str_with_vars("  VAR(--brand)", resolve).unwrap();
+        assert_eq!(color.hex(), "#0af");
+
+        let err = Color::from_str_with_vars("var(--missing)", resolve).unwrap_err();
+        assert_eq!(err.to_string(), "Unresolved variable '--missing'");
+    }
+
+    #[test]
+    fn test_color_from_str_with_context() {
+        let context = ParseContext {
+            current_color: Some(Color::new(255.0, 0.0, 0.0, 1.0)),
+            color_scheme: crate::ColorScheme::Dark,
+            ..Default::default()
+        };
+        let color = Color::from_str_with_context(" currentColor", &context).unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        let color =
+            Color::from_str_with_context("hsl(from currentcolor h s calc(l - 20%))", &context)
+                .unwrap();
+        assert_eq!(color.hex(), "#900");
+
+        let color = Color::from_str_with_context("light-dark(Canvas, CanvasText)", &context);
+        assert_eq!(color.unwrap().hex(), "#fff");
+
+        let s = "rgb(from light-dark(#000, currentColor) r g b / 0.5)";
+        let color = Color::from_str_with_context(s, &context).unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 0, 0, 0.5)");
+
+        let s = "color-mix(in srgb, light-dark(red, blue), currentColor)";
+        let color = Color::from_str_with_context(s, &context).unwrap();
+        assert_eq!(color.hex(), "#800080");
+
+        assert_eq!(
+            Color::from_str("Transparent").unwrap(),
+            Color::new(0.0, 0.0, 0.0, 0.0)
+        );
+        assert_eq!(Color::from_str("Canvas").unwrap().hex(), "#fff");
+        assert!(Color::from_str("currentColor").is_err());
+        assert!(Color::from_str("rgb(from currentColor r g b)").is_err());
+    }
 }
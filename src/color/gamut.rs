@@ -0,0 +1,105 @@
+use crate::{
+    utils::{lit, Float},
+    Color,
+};
+
+/// Tolerance of the gamut check in 0..255 units (0.000075 in 0..1 units),
+/// so that the float noise of a round trip through XYZ or Lab still counts as in gamut.
+const GAMUT_EPSILON: f64 = 0.000075 * 255.0;
+
+impl<T: Float> Color<T> {
+    /// Whether the color is inside the sRGB gamut.
+    ///
+    /// Colors created from spaces wider than sRGB (e.g. `lab()` or `xyz()`) keep their
+    /// extended range values, their channels may be below 0 or above 255. A NaN or infinite
+    /// channel is never in gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    /// use std::str::FromStr;
+    ///
+    /// let color = Color::from_str("lab(60, 90, -90)").unwrap();
+    /// assert!(!color.is_in_gamut());
+    /// assert_eq!(color.lab(), "lab(60, 90, -90)");
+    ///
+    /// let color = Color::from_str("lab(60, 20, -20)").unwrap();
+    /// assert!(color.is_in_gamut());
+    /// ```
+    pub fn is_in_gamut(&self) -> bool {
+        let range = lit::<T>(-GAMUT_EPSILON)..=lit(255.0 + GAMUT_EPSILON);
+        self.rgb.iter().all(|v| range.contains(v))
+    }
+    /// Clamps the color into the sRGB gamut.
+    ///
+    /// Every RGB channel is clipped to `0..=255` and the alpha to `0..=1`, a NaN channel
+    /// becomes `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    /// use std::str::FromStr;
+    ///
+    /// let color = Color::from_str("lab(60, 90, -90)").unwrap().clamp();
+    /// assert!(color.is_in_gamut());
+    /// assert_eq!(color.rgb(), "rgb(226, 45, 255)");
+    /// ```
+    pub fn clamp(&self) -> Self {
+        Color {
+            rgb: self.rgb.map(|v| {
+                if v.is_nan() {
+                    T::ZERO
+                } else {
+                    v.clamp(T::ZERO, lit(255.0))
+                }
+            }),
+            alpha: self.alpha.clamp(T::ZERO, T::ONE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[cfg(feature = "alloc")]
+    use std::str::FromStr;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_is_in_gamut() {
+        assert!(color!(#fff).unwrap().is_in_gamut());
+        assert!(color!(#000).unwrap().is_in_gamut());
+        assert!(Color::new(255.001, 0.0, 0.0, 1.0).is_in_gamut());
+        assert!(!Color::new(256, 0, 0, 1.0).is_in_gamut());
+        assert!(!Color::new(0.0, -1.0, 0.0, 1.0).is_in_gamut());
+        assert!(!Color::new(f64::NAN, 0.0, 0.0, 1.0).is_in_gamut());
+        assert!(!Color::new(0.0, f64::INFINITY, 0.0, 1.0).is_in_gamut());
+
+        let color = Color::from_str(&color!(#ff0).unwrap().lab()).unwrap();
+        assert!(color.is_in_gamut());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_wide_gamut_round_trip() {
+        let color = Color::from_str("lab(50, 120, -130)").unwrap();
+        assert!(!color.is_in_gamut());
+        assert_eq!(color.lab(), "lab(50, 120, -130)");
+
+        let color = Color::from_str("xyz(0.2, 0.5, 0.05)").unwrap();
+        assert!(!color.is_in_gamut());
+        assert_eq!(color.xyz(), "xyz(0.2, 0.5, 0.05)");
+    }
+
+    #[test]
+    fn test_clamp() {
+        let color = Color::new(300.0, -20.0, 128.0, 1.5).clamp();
+        assert_eq!(color, Color::new(255.0, 0.0, 128.0, 1.0));
+        assert!(color.is_in_gamut());
+
+        let color = Color::new(f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1.0).clamp();
+        assert_eq!(color, Color::new(0.0, 255.0, 0.0, 1.0));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{conversion, data::hex_of_name, Color, Error};
+use alloc::{string::String, vec::Vec};
+
+impl Color {
+    /// Creates a new [`Color`] from a legacy HTML attribute like `bgcolor` or `<font color>`.
+    ///
+    /// Implements the [rules for parsing a legacy color value](https://html.spec.whatwg.org/multipage/common-microsyntaxes.html#rules-for-parsing-a-legacy-colour-value)
+    /// of the WHATWG HTML standard, so any string that is not empty or `transparent` gives a
+    /// color, the same one as in the browsers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::from_html_legacy("chucknorris").unwrap();
+    /// assert_eq!(color.hex(), "#c00000");
+    ///
+    /// let color = Color::from_html_legacy("#1234").unwrap();
+    /// assert_eq!(color.hex(), "#123400");
+    ///
+    /// let color = Color::from_html_legacy(" Red ").unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    ///
+    /// assert!(Color::from_html_legacy("transparent").is_err());
+    /// ```
+    pub fn from_html_legacy(s: &str) -> Result<Self, Error> {
+        if s.is_empty() {
+            return Err(Error::ColorParserError("Empty legacy color".into()));
+        }
+        let input = s.trim_matches(|c: char| c.is_ascii_whitespace());
+        if input.eq_ignore_ascii_case("transparent") {
+            return Err(Error::ColorParserError(
+                "'transparent' is not a legacy color".into(),
+            ));
+        }
+        if let Some(hex) = hex_of_name(&input.to_ascii_lowercase()) {
+            let [r, g, b] = conversion::hex::hex2rgb(hex);
+            return Ok(Color::new(r, g, b, 1.0));
+        }
+
+        let chars: Vec<char> = input.chars().collect();
+        if let ['#', digits @ ..] = &chars[..] {
+            if digits.len() == 3 && digits.iter().all(char::is_ascii_hexdigit) {
+                let [r, g, b] = [digits[0], digits[1], digits[2]]
+                    .map(|c| c.to_digit(16).unwrap_or_default() as f64 * 17.0);
+                return Ok(Color::new(r, g, b, 1.0));
+            }
+        }
+
+        // the characters outside of the BMP count as two in UTF-16
+        let mut digits = Vec::new();
+        for c in chars {
+            if u32::from(c) > 0xffff {
+                digits.extend(['0', '0']);
+            } else {
+                digits.push(c);
+            }
+        }
+        digits.truncate(128);
+        if digits.first() == Some(&'#') {
+            digits.remove(0);
+        }
+        for c in digits.iter_mut() {
+            if !c.is_ascii_hexdigit() {
+                *c = '0';
+            }
+        }
+        while digits.is_empty() || digits.len() % 3 != 0 {
+            digits.push('0');
+        }
+
+        let mut length = digits.len() / 3;
+        let mut components: Vec<&[char]> = digits.chunks(length).collect();
+        if length > 8 {
+            components = components.iter().map(|c| &c[length - 8..]).collect();
+            length = 8;
+        }
+        while length > 2 && components.iter().all(|c| c[0] == '0') {
+            components = components.iter().map(|c| &c[1..]).collect();
+            length -= 1;
+        }
+        let [r, g, b] = [0, 1, 2].map(|i| {
+            let hex: String = components[i].iter().take(2).collect();
+            u8::from_str_radix(&hex, 16).unwrap_or_default() as f64
+        });
+        Ok(Color::new(r, g, b, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Color;
+
+    #[test]
+    fn test_color_from_html_legacy() {
+        let cases = [
+            ("chucknorris", "#c00000"),
+            ("ninjaturtle", "#00a000"),
+            ("crap", "#c0a000"),
+            ("sick", "#00c000"),
+            ("#1234", "#123400"),
+            ("#abc", "#abc"),
+            ("#ABCDEF", "#abcdef"),
+            ("abc", "#0a0b0c"),
+            ("#0000000000000000ff", "#00f"),
+            ("#10000000020000000030000000", "#000"),
+            ("#123456789abcdef012", "#1278de"),
+            ("  LightGoldenrodYellow\n", "#fafad2"),
+            ("   ", "#000"),
+            ("#", "#000"),
+            ("😀", "#000"),
+        ];
+        for (input, hex) in cases {
+            let color = Color::from_html_legacy(input).unwrap();
+            assert_eq!(color.hex(), hex, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_color_from_html_legacy_err() {
+        assert!(Color::from_html_legacy("").is_err());
+        assert!(Color::from_html_legacy(" Transparent ").is_err());
+    }
+}
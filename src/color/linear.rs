@@ -0,0 +1,178 @@
+use super::channel_ops::impl_channel_ops;
+use crate::{
+    conversion::utils::{gam_srgb, lin_srgb},
+    utils::{lit, Float},
+    Color,
+};
+use core::ops::{Mul, MulAssign};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A color in linear-light sRGB.
+///
+/// The RGB channels (`0.0..=1.0`) are proportional to the intensity of the light, without the
+/// gamma encoding of [`Color`]. Adding, scaling and filtering light is physically correct in
+/// this representation: two lamps add up their linear values, a half as bright light is half
+/// the linear value.
+///
+/// The operators work component-wise like the ones of [`Color`], the results are not clamped.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{color, Color, LinearRgb};
+///
+/// let lamp = color!(#808080).unwrap().to_linear();
+/// assert_eq!((lamp + lamp).to_color().clamp().hex(), "#b0b0b0");
+/// // the gamma encoded sum overshoots to white
+/// assert_eq!((color!(#808080).unwrap() + color!(#808080).unwrap()).clamp().hex(), "#fff");
+///
+/// // a filter that lets half of the light through
+/// let half = color!(#fff).unwrap().to_linear() * LinearRgb::new(0.5, 0.5, 0.5, 1.0);
+/// assert_eq!(half.to_color().hex(), "#bcbcbc");
+///
+/// let surface = LinearRgb::from(color!(#c86432).unwrap());
+/// assert_eq!(Color::from(color!(#fff).unwrap().to_linear() * surface).hex(), "#c86432");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct LinearRgb<T = f64> {
+    rgb: [T; 3],
+    alpha: T,
+}
+
+impl<T: Float> LinearRgb<T> {
+    /// Creates a new [`LinearRgb`] from linear-light channels.
+    pub fn new(r: T, g: T, b: T, alpha: T) -> Self {
+        LinearRgb {
+            rgb: [r, g, b],
+            alpha,
+        }
+    }
+    /// The linear-light RGB channels.
+    pub fn rgb(&self) -> [T; 3] {
+        self.rgb
+    }
+    /// The alpha.
+    pub fn alpha(&self) -> T {
+        self.alpha
+    }
+    /// Converts back to a gamma encoded [`Color`].
+    pub fn to_color(&self) -> Color<T> {
+        Color {
+            rgb: gam_srgb(self.rgb).map(|v| v * lit(255.0)),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<T: Float> Color<T> {
+    /// Converts the color to linear-light sRGB.
+    pub fn to_linear(&self) -> LinearRgb<T> {
+        LinearRgb {
+            rgb: lin_srgb(self.rgb.map(|v| v / lit(255.0))),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<T: Float> From<Color<T>> for LinearRgb<T> {
+    fn from(color: Color<T>) -> Self {
+        color.to_linear()
+    }
+}
+
+impl<T: Float> From<LinearRgb<T>> for Color<T> {
+    fn from(color: LinearRgb<T>) -> Self {
+        color.to_color()
+    }
+}
+
+impl<T: Float> Default for LinearRgb<T> {
+    /// default returns a transparent black color.
+    fn default() -> Self {
+        LinearRgb::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO)
+    }
+}
+
+impl_channel_ops!(LinearRgb);
+
+/// Multiplies the channels and the alphas, e.g. a light filtered by a surface.
+impl<T: Float> Mul for LinearRgb<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a * b)
+    }
+}
+
+impl<T: Float> MulAssign for LinearRgb<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn round_trip(color: Color) -> Color {
+        let [r, g, b] = color.to_linear().to_color().rgb.map(|v| v.round());
+        Color::new(r, g, b, color.alpha)
+    }
+
+    #[test]
+    fn test_to_linear() {
+        let white = Color::new(255.0, 255.0, 255.0, 0.5).to_linear();
+        assert_eq!(white, LinearRgb::new(1.0, 1.0, 1.0, 0.5));
+        assert_eq!(Color::new(0, 0, 0, 1.0).to_linear().rgb(), [0.0; 3]);
+
+        let gray = Color::new(128.0, 128.0, 128.0, 1.0).to_linear();
+        assert!((gray.rgb()[0] - 0.2158605).abs() < 1e-6);
+
+        for rgb in [[200.0, 100.0, 50.0], [1.0, 2.0, 3.0], [255.0, 0.0, 128.0]] {
+            let color = Color::new(rgb[0], rgb[1], rgb[2], 0.8);
+            assert_eq!(round_trip(color), color);
+        }
+    }
+
+    #[test]
+    fn test_linear_light_arithmetic() {
+        let gray = Color::new(128.0, 128.0, 128.0, 1.0);
+        let lamp = gray.to_linear();
+
+        // two lamps add up their light, not their gamma encoded values
+        let lit = (lamp + lamp).to_color();
+        assert_eq!(lit.rgb.map(|v| v.round()), [176.0; 3]);
+        assert_eq!((gray + gray).rgb, [256.0; 3]);
+        assert_eq!(lamp + lamp, 2.0 * lamp);
+        assert_eq!((lamp + lamp - lamp), lamp);
+
+        // half the light is half the linear value
+        let half = (Color::new(255, 255, 255, 1.0).to_linear() / 2.0).to_color();
+        assert_eq!(half.rgb.map(|v| v.round()), [188.0; 3]);
+
+        // a white light keeps the color of the surface, a black one turns it black
+        let surface = Color::new(200.0, 100.0, 50.0, 1.0).to_linear();
+        assert_eq!(LinearRgb::new(1.0, 1.0, 1.0, 1.0) * surface, surface);
+        assert_eq!(
+            LinearRgb::new(0.0, 0.0, 0.0, 1.0) * surface,
+            LinearRgb::new(0.0, 0.0, 0.0, 1.0)
+        );
+
+        let mut light = lamp;
+        light += lamp;
+        light *= 0.5;
+        light -= lamp;
+        light /= 2.0;
+        light *= surface;
+        assert_eq!(light, LinearRgb::new(0.0, 0.0, 0.0, 0.0));
+
+        let sum: LinearRgb = [lamp, lamp, lamp].into_iter().sum();
+        assert_eq!(sum, lamp * 3.0);
+
+        let lamp: LinearRgb<f32> = Color::new(128.0_f32, 128.0, 128.0, 1.0).into();
+        assert_eq!(0.5_f32 * lamp, lamp / 2.0);
+    }
+}
@@ -1,29 +1,66 @@
+pub mod arithmetic;
+pub mod channel_ops;
 pub mod color_channel;
+#[cfg(feature = "alloc")]
 pub mod color_macros;
+pub mod equality;
+#[cfg(feature = "alloc")]
+pub mod evaluate;
+#[cfg(feature = "alloc")]
 pub mod from_num;
+#[cfg(feature = "alloc")]
 pub mod from_space;
+#[cfg(feature = "alloc")]
 pub mod from_str;
+pub mod gamut;
+#[cfg(feature = "alloc")]
+pub mod html_legacy;
+pub mod linear;
+pub mod packed;
+pub mod premultiplied;
+pub mod separation;
+#[cfg(feature = "alloc")]
 pub mod stringify;
 pub mod vec_of;
 
-use std::fmt::Display;
+use crate::utils::Float;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Color is a struct that represents a color.
+///
+/// The color is stored as extended range sRGB, the channels are not clamped to `0..=255`,
+/// so colors from wider spaces survive a round trip. See [`Color::is_in_gamut`] and [`Color::clamp`].
+///
+/// The channels are stored as `f64` by default, `Color<f32>` halves the memory of large buffers.
+/// The conversions, [`Color::vec_of`], the color space structs and the distance functions work
+/// with both, the rest of the API is available on `Color<f64>`.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{Color, Hsl};
+///
+/// let color: Color<f32> = Color::new(255.0, 51.0, 153.0, 1.0);
+/// let hsl = Hsl::from(color);
+/// assert_eq!(hsl.h, 330.0_f32);
+///
+/// let color: Color = color.into();
+/// assert_eq!(color.hex(), "#f39");
+/// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Debug, Clone, Copy)]
-pub struct Color {
-    pub(crate) rgb: [f64; 3],
-    pub(crate) alpha: f64,
+pub struct Color<T = f64> {
+    pub(crate) rgb: [T; 3],
+    pub(crate) alpha: T,
 }
 
-impl Color {
+impl<T: Float> Color<T> {
     /// Creates a new [`Color`].
-    pub fn new<T>(r: T, g: T, b: T, alpha: f64) -> Self
+    pub fn new<U>(r: U, g: U, b: U, alpha: T) -> Self
     where
-        T: Into<f64>,
+        U: Into<T>,
     {
         let r = r.into();
         let g = g.into();
@@ -33,17 +70,55 @@ impl Color {
             alpha,
         }
     }
+    /// Creates a new [`Color`] in a const context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// const DEEP_PINK: Color = Color::new_const(255.0, 20.0, 147.0, 1.0);
+    /// assert_eq!(DEEP_PINK.hex(), "#ff1493");
+    /// ```
+    pub const fn new_const(r: T, g: T, b: T, alpha: T) -> Self {
+        Color {
+            rgb: [r, g, b],
+            alpha,
+        }
+    }
 }
 
-impl Default for Color {
+impl<T: Float> Default for Color<T> {
     /// default returns a black color.
     fn default() -> Self {
-        Color::new(0, 0, 0, 1.0)
+        Color {
+            rgb: [T::ZERO; 3],
+            alpha: T::ONE,
+        }
+    }
+}
+
+impl From<Color<f32>> for Color<f64> {
+    fn from(color: Color<f32>) -> Self {
+        Color {
+            rgb: color.rgb.map(f64::from),
+            alpha: color.alpha as f64,
+        }
+    }
+}
+
+impl From<Color<f64>> for Color<f32> {
+    fn from(color: Color<f64>) -> Self {
+        Color {
+            rgb: color.rgb.map(|v| v as f32),
+            alpha: color.alpha as f32,
+        }
     }
 }
 
-impl Display for Color {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for Color {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.hex())
     }
 }
@@ -58,6 +133,19 @@ mod tests {
         assert_eq!(color, Color::new(0, 0, 0, 1.0));
     }
 
+    #[test]
+    fn test_color_f32() {
+        let color: Color<f32> = Color::new(255.0, 51.0, 153.0, 0.5);
+        assert_eq!(std::mem::size_of_val(&color), 16);
+
+        let color: Color = color.into();
+        assert_eq!(color, Color::new(255.0, 51.0, 153.0, 0.5));
+
+        let color: Color<f32> = color.into();
+        assert_eq!(color, Color::new(255.0_f32, 51.0, 153.0, 0.5));
+    }
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_color_display() {
         let color = Color::new(255, 255, 0, 1.0);
@@ -0,0 +1,233 @@
+use crate::{utils::Float, Color, Error};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Integer pixel formats for [`Color::from_packed`] and [`Color::to_packed`].
+///
+/// The channels are named from the most to the least significant bits,
+/// e.g. `RGBA8888` is `0xRRGGBBAA` and `RGB565` is `0bRRRRRGGGGGGBBBBB`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    /// 8 bit RGBA, `0xRRGGBBAA`.
+    RGBA8888,
+    /// 8 bit ARGB, `0xAARRGGBB`.
+    ARGB8888,
+    /// 8 bit ABGR, `0xAABBGGRR`.
+    ABGR8888,
+    /// 8 bit BGRA, `0xBBGGRRAA`.
+    BGRA8888,
+    /// 16 bit RGB with 5 bits of red and blue and 6 bits of green, opaque.
+    RGB565,
+    /// 4 bit RGBA, `0xRGBA`.
+    RGBA4444,
+    /// 16 bit RGBA, `0xRRRRGGGGBBBBAAAA`.
+    RGBA16161616,
+    /// The Win32 `COLORREF`, `0x00BBGGRR`, opaque.
+    COLORREF,
+}
+
+const R: usize = 0;
+const G: usize = 1;
+const B: usize = 2;
+const A: usize = 3;
+
+impl PixelFormat {
+    /// The channels and their bit widths, from the most to the least significant bits.
+    fn layout(&self) -> &'static [(usize, u32)] {
+        match self {
+            PixelFormat::RGBA8888 => &[(R, 8), (G, 8), (B, 8), (A, 8)],
+            PixelFormat::ARGB8888 => &[(A, 8), (R, 8), (G, 8), (B, 8)],
+            PixelFormat::ABGR8888 => &[(A, 8), (B, 8), (G, 8), (R, 8)],
+            PixelFormat::BGRA8888 => &[(B, 8), (G, 8), (R, 8), (A, 8)],
+            PixelFormat::RGB565 => &[(R, 5), (G, 6), (B, 5)],
+            PixelFormat::RGBA4444 => &[(R, 4), (G, 4), (B, 4), (A, 4)],
+            PixelFormat::RGBA16161616 => &[(R, 16), (G, 16), (B, 16), (A, 16)],
+            PixelFormat::COLORREF => &[(B, 8), (G, 8), (R, 8)],
+        }
+    }
+    /// Number of bits used by the format.
+    pub fn bits(&self) -> u32 {
+        self.layout().iter().map(|(_, bits)| bits).sum()
+    }
+}
+
+impl Color {
+    /// Unpacks an integer pixel.
+    ///
+    /// Formats without an alpha channel give opaque colors. The value must fit the bits of the
+    /// format, e.g. `0..=0xffff` for `RGB565` and `0..=0xffffff` for `COLORREF`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{Color, PixelFormat};
+    ///
+    /// let color = Color::from_packed(0xff339980_u32, PixelFormat::RGBA8888).unwrap();
+    /// assert_eq!(color.rgba(), "rgba(255, 51, 153, 0.5)");
+    ///
+    /// let color = Color::from_packed(0x9933ff_u32, PixelFormat::COLORREF).unwrap();
+    /// assert_eq!(color.hex(), "#f39");
+    ///
+    /// let color = Color::from_packed(0xf800_u16, PixelFormat::RGB565).unwrap();
+    /// assert_eq!(color.hex(), "#f00");
+    ///
+    /// assert!(Color::from_packed(0x10000_u32, PixelFormat::RGB565).is_err());
+    /// ```
+    pub fn from_packed<V>(value: V, format: PixelFormat) -> Result<Self, Error>
+    where
+        V: Into<u64>,
+    {
+        let mut value = value.into();
+        if format.bits() < 64 && value >> format.bits() != 0 {
+            return Err(Error::InvalidParamsError(
+                "packed value does not fit the pixel format".into(),
+            ));
+        }
+
+        let mut channels = [0.0, 0.0, 0.0, 1.0];
+        for &(channel, bits) in format.layout().iter().rev() {
+            let max = ((1_u64 << bits) - 1) as f64;
+            let scale = if channel == A { 1.0 } else { 255.0 };
+            channels[channel] = (value & ((1 << bits) - 1)) as f64 / max * scale;
+            value >>= bits;
+        }
+
+        let [r, g, b, alpha] = channels;
+        Ok(Color::new(r, g, b, alpha))
+    }
+    /// Packs the color into an integer pixel.
+    ///
+    /// The channels are clamped into the sRGB gamut and rounded to the bits of the format.
+    /// Formats without an alpha channel drop the alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, PixelFormat};
+    ///
+    /// let color = color!(rgba(255, 51, 153, 0.5)).unwrap();
+    /// assert_eq!(color.to_packed(PixelFormat::ARGB8888), 0x80ff3399);
+    /// assert_eq!(color.to_packed(PixelFormat::RGBA4444), 0xf398);
+    /// assert_eq!(color.to_packed(PixelFormat::COLORREF), 0x9933ff);
+    /// ```
+    pub fn to_packed(&self, format: PixelFormat) -> u64 {
+        let channels = [self.rgb[0], self.rgb[1], self.rgb[2], self.alpha * 255.0];
+        format.layout().iter().fold(0, |packed, &(channel, bits)| {
+            let max = ((1_u64 << bits) - 1) as f64;
+            let value = (channels[channel].clamp(0.0, 255.0) / 255.0 * max).round();
+            (packed << bits) | value as u64
+        })
+    }
+}
+
+impl<T: Float> From<[u8; 4]> for Color<T> {
+    /// Converts RGBA bytes, the alpha is scaled from `0..=255`.
+    fn from([r, g, b, alpha]: [u8; 4]) -> Self {
+        let [r, g, b, alpha] = [r, g, b, alpha].map(|v| T::from_f64(v as f64));
+        Color::new(r, g, b, alpha / T::from_f64(255.0))
+    }
+}
+
+impl<T: Float> From<[u8; 3]> for Color<T> {
+    /// Converts RGB bytes to an opaque color.
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Color::from([r, g, b, 255])
+    }
+}
+
+impl<T: Float> From<(u8, u8, u8, u8)> for Color<T> {
+    fn from((r, g, b, alpha): (u8, u8, u8, u8)) -> Self {
+        Color::from([r, g, b, alpha])
+    }
+}
+
+impl<T: Float> From<(u8, u8, u8)> for Color<T> {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Color::from([r, g, b, 255])
+    }
+}
+
+impl<T: Float> From<Color<T>> for [u8; 4] {
+    /// Converts to RGBA bytes, see [`Color::to_rgba8`].
+    fn from(color: Color<T>) -> Self {
+        color.to_rgba8()
+    }
+}
+
+impl<T: Float> From<Color<T>> for (u8, u8, u8, u8) {
+    fn from(color: Color<T>) -> Self {
+        let [r, g, b, alpha] = color.to_rgba8();
+        (r, g, b, alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use PixelFormat::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_packed_round_trip() {
+        let color = color!(rgba(255, 51, 153, 0.8)).unwrap();
+        for (format, packed) in [
+            (RGBA8888, 0xff3399cc),
+            (ARGB8888, 0xccff3399),
+            (ABGR8888, 0xcc9933ff),
+            (BGRA8888, 0x9933ffcc),
+            (RGBA16161616, 0xffff_3333_9999_cccc),
+        ] {
+            assert_eq!(color.to_packed(format), packed);
+            assert_eq!(Color::from_packed(packed, format).unwrap(), color);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_packed_low_bits() {
+        let color = color!(#f39).unwrap();
+        assert_eq!(color.to_packed(RGB565), 0xf9b3);
+        assert_eq!(
+            Color::from_packed(0xf9b3_u16, RGB565).unwrap().hex(),
+            "#ff359c"
+        );
+
+        let color = color!(rgba(255, 51, 153, 0.4)).unwrap();
+        assert_eq!(color.to_packed(RGBA4444), 0xf396);
+        assert_eq!(
+            Color::from_packed(0xf396_u16, RGBA4444).unwrap().rgba(),
+            "rgba(255, 51, 153, 0.4)"
+        );
+
+        assert_eq!(
+            Color::new(300.0, -5.0, 0.0, 1.0).to_packed(COLORREF),
+            0x0000ff
+        );
+    }
+
+    #[test]
+    fn test_packed_err() {
+        assert!(Color::from_packed(0x1000000_u32, COLORREF).is_err());
+        assert!(Color::from_packed(0x1_0000_0000_u64, RGBA8888).is_err());
+        assert!(Color::from_packed(u64::MAX, RGBA16161616).is_ok());
+        assert_eq!(RGB565.bits(), 16);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_byte_conversions() {
+        let color: Color = [255, 51, 153, 51].into();
+        assert_eq!(color.rgba(), "rgba(255, 51, 153, 0.2)");
+        assert_eq!(<[u8; 4]>::from(color), [255, 51, 153, 51]);
+        assert_eq!(<(u8, u8, u8, u8)>::from(color), (255, 51, 153, 51));
+
+        assert_eq!(Color::<f64>::from((255, 51, 153)), color!(#f39).unwrap());
+        assert_eq!(Color::<f64>::from([255, 51, 153]), color!(#f39).unwrap());
+        assert_eq!(
+            Color::<f64>::from((255, 51, 153, 255)),
+            color!(#f39).unwrap()
+        );
+    }
+}
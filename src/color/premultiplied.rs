@@ -0,0 +1,220 @@
+use super::channel_ops::impl_channel_ops;
+use crate::{utils::Float, Color};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A color with premultiplied alpha.
+///
+/// The RGB channels (`0.0..=255.0`) are already multiplied by the alpha, e.g. a half transparent
+/// red is `(127.5, 0, 0, 0.5)`. Sums, scaling and interpolation of premultiplied colors are
+/// correct also for translucent colors, which makes it the representation for compositing.
+///
+/// Unpremultiplying a fully transparent color gives transparent black, there is no color left
+/// to recover.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{Color, PremultipliedColor};
+///
+/// let color = Color::new(255.0, 0.0, 0.0, 0.5);
+/// let premultiplied = color.premultiply();
+/// assert_eq!(premultiplied.rgb(), [127.5, 0.0, 0.0]);
+/// assert_eq!(premultiplied.unpremultiply(), color);
+///
+/// let transparent = PremultipliedColor::new(0.0, 0.0, 0.0, 0.0);
+/// assert_eq!(transparent.unpremultiply(), Color::new(0.0, 0.0, 0.0, 0.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct PremultipliedColor<T = f64> {
+    rgb: [T; 3],
+    alpha: T,
+}
+
+impl<T: Float> PremultipliedColor<T> {
+    /// Creates a new [`PremultipliedColor`] from premultiplied channels.
+    pub fn new(r: T, g: T, b: T, alpha: T) -> Self {
+        PremultipliedColor {
+            rgb: [r, g, b],
+            alpha,
+        }
+    }
+    /// The premultiplied RGB channels.
+    pub fn rgb(&self) -> [T; 3] {
+        self.rgb
+    }
+    /// The alpha.
+    pub fn alpha(&self) -> T {
+        self.alpha
+    }
+    /// Converts back to a [`Color`] with straight alpha.
+    pub fn unpremultiply(&self) -> Color<T> {
+        if self.alpha == T::ZERO {
+            return Color::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+        }
+        Color {
+            rgb: self.rgb.map(|v| v / self.alpha),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl PremultipliedColor {
+    /// Composites the color over a backdrop (Porter-Duff source over).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::Color;
+    ///
+    /// let source = Color::new(255.0, 0.0, 0.0, 0.5).premultiply();
+    /// let backdrop = Color::new(0.0, 0.0, 255.0, 1.0).premultiply();
+    /// let color = source.over(&backdrop).unpremultiply();
+    /// assert_eq!(color, Color::new(127.5, 0.0, 127.5, 1.0));
+    /// ```
+    pub fn over(&self, backdrop: &PremultipliedColor) -> Self {
+        *self + *backdrop * (1.0 - self.alpha)
+    }
+    /// Mix two premultiplied colors with a weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to mix with.
+    /// * `weight` - The weight of the new color to mix with. 0.0 is all the original color, 1.0 is all the new color.
+    pub fn mix_with(&self, new_color: &PremultipliedColor, weight: f64) -> Self {
+        let weight = weight.clamp(0.0, 1.0);
+        *self * (1.0 - weight) + *new_color * weight
+    }
+}
+
+impl<T: Float> Color<T> {
+    /// Converts the color to premultiplied alpha.
+    pub fn premultiply(&self) -> PremultipliedColor<T> {
+        PremultipliedColor {
+            rgb: self.rgb.map(|v| v * self.alpha),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl Color {
+    /// Mix two colors with a weight, interpolating in premultiplied alpha.
+    ///
+    /// Unlike [`Color::mix_with`], a transparent color does not tint the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, Color};
+    ///
+    /// let transparent = Color::new(0.0, 0.0, 255.0, 0.0);
+    /// let color = color!(#f00).unwrap().mix_with_premultiplied(&transparent, 0.5);
+    /// assert_eq!(color.rgba(), "rgba(255, 0, 0, 0.5)");
+    /// assert_eq!(color!(#f00).unwrap().mix_with(&transparent, 0.5).rgba(), "rgba(128, 0, 128, 0.5)");
+    /// ```
+    pub fn mix_with_premultiplied(&self, new_color: &Color, weight: f64) -> Self {
+        self.premultiply()
+            .mix_with(&new_color.premultiply(), weight)
+            .unpremultiply()
+    }
+}
+
+impl<T: Float> From<Color<T>> for PremultipliedColor<T> {
+    fn from(color: Color<T>) -> Self {
+        color.premultiply()
+    }
+}
+
+impl<T: Float> From<PremultipliedColor<T>> for Color<T> {
+    fn from(color: PremultipliedColor<T>) -> Self {
+        color.unpremultiply()
+    }
+}
+
+impl<T: Float> Default for PremultipliedColor<T> {
+    /// default returns a transparent black color.
+    fn default() -> Self {
+        PremultipliedColor::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO)
+    }
+}
+
+impl_channel_ops!(PremultipliedColor);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_premultiply() {
+        let color = Color::new(200.0, 100.0, 50.0, 0.5);
+        let premultiplied = color.premultiply();
+        assert_eq!(
+            premultiplied,
+            PremultipliedColor::new(100.0, 50.0, 25.0, 0.5)
+        );
+        assert_eq!(Color::from(premultiplied), color);
+        assert_eq!(
+            PremultipliedColor::from(color!(#fff).unwrap()).rgb(),
+            [255.0; 3]
+        );
+
+        let transparent = Color::new(200.0, 100.0, 50.0, 0.0).premultiply();
+        assert_eq!(transparent, PremultipliedColor::default());
+        assert_eq!(transparent.unpremultiply(), Color::new(0, 0, 0, 0.0));
+    }
+
+    #[test]
+    fn test_premultiplied_average() {
+        let colors = [
+            Color::new(255.0, 0.0, 0.0, 1.0),
+            Color::new(0.0, 0.0, 255.0, 0.0),
+        ];
+        let average = colors
+            .iter()
+            .map(Color::premultiply)
+            .sum::<PremultipliedColor>()
+            / 2.0;
+        assert_eq!(average.unpremultiply(), Color::new(255.0, 0.0, 0.0, 0.5));
+        assert_eq!(average - average, PremultipliedColor::default());
+        assert_eq!(2.0 * average, average * 2.0);
+
+        let mut color = average;
+        color *= 2.0;
+        color -= average;
+        color /= 2.0;
+        color += average;
+        assert_eq!(color, average * 1.5);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_over() {
+        let backdrop = color!(#00f).unwrap().premultiply();
+        assert_eq!(
+            color!(#f00).unwrap().premultiply().over(&backdrop),
+            color!(#f00).unwrap().premultiply()
+        );
+        assert_eq!(PremultipliedColor::default().over(&backdrop), backdrop);
+
+        let source = Color::new(255.0, 0.0, 0.0, 0.5).premultiply();
+        let color = source.over(&Color::new(0.0, 0.0, 255.0, 0.5).premultiply());
+        assert_eq!(color.alpha(), 0.75);
+        assert_eq!(color.rgb(), [127.5, 0.0, 63.75]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_mix_with_premultiplied() {
+        let color1 = color!(rgba(255, 0, 0, 0.2)).unwrap();
+        let color2 = color!(rgba(0, 0, 255, 0.8)).unwrap();
+        let color = color1.mix_with_premultiplied(&color2, 0.5);
+        assert_eq!(color.rgba(), "rgba(51, 0, 204, 0.5)");
+
+        assert!(color1
+            .mix_with_premultiplied(&color2, 0.0)
+            .approx_eq(&color1, 1e-9));
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(feature = "alloc")]
+use crate::{conversion::cmyk::cmyk2rgb_with, ColorSpace, Error};
+use crate::{conversion::cmyk::rgb2cmyk_with, Color, SeparationOptions};
+
+/// Print-oriented CMYK separation.
+impl Color {
+    /// Separate the color into CMYK inks with the given [`SeparationOptions`].
+    ///
+    /// Returns the `[c, m, y, k]` ink amounts, each between 0.0 and 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, BlackGeneration, SeparationMode, SeparationOptions};
+    ///
+    /// let color = color!(#808080).unwrap();
+    ///
+    /// let options = SeparationOptions {
+    ///     black_generation: BlackGeneration::None,
+    ///     ..Default::default()
+    /// };
+    /// let cmyk = color.to_cmyk_with(&options);
+    /// assert_eq!(cmyk[3], 0.0);
+    ///
+    /// let options = SeparationOptions {
+    ///     mode: SeparationMode::Ucr,
+    ///     rich_black: Some([0.6, 0.4, 0.4, 1.0]),
+    ///     ..Default::default()
+    /// };
+    /// let cmyk = color!(#000).unwrap().to_cmyk_with(&options);
+    /// assert_eq!(cmyk, [0.6, 0.4, 0.4, 1.0]);
+    /// ```
+    pub fn to_cmyk_with(&self, options: &SeparationOptions) -> [f64; 4] {
+        rgb2cmyk_with(self.rgb, options)
+    }
+    /// Create a color from CMYK inks separated with the given [`SeparationOptions`].
+    ///
+    /// This is the inverse of [`Color::to_cmyk_with`], the rich black recipe maps back to pure black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{Color, SeparationOptions};
+    ///
+    /// let options = SeparationOptions {
+    ///     rich_black: Some([0.6, 0.4, 0.4, 1.0]),
+    ///     ..Default::default()
+    /// };
+    /// let color = Color::from_cmyk_with(0.6, 0.4, 0.4, 1.0, &options).unwrap();
+    /// assert_eq!(color.hex(), "#000");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_cmyk_with(
+        c: f64,
+        m: f64,
+        y: f64,
+        k: f64,
+        options: &SeparationOptions,
+    ) -> Result<Self, Error> {
+        let cmyk = [c, m, y, k];
+        ColorSpace::CMYK.valid(&cmyk)?;
+        let [r, g, b] = cmyk2rgb_with(cmyk, options);
+        Ok(Color::new(r, g, b, 1.0))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_separation_round_trip() {
+        let options = SeparationOptions {
+            black_generation: BlackGeneration::Heavy,
+            strength: 0.8,
+            total_ink_limit: 3.2,
+            ..Default::default()
+        };
+        for hex in ["#f39", "#a16e57", "#2a2a2a", "#fff"] {
+            let color = Color::from_hex(hex).unwrap();
+            let cmyk = color.to_cmyk_with(&options);
+            assert!(cmyk.iter().sum::<f64>() <= 3.2 + 1e-9);
+            let color2 = Color::from_cmyk_with(cmyk[0], cmyk[1], cmyk[2], cmyk[3], &options);
+            assert_eq!(color2.unwrap().hex(), color.hex());
+        }
+    }
+
+    #[test]
+    fn test_separation_default_matches_cmyk() {
+        let color = color!(#a16e57).unwrap();
+        let cmyk = color.to_cmyk_with(&SeparationOptions::default());
+        assert_eq!(cmyk.to_vec(), color.vec_of(ColorSpace::CMYK));
+    }
+}
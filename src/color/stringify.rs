@@ -7,6 +7,8 @@ use crate::{
         hsv::rgb2hsv,
         hwb::rgb2hwb,
         lab::rgb2lab,
+        oklab::{lab2lch, rgb2oklab},
+        predefined::rgb2predefined,
         xyz::rgb2xyz,
         ycbcr::rgb2ycbcr,
         yiq::rgb2yiq,
@@ -14,7 +16,11 @@ use crate::{
     },
     data::name_of_hex,
     utils::{hex::simplify_hex, round},
-    Color,
+    Color, ColorSpace, Error, PredefinedSpace,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
 };
 
 /// Stringify a color to a string.
@@ -52,13 +58,30 @@ impl Color {
     /// assert_eq!(color.hex_full(), "#ff00ff");
     /// ```
     pub fn hex_full(self) -> String {
-        if self.alpha == 1.0 {
+        let alpha = self.string_alpha();
+        if alpha == 1.0 {
             rgb2hex(self.rgb)
         } else {
             let [r, g, b] = self.rgb;
-            rgba2hex([r, g, b, self.alpha])
+            rgba2hex([r, g, b, alpha])
         }
     }
+    /// `rgb:` string of the color for X11, e.g. in Xresources.
+    ///
+    /// The channels have two hex digits, the alpha is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255, 128, 0, 0.5);
+    /// assert_eq!(color.x11_rgb(), "rgb:ff/80/00");
+    /// ```
+    pub fn x11_rgb(self) -> String {
+        let [r, g, b] = self.rgb.map(|v| round(v, 0) as u8);
+        format!("rgb:{:02x}/{:02x}/{:02x}", r, g, b)
+    }
     /// `rgb` string of the color
     ///
     /// # Examples
@@ -71,9 +94,9 @@ impl Color {
     /// ```
     pub fn rgb(self) -> String {
         let [r, g, b] = self.rgb;
-        let r = r.round() as u8;
-        let g = g.round() as u8;
-        let b = b.round() as u8;
+        let r = round(r, 0) as u8;
+        let g = round(g, 0) as u8;
+        let b = round(b, 0) as u8;
         format!("rgb({}, {}, {})", r, g, b)
     }
     /// `rgba` string of the color
@@ -88,10 +111,16 @@ impl Color {
     /// ```
     pub fn rgba(self) -> String {
         let [r, g, b] = self.rgb;
-        let r = r.round() as u8;
-        let g = g.round() as u8;
-        let b = b.round() as u8;
-        format!("rgba({}, {}, {}, {})", r, g, b, self.alpha())
+        let r = round(r, 0) as u8;
+        let g = round(g, 0) as u8;
+        let b = round(b, 0) as u8;
+        format!(
+            "rgba({}, {}, {}, {})",
+            r,
+            g,
+            b,
+            round(self.string_alpha(), 2)
+        )
     }
     /// `hsl` string of the color
     ///
@@ -104,7 +133,7 @@ impl Color {
     /// assert_eq!(color.hsl(), "hsl(0, 0%, 100%)");
     /// ```
     pub fn hsl(self) -> String {
-        let hsl = rgb2hsl(&self.rgb);
+        let hsl = rgb2hsl(self.rgb);
         let h = round(hsl[0], 0);
         let s = round(hsl[1] * 100.0, 0);
         let l = round(hsl[2] * 100.0, 0);
@@ -121,11 +150,17 @@ impl Color {
     /// assert_eq!(color.hsla(), "hsla(0, 0%, 100%, 0.3)");
     /// ```
     pub fn hsla(self) -> String {
-        let hsl = rgb2hsl(&self.rgb);
+        let hsl = rgb2hsl(self.rgb);
         let h = round(hsl[0], 0);
         let s = round(hsl[1] * 100.0, 0);
         let l = round(hsl[2] * 100.0, 0);
-        format!("hsla({}, {}%, {}%, {})", h, s, l, self.alpha())
+        format!(
+            "hsla({}, {}%, {}%, {})",
+            h,
+            s,
+            l,
+            round(self.string_alpha(), 2)
+        )
     }
     /// `hsv` string of the color
     ///
@@ -138,7 +173,7 @@ impl Color {
     /// assert_eq!(color.hsv(), "hsv(0, 0%, 100%)");
     /// ```
     pub fn hsv(self) -> String {
-        let hsv = rgb2hsv(&self.rgb);
+        let hsv = rgb2hsv(self.rgb);
         let h = round(hsv[0], 0);
         let s = round(hsv[1] * 100.0, 0);
         let v = round(hsv[2] * 100.0, 0);
@@ -155,7 +190,7 @@ impl Color {
     /// assert_eq!(color.hsi(), "hsi(0, 0%, 100%)");
     /// ```
     pub fn hsi(self) -> String {
-        let hsi = rgb2hsi(&self.rgb);
+        let hsi = rgb2hsi(self.rgb);
         let h = round(hsi[0], 0);
         let s = round(hsi[1] * 100.0, 2);
         let i = round(hsi[2] * 100.0, 2);
@@ -172,7 +207,7 @@ impl Color {
     /// assert_eq!(color.hwb(), "hwb(0, 100%, 0%)");
     /// ```
     pub fn hwb(self) -> String {
-        let hwb = rgb2hwb(&self.rgb);
+        let hwb = rgb2hwb(self.rgb);
         let h = round(hwb[0], 0);
         let w = round(hwb[1] * 100.0, 0);
         let b = round(hwb[2] * 100.0, 0);
@@ -189,10 +224,7 @@ impl Color {
     /// assert_eq!(color.cmyk(), "cmyk(0%, 0%, 0%, 0%)");
     /// ```
     pub fn cmyk(self) -> String {
-        let cmyk = rgb2cmyk(&self.rgb)
-            .iter()
-            .map(|&v| round(v * 100.0, 0))
-            .collect::<Vec<_>>();
+        let cmyk = rgb2cmyk(self.rgb).map(|v| round(v * 100.0, 0));
         format!(
             "cmyk({}%, {}%, {}%, {}%)",
             cmyk[0], cmyk[1], cmyk[2], cmyk[3]
@@ -209,10 +241,7 @@ impl Color {
     /// assert_eq!(color.xyz(), "xyz(0.412391, 0.212639, 0.019331)");
     /// ```
     pub fn xyz(self) -> String {
-        let xyz = rgb2xyz(&self.rgb)
-            .iter()
-            .map(|&v| round(v, 6))
-            .collect::<Vec<_>>();
+        let xyz = rgb2xyz(self.rgb).map(|v| round(v, 6));
         format!("xyz({}, {}, {})", xyz[0], xyz[1], xyz[2])
     }
     /// `yiq` string of the color
@@ -226,10 +255,7 @@ impl Color {
     /// assert_eq!(color.yiq(), "yiq(0.299, 0.59572, 0.21146)");
     /// ```
     pub fn yiq(self) -> String {
-        let yiq = rgb2yiq(&self.rgb)
-            .iter()
-            .map(|&v| round(v, 5))
-            .collect::<Vec<_>>();
+        let yiq = rgb2yiq(self.rgb).map(|v| round(v, 5));
         format!("yiq({}, {}, {})", yiq[0], yiq[1], yiq[2])
     }
     /// `yuv` string of the color
@@ -243,10 +269,7 @@ impl Color {
     /// assert_eq!(color.yuv(), "yuv(0.299, -0.1471, 0.6148)");
     /// ```
     pub fn yuv(self) -> String {
-        let yuv = rgb2yuv(&self.rgb)
-            .iter()
-            .map(|&v| round(v, 4))
-            .collect::<Vec<_>>();
+        let yuv = rgb2yuv(self.rgb).map(|v| round(v, 4));
         format!("yuv({}, {}, {})", yuv[0], yuv[1], yuv[2])
     }
     /// `lab` string of the color
@@ -260,12 +283,51 @@ impl Color {
     /// assert_eq!(color.lab(), "lab(97.61, -15.75, 93.39)");
     /// ```
     pub fn lab(self) -> String {
-        let lab = rgb2lab(&self.rgb)
-            .iter()
-            .map(|&v| round(v, 2))
-            .collect::<Vec<_>>();
+        let lab = rgb2lab(self.rgb).map(|v| round(v, 2));
         format!("lab({}, {}, {})", lab[0], lab[1], lab[2])
     }
+    /// `lch` string of the color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 255.0, 0.0, 1.0);
+    /// assert_eq!(color.lch(), "lch(97.61, 94.71, 99.57)");
+    /// ```
+    pub fn lch(self) -> String {
+        let lch = lab2lch(rgb2lab(self.rgb)).map(|v| round(v, 2));
+        format!("lch({}, {}, {})", lch[0], lch[1], lch[2])
+    }
+    /// `oklab` string of the color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 255.0, 0.0, 1.0);
+    /// assert_eq!(color.oklab(), "oklab(0.968, -0.0714, 0.1986)");
+    /// ```
+    pub fn oklab(self) -> String {
+        let oklab = rgb2oklab(self.rgb).map(|v| round(v, 4));
+        format!("oklab({}, {}, {})", oklab[0], oklab[1], oklab[2])
+    }
+    /// `oklch` string of the color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::Color;
+    ///
+    /// let color = Color::new(255.0, 255.0, 0.0, 1.0);
+    /// assert_eq!(color.oklch(), "oklch(0.968, 0.211, 109.77)");
+    /// ```
+    pub fn oklch(self) -> String {
+        let [l, c, h] = lab2lch(rgb2oklab(self.rgb));
+        format!("oklch({}, {}, {})", round(l, 4), round(c, 4), round(h, 2))
+    }
     /// `YCbCr` string of the color
     ///
     /// # Examples
@@ -277,10 +339,7 @@ impl Color {
     /// assert_eq!(color.ycbcr(), "YCbCr(225.93, 0.5755, 148.7269)");
     /// ```
     pub fn ycbcr(self) -> String {
-        let ycbcr = rgb2ycbcr(&self.rgb)
-            .iter()
-            .map(|&v| round(v, 4))
-            .collect::<Vec<_>>();
+        let ycbcr = rgb2ycbcr(self.rgb).map(|v| round(v, 4));
         format!("YCbCr({}, {}, {})", ycbcr[0], ycbcr[1], ycbcr[2])
     }
     /// `name` of the color
@@ -294,17 +353,17 @@ impl Color {
     /// ```rust
     /// use color_art::{Color, color};
     ///
-    /// let color = color!(#ffffff);
+    /// let color = color!(#ffffff).unwrap();
     /// assert_eq!(color.name(), "white");
     ///
-    /// let color = color!(#f8df72);
+    /// let color = color!(#f8df72).unwrap();
     /// assert_eq!(color.name(), "茉莉黄");
     ///
     /// let color = Color::new(42, 42, 42, 1.0);
     /// assert_eq!(color.name(), "#2a2a2a");
     /// ```
     pub fn name(self) -> String {
-        if self.alpha == 1.0 {
+        if self.string_alpha() == 1.0 {
             let hex = rgb2hex(self.rgb);
             match name_of_hex(&hex) {
                 Some(name) => name.to_string(),
@@ -314,6 +373,105 @@ impl Color {
             self.hex()
         }
     }
+    /// CSS `color()` string of the color in a predefined color space.
+    ///
+    /// The values are rounded to 6 decimals and are not clamped, wide gamut colors keep their
+    /// values outside of `0..=1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, PredefinedSpace};
+    ///
+    /// let color = Color::new(255, 0, 0, 0.5);
+    /// assert_eq!(color.css_color(PredefinedSpace::Srgb), "color(srgb 1 0 0 / 0.5)");
+    /// assert_eq!(
+    ///     color.css_color(PredefinedSpace::DisplayP3),
+    ///     "color(display-p3 0.917488 0.200287 0.138561 / 0.5)"
+    /// );
+    /// ```
+    pub fn css_color(self, space: PredefinedSpace) -> String {
+        let [a, b, c] = rgb2predefined(space, self.rgb).map(|v| round(v, 6));
+        let alpha = self.string_alpha();
+        if alpha == 1.0 {
+            format!("color({} {} {} {})", space, a, b, c)
+        } else {
+            format!("color({} {} {} {} / {})", space, a, b, c, round(alpha, 2))
+        }
+    }
+    /// String of the color in the given color space.
+    ///
+    /// User-defined color spaces are formatted with [`ColorSpaceDef::format`](crate::ColorSpaceDef::format),
+    /// the unknown color space falls back to the `hex` string, see [`Color::try_stringify`] for
+    /// an error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, ColorSpace};
+    ///
+    /// let color = Color::new(255, 51, 153, 1.0);
+    /// assert_eq!(color.stringify(ColorSpace::HSL), "hsl(330, 100%, 60%)");
+    /// assert_eq!(color.stringify("cmyk"), "cmyk(0%, 80%, 40%, 0%)");
+    /// ```
+    pub fn stringify(self, color_space: impl Into<ColorSpace>) -> String {
+        match color_space.into() {
+            ColorSpace::RGB => self.rgb(),
+            ColorSpace::RGBA => self.rgba(),
+            ColorSpace::HSI => self.hsi(),
+            ColorSpace::HSL => self.hsl(),
+            ColorSpace::HSLA => self.hsla(),
+            ColorSpace::HSV => self.hsv(),
+            ColorSpace::HEX | ColorSpace::HEXA | ColorSpace::Unknown => self.hex(),
+            ColorSpace::HWB => self.hwb(),
+            ColorSpace::CMYK => self.cmyk(),
+            ColorSpace::XYZ => self.xyz(),
+            ColorSpace::YIQ => self.yiq(),
+            ColorSpace::YUV => self.yuv(),
+            ColorSpace::YCbCr => self.ycbcr(),
+            ColorSpace::Lab => self.lab(),
+            ColorSpace::Lch => self.lch(),
+            ColorSpace::Oklab => self.oklab(),
+            ColorSpace::Oklch => self.oklch(),
+            #[cfg(feature = "std")]
+            ColorSpace::Custom(space) => self
+                .try_stringify(ColorSpace::Custom(space))
+                .unwrap_or_else(|_| self.hex()),
+        }
+    }
+    /// String of the color in the given color space, or an error for the unknown color space
+    /// and a custom space that is not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{Color, ColorSpace};
+    ///
+    /// let color = Color::new(255, 51, 153, 1.0);
+    /// assert_eq!(color.try_stringify("hsl").unwrap(), "hsl(330, 100%, 60%)");
+    /// assert!(color.try_stringify("hsx").is_err());
+    /// ```
+    pub fn try_stringify(self, color_space: impl Into<ColorSpace>) -> Result<String, Error> {
+        match color_space.into() {
+            #[cfg(feature = "std")]
+            ColorSpace::Custom(space) => {
+                let def = space.def()?;
+                let values = space.rgb_to_values(self.rgb)?;
+                Ok(def.format(&values[..def.channel_ranges().len()]))
+            }
+            ColorSpace::Unknown => Err(Error::InvalidParamsError(
+                "Unknown color space has no string".into(),
+            )),
+            color_space => Ok(self.stringify(color_space)),
+        }
+    }
+    /// The alpha of the strings.
+    ///
+    /// The arithmetic operators do not clamp, e.g. a sum of opaque colors has an alpha above
+    /// `1.0`. The strings clamp it to `0.0..=1.0` like the channels are clamped to `0..=255`.
+    fn string_alpha(&self) -> f64 {
+        self.alpha.clamp(0.0, 1.0)
+    }
 }
 
 #[cfg(test)]
@@ -350,6 +508,8 @@ mod tests {
         assert_eq!(color.xyz(), "xyz(0, 0, 0)");
         assert_eq!(color.ycbcr(), "YCbCr(0, 128, 128)");
         assert_eq!(color.lab(), "lab(0, 0, 0)");
+        assert_eq!(color.lch(), "lch(0, 0, 0)");
+        assert_eq!(color.oklch(), "oklch(0, 0, 0)");
         assert_eq!(color.name(), "#0003");
 
         let color = Color::new(0.0, 128.0, 128.0, 1.0);
@@ -365,6 +525,9 @@ mod tests {
         assert_eq!(color.xyz(), "xyz(0.116147, 0.16996, 0.230912)");
         assert_eq!(color.ycbcr(), "YCbCr(89.728, 149.5854, 64.0239)");
         assert_eq!(color.lab(), "lab(47.99, -30.39, -8.98)");
+        assert_eq!(color.lch(), "lch(47.99, 31.69, 196.45)");
+        assert_eq!(color.oklab(), "oklab(0.5431, -0.0896, -0.0236)");
+        assert_eq!(color.oklch(), "oklch(0.5431, 0.0927, 194.77)");
         assert_eq!(color.name(), "teal");
 
         let color = Color::new(161, 110, 87, 1.0);
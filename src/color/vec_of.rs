@@ -1,6 +1,10 @@
-use crate::{conversion, Color, ColorSpace};
+#[cfg(feature = "alloc")]
+use crate::Error;
+use crate::{conversion, utils::Float, Color, ColorSpace};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
-impl Color {
+impl<T: Float> Color<T> {
     /// Get the color space vector of the color instance.
     ///
     /// ⚗️ **Experimental**: This method is experimental and may change frequently in the future.
@@ -10,56 +14,148 @@ impl Color {
     /// ```
     /// use color_art::{color, ColorSpace};
     ///
-    /// let color = color!(rgb(255, 51, 153));
+    /// let color = color!(rgb(255, 51, 153)).unwrap();
     /// let vec = color.vec_of(ColorSpace::RGB);
     /// assert_eq!(vec, vec![255.0, 51.0, 153.0]);
     ///
     /// let vec = color.vec_of(ColorSpace::HSV);
     /// assert_eq!(vec, vec![330.0, 0.8, 1.0]);
     /// ```
-    pub fn vec_of(&self, color_space: impl Into<ColorSpace>) -> Vec<f64> {
-        let color = self.rgb.to_vec();
-        let color_space = color_space.into();
+    ///
+    /// The unknown color space has no values, see [`Color::try_vec_of`] for an error instead.
+    #[cfg(feature = "alloc")]
+    pub fn vec_of(&self, color_space: impl Into<ColorSpace>) -> Vec<T> {
+        self.try_vec_of(color_space).unwrap_or_default()
+    }
+    /// Get the color space vector of the color instance, or an error for the unknown color
+    /// space and a custom space that is not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use color_art::{color, ColorSpace};
+    ///
+    /// let color = color!(rgb(255, 51, 153)).unwrap();
+    /// assert_eq!(color.try_vec_of(ColorSpace::HSV).unwrap(), vec![330.0, 0.8, 1.0]);
+    /// assert!(color.try_vec_of(ColorSpace::Unknown).is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_vec_of(&self, color_space: impl Into<ColorSpace>) -> Result<Vec<T>, Error> {
+        match color_space.into() {
+            #[cfg(feature = "std")]
+            ColorSpace::Custom(space) => {
+                let values = space.rgb_to_values(self.rgb.map(T::to_f64))?;
+                Ok(values[..space.value_count()?]
+                    .iter()
+                    .map(|&v| T::from_f64(v))
+                    .collect())
+            }
+            ColorSpace::Unknown => Err(Error::InvalidParamsError(
+                "Unknown color space has no values".into(),
+            )),
+            color_space => Ok(self.values_of(color_space)[..color_space.value_count()].to_vec()),
+        }
+    }
+    /// Get the color space values of the color instance without allocating.
+    ///
+    /// Only the first `color_space.value_count()` values are meaningful, the rest are `0.0`.
+    /// [`ColorSpace::Unknown`] and an unregistered custom space have no values.
+    pub(crate) fn values_of(&self, color_space: ColorSpace) -> [T; 4] {
+        let color = self.rgb;
+        let pad = |[a, b, c]: [T; 3]| [a, b, c, T::ZERO];
+        let with_alpha = |[a, b, c]: [T; 3]| [a, b, c, self.alpha];
         match color_space {
-            ColorSpace::RGB | ColorSpace::HEX => color,
-            ColorSpace::RGBA | ColorSpace::HEXA => {
-                let [r, g, b] = self.rgb;
-                vec![r, g, b, self.alpha]
+            ColorSpace::RGB | ColorSpace::HEX => pad(color),
+            ColorSpace::RGBA | ColorSpace::HEXA => with_alpha(color),
+            ColorSpace::HSI => pad(conversion::hsi::rgb2hsi(color)),
+            ColorSpace::HSL => pad(conversion::hsl::rgb2hsl(color)),
+            ColorSpace::HSLA => with_alpha(conversion::hsl::rgb2hsl(color)),
+            ColorSpace::HSV => pad(conversion::hsv::rgb2hsv(color)),
+            ColorSpace::HWB => pad(conversion::hwb::rgb2hwb(color)),
+            ColorSpace::CMYK => conversion::cmyk::rgb2cmyk(color),
+            ColorSpace::XYZ => pad(conversion::xyz::rgb2xyz(color)),
+            ColorSpace::YIQ => pad(conversion::yiq::rgb2yiq(color)),
+            ColorSpace::YUV => pad(conversion::yuv::rgb2yuv(color)),
+            ColorSpace::YCbCr => pad(conversion::ycbcr::rgb2ycbcr(color)),
+            ColorSpace::Lab => pad(conversion::lab::rgb2lab(color)),
+            ColorSpace::Lch => pad(conversion::oklab::lab2lch(conversion::lab::rgb2lab(color))),
+            ColorSpace::Oklab => pad(conversion::oklab::rgb2oklab(color)),
+            ColorSpace::Oklch => pad(conversion::oklab::lab2lch(conversion::oklab::rgb2oklab(
+                color,
+            ))),
+            #[cfg(feature = "std")]
+            ColorSpace::Custom(space) => space
+                .rgb_to_values(color.map(T::to_f64))
+                .unwrap_or_default()
+                .map(T::from_f64),
+            ColorSpace::Unknown => [T::ZERO; 4],
+        }
+    }
+    /// Creates a color from the values of a color space, the inverse of [`Color::values_of`].
+    ///
+    /// Missing values are `0.0`, a missing alpha is `1.0`. [`ColorSpace::Unknown`] and an
+    /// unregistered custom space give the default color.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_values_of(color_space: ColorSpace, values: &[T]) -> Self {
+        let mut padded = [T::ZERO, T::ZERO, T::ZERO, T::ONE];
+        padded
+            .iter_mut()
+            .zip(values.iter())
+            .for_each(|(v, &c)| *v = c);
+        let [a, b, c, d] = padded;
+
+        let (rgb, alpha) = match color_space {
+            ColorSpace::RGB | ColorSpace::RGBA | ColorSpace::HEX | ColorSpace::HEXA => {
+                ([a, b, c], d)
             }
-            ColorSpace::HSI => conversion::hsi::rgb2hsi(&color),
-            ColorSpace::HSL => conversion::hsl::rgb2hsl(&color),
-            ColorSpace::HSLA => {
-                let mut hsl = conversion::hsl::rgb2hsl(&color);
-                hsl.push(self.alpha);
-                hsl
+            ColorSpace::HSI => (conversion::hsi::hsi2rgb([a, b, c]), T::ONE),
+            ColorSpace::HSL => (conversion::hsl::hsl2rgb([a, b, c]), T::ONE),
+            ColorSpace::HSLA => (conversion::hsl::hsl2rgb([a, b, c]), d),
+            ColorSpace::HSV => (conversion::hsv::hsv2rgb([a, b, c]), T::ONE),
+            ColorSpace::CMYK => (conversion::cmyk::cmyk2rgb(padded), T::ONE),
+            ColorSpace::XYZ => (conversion::xyz::xyz2rgb([a, b, c]), T::ONE),
+            ColorSpace::YIQ => (conversion::yiq::yiq2rgb([a, b, c]), T::ONE),
+            ColorSpace::YUV => (conversion::yuv::yuv2rgb([a, b, c]), T::ONE),
+            ColorSpace::YCbCr => (conversion::ycbcr::ycbcr2rgb([a, b, c]), T::ONE),
+            ColorSpace::Lab => (conversion::lab::lab2rgb([a, b, c]), T::ONE),
+            ColorSpace::Lch => (
+                conversion::lab::lab2rgb(conversion::oklab::lch2lab([a, b, c])),
+                T::ONE,
+            ),
+            ColorSpace::Oklab => (conversion::oklab::oklab2rgb([a, b, c]), T::ONE),
+            ColorSpace::Oklch => (
+                conversion::oklab::oklab2rgb(conversion::oklab::lch2lab([a, b, c])),
+                T::ONE,
+            ),
+            ColorSpace::HWB => (conversion::hwb::hwb2rgb([a, b, c]), T::ONE),
+            #[cfg(feature = "std")]
+            ColorSpace::Custom(space) => {
+                let values = values.iter().map(|v| v.to_f64()).collect::<Vec<_>>();
+                match space.values_to_rgb(&values) {
+                    Ok(rgb) => (rgb.map(T::from_f64), T::ONE),
+                    Err(_) => return Color::default(),
+                }
             }
-            ColorSpace::HSV => conversion::hsv::rgb2hsv(&color),
-            ColorSpace::HWB => conversion::hwb::rgb2hwb(&color),
-            ColorSpace::CMYK => conversion::cmyk::rgb2cmyk(&color),
-            ColorSpace::XYZ => conversion::xyz::rgb2xyz(&color),
-            ColorSpace::YIQ => conversion::yiq::rgb2yiq(&color),
-            ColorSpace::YUV => conversion::yuv::rgb2yuv(&color),
-            ColorSpace::YCbCr => conversion::ycbcr::rgb2ycbcr(&color),
-            ColorSpace::Lab => conversion::lab::rgb2lab(&color),
-            ColorSpace::Unknown => todo!("Unknown color space not yet implemented `vec_of`"),
-        }
+            ColorSpace::Unknown => return Color::default(),
+        };
+        Color { rgb, alpha }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::{utils::round, *};
 
     #[test]
     fn test_vec_of_hsl() {
-        let color = color!(#80e619);
+        let color = color!(#80e619).unwrap();
         let vec = color.vec_of(ColorSpace::HSL);
         assert_eq!(vec, vec![89.85365853658537, 0.803921568627451, 0.5]);
     }
 
     #[test]
     fn test_vec_of_lab() {
-        let color = color!(#7654cd);
+        let color = color!(#7654cd).unwrap();
         let vec = color
             .vec_of(ColorSpace::Lab)
             .iter()
@@ -70,7 +166,7 @@ mod tests {
 
     #[test]
     fn test_vec_of_xyz() {
-        let color = color!(#7654cd);
+        let color = color!(#7654cd).unwrap();
         let vec = color
             .vec_of(ColorSpace::XYZ)
             .iter()
@@ -81,7 +177,7 @@ mod tests {
 
     #[test]
     fn test_vec_of_yiq() {
-        let color = color!(#7654cd);
+        let color = color!(#7654cd).unwrap();
         let vec = color
             .vec_of(ColorSpace::YIQ)
             .iter()
@@ -89,4 +185,10 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(vec, vec![0.42337, -0.07301, 0.17583]);
     }
+
+    #[test]
+    fn test_vec_of_unknown() {
+        let color = color!(#7654cd).unwrap();
+        assert!(color.vec_of(ColorSpace::Unknown).is_empty());
+    }
 }
@@ -0,0 +1,67 @@
+/// Element type of an interleaved pixel buffer, see [`convert_buffer`](crate::convert_buffer).
+///
+/// Float channels hold the values of the color space as is, e.g. `0.0..=255.0` for RGB and
+/// `0.0..=1.0` for alpha. Integer channels hold RGB or RGBA pixels only, the alpha is scaled
+/// to the range of the integer.
+pub trait BufferChannel: Copy + Send + Sync {
+    /// Whether the channel can hold the values of any color space.
+    const FLOAT: bool;
+
+    /// Reads the channel as a color space value.
+    fn to_value(self, is_alpha: bool) -> f64;
+    /// Writes a color space value to the channel.
+    fn from_value(value: f64, is_alpha: bool) -> Self;
+}
+
+impl BufferChannel for u8 {
+    const FLOAT: bool = false;
+
+    fn to_value(self, is_alpha: bool) -> f64 {
+        if is_alpha {
+            self as f64 / 255.0
+        } else {
+            self as f64
+        }
+    }
+    fn from_value(value: f64, is_alpha: bool) -> Self {
+        let value = if is_alpha { value * 255.0 } else { value };
+        crate::utils::round(value, 0).clamp(0.0, 255.0) as u8
+    }
+}
+
+impl BufferChannel for f32 {
+    const FLOAT: bool = true;
+
+    fn to_value(self, _is_alpha: bool) -> f64 {
+        self as f64
+    }
+    fn from_value(value: f64, _is_alpha: bool) -> Self {
+        value as f32
+    }
+}
+
+impl BufferChannel for f64 {
+    const FLOAT: bool = true;
+
+    fn to_value(self, _is_alpha: bool) -> f64 {
+        self
+    }
+    fn from_value(value: f64, _is_alpha: bool) -> Self {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_u8_channel() {
+        assert_eq!(u8::from_value(127.6, false), 128);
+        assert_eq!(u8::from_value(300.0, false), 255);
+        assert_eq!(u8::from_value(-3.0, false), 0);
+        assert_eq!(u8::from_value(0.5, true), 128);
+        assert_eq!(255_u8.to_value(true), 1.0);
+        assert_eq!(51_u8.to_value(false), 51.0);
+    }
+}
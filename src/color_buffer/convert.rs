@@ -0,0 +1,322 @@
+use super::BufferChannel;
+#[cfg(feature = "std")]
+use crate::{
+    color_space::custom::{rgb_to_values, values_to_rgb},
+    ColorSpaceDef,
+};
+use crate::{Color, ColorSpace, Error};
+use alloc::format;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Converts an interleaved pixel buffer from one color space into another.
+///
+/// Each pixel of `input` holds the values of `from`, each pixel of `output` the values of `to`,
+/// e.g. 3 values per pixel for [`ColorSpace::RGB`] and 4 for [`ColorSpace::RGBA`] or
+/// [`ColorSpace::CMYK`]. The alpha is kept when both spaces have an alpha channel
+/// and is `1.0` when only `to` has one.
+///
+/// `u8` buffers hold RGB or RGBA pixels, float buffers any color space.
+/// The values are not validated, out of range values are converted as they are.
+///
+/// With the `rayon` feature the pixels are converted in parallel.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{convert_buffer, ColorSpace};
+///
+/// let pixels: [u8; 8] = [255, 51, 153, 255, 0, 0, 0, 128];
+/// let mut hsla = [0.0_f32; 8];
+/// convert_buffer(&pixels, ColorSpace::RGBA, &mut hsla, ColorSpace::HSLA).unwrap();
+/// assert_eq!(&hsla[..4], &[330.0, 1.0, 0.6, 1.0]);
+///
+/// let mut rgb = [0_u8; 6];
+/// convert_buffer(&hsla, ColorSpace::HSLA, &mut rgb, ColorSpace::RGB).unwrap();
+/// assert_eq!(rgb, [255, 51, 153, 0, 0, 0]);
+/// ```
+pub fn convert_buffer<I, O>(
+    input: &[I],
+    from: ColorSpace,
+    output: &mut [O],
+    to: ColorSpace,
+) -> Result<(), Error>
+where
+    I: BufferChannel,
+    O: BufferChannel,
+{
+    let in_stride = stride_of::<I>(from, input.len())?;
+    let out_stride = stride_of::<O>(to, output.len())?;
+    if input.len() / in_stride != output.len() / out_stride {
+        return Err(Error::InvalidParamsError(
+            format!(
+                "Output buffer must hold {} pixels, got {}",
+                input.len() / in_stride,
+                output.len() / out_stride
+            )
+            .into(),
+        ));
+    }
+
+    let (from, to) = (PixelSpace::resolve(from)?, PixelSpace::resolve(to)?);
+    let convert = |(src, dst): (&[I], &mut [O])| to.write(&from.read(src), dst);
+
+    #[cfg(feature = "rayon")]
+    input
+        .par_chunks(in_stride)
+        .zip(output.par_chunks_mut(out_stride))
+        .for_each(convert);
+    #[cfg(not(feature = "rayon"))]
+    input
+        .chunks(in_stride)
+        .zip(output.chunks_mut(out_stride))
+        .for_each(convert);
+
+    Ok(())
+}
+
+/// Converts an interleaved pixel buffer from one color space into another in place.
+///
+/// Both spaces must have the same number of values per pixel, e.g. [`ColorSpace::RGB`] and
+/// [`ColorSpace::Lab`]. See [`convert_buffer`] for the buffer layout.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{convert_buffer_in_place, ColorSpace};
+///
+/// let mut pixels = [255.0_f32, 51.0, 153.0, 0.0, 0.0, 255.0];
+/// convert_buffer_in_place(&mut pixels, ColorSpace::RGB, ColorSpace::HSV).unwrap();
+/// assert_eq!(pixels, [330.0, 0.8, 1.0, 240.0, 1.0, 1.0]);
+/// ```
+pub fn convert_buffer_in_place<T>(
+    buffer: &mut [T],
+    from: ColorSpace,
+    to: ColorSpace,
+) -> Result<(), Error>
+where
+    T: BufferChannel,
+{
+    let stride = stride_of::<T>(from, buffer.len())?;
+    if stride_of::<T>(to, buffer.len())? != stride {
+        return Err(Error::InvalidParamsError(
+            format!(
+            "In place conversion requires the same number of values per pixel, got {:?} and {:?}",
+            from, to
+        )
+            .into(),
+        ));
+    }
+
+    let (from, to) = (PixelSpace::resolve(from)?, PixelSpace::resolve(to)?);
+    let convert = |pixel: &mut [T]| {
+        let color = from.read(pixel);
+        to.write(&color, pixel);
+    };
+
+    #[cfg(feature = "rayon")]
+    buffer.par_chunks_mut(stride).for_each(convert);
+    #[cfg(not(feature = "rayon"))]
+    buffer.chunks_mut(stride).for_each(convert);
+
+    Ok(())
+}
+
+/// Number of values per pixel of `color_space`, checking that a buffer of `len` values fits.
+fn stride_of<T: BufferChannel>(color_space: ColorSpace, len: usize) -> Result<usize, Error> {
+    let stride = color_space.value_count();
+    if stride == 0 {
+        return Err(Error::InvalidParamsError(
+            format!("Unsupported buffer color space {:?}", color_space).into(),
+        ));
+    }
+    let is_rgb = matches!(
+        color_space,
+        ColorSpace::RGB | ColorSpace::RGBA | ColorSpace::HEX | ColorSpace::HEXA
+    );
+    if !T::FLOAT && !is_rgb {
+        return Err(Error::InvalidParamsError(
+            format!(
+                "Integer buffers hold RGB or RGBA pixels, got {:?}",
+                color_space
+            )
+            .into(),
+        ));
+    }
+    if len % stride != 0 {
+        return Err(Error::InvalidParamsError(
+            format!(
+                "Buffer length must be a multiple of {}, got {}",
+                stride, len
+            )
+            .into(),
+        ));
+    }
+    Ok(stride)
+}
+
+/// The color space of the pixels of a buffer.
+///
+/// A custom space is looked up in the registry once per buffer, the pixels are then converted
+/// without its lock.
+enum PixelSpace {
+    Builtin(ColorSpace),
+    #[cfg(feature = "std")]
+    Custom(Arc<dyn ColorSpaceDef>),
+}
+
+impl PixelSpace {
+    fn resolve(color_space: ColorSpace) -> Result<Self, Error> {
+        match color_space {
+            #[cfg(feature = "std")]
+            ColorSpace::Custom(space) => Ok(PixelSpace::Custom(space.def()?)),
+            color_space => Ok(PixelSpace::Builtin(color_space)),
+        }
+    }
+    /// Index of the alpha value in a pixel, custom spaces have no alpha.
+    fn alpha_index(&self) -> Option<usize> {
+        match self {
+            PixelSpace::Builtin(ColorSpace::RGBA | ColorSpace::HEXA | ColorSpace::HSLA) => Some(3),
+            _ => None,
+        }
+    }
+    fn read<T: BufferChannel>(&self, pixel: &[T]) -> Color {
+        let alpha = self.alpha_index();
+        let mut values = [0.0; 4];
+        values
+            .iter_mut()
+            .zip(pixel.iter())
+            .enumerate()
+            .for_each(|(i, (v, c))| *v = c.to_value(alpha == Some(i)));
+        let values = &values[..pixel.len()];
+        match self {
+            PixelSpace::Builtin(color_space) => Color::from_values_of(*color_space, values),
+            #[cfg(feature = "std")]
+            PixelSpace::Custom(def) => Color {
+                rgb: values_to_rgb(&**def, values),
+                alpha: 1.0,
+            },
+        }
+    }
+    fn write<T: BufferChannel>(&self, color: &Color, pixel: &mut [T]) {
+        let alpha = self.alpha_index();
+        let values = match self {
+            PixelSpace::Builtin(color_space) => color.values_of(*color_space),
+            #[cfg(feature = "std")]
+            PixelSpace::Custom(def) => rgb_to_values(&**def, color.rgb),
+        };
+        pixel
+            .iter_mut()
+            .zip(values)
+            .enumerate()
+            .for_each(|(i, (c, v))| *c = T::from_value(v, alpha == Some(i)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{color, utils::round};
+
+    #[test]
+    fn test_convert_buffer() {
+        let pixels: Vec<u8> = vec![255, 51, 153, 0, 0, 255, 118, 84, 205];
+        let mut lab = vec![0.0_f32; 9];
+        convert_buffer(&pixels, ColorSpace::RGB, &mut lab, ColorSpace::Lab).unwrap();
+
+        let expected = color!(#7654cd).unwrap().vec_of(ColorSpace::Lab);
+        for (a, b) in lab[6..].iter().zip(expected) {
+            assert!((*a as f64 - b).abs() < 1e-4);
+        }
+
+        let mut rgba = vec![0_u8; 12];
+        convert_buffer(&lab, ColorSpace::Lab, &mut rgba, ColorSpace::RGBA).unwrap();
+        assert_eq!(
+            rgba,
+            vec![255, 51, 153, 255, 0, 0, 255, 255, 118, 84, 205, 255]
+        );
+    }
+
+    #[test]
+    fn test_convert_buffer_keeps_alpha() {
+        let pixels = [255_u8, 51, 153, 128];
+        let mut hsla = [0.0; 4];
+        convert_buffer(&pixels, ColorSpace::RGBA, &mut hsla, ColorSpace::HSLA).unwrap();
+        assert_eq!(round(hsla[3], 2), 0.5);
+
+        let mut hsl = [0.0; 3];
+        convert_buffer(&pixels, ColorSpace::RGBA, &mut hsl, ColorSpace::HSL).unwrap();
+        assert_eq!(hsl, [330.0, 1.0, 0.6]);
+    }
+
+    #[test]
+    fn test_convert_buffer_in_place() {
+        let mut pixels = [50.0_f64, 20.0, -30.0, 75.0, -10.0, 40.0];
+        let origin = pixels;
+        convert_buffer_in_place(&mut pixels, ColorSpace::Lab, ColorSpace::RGB).unwrap();
+        convert_buffer_in_place(&mut pixels, ColorSpace::RGB, ColorSpace::Lab).unwrap();
+        for (a, b) in pixels.iter().zip(origin) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_convert_buffer_custom_space() {
+        use crate::{register_color_space, ReferenceSpace};
+        use core::ops::RangeInclusive;
+
+        struct Luma;
+
+        impl ColorSpaceDef for Luma {
+            fn name(&self) -> &str {
+                "bufferluma"
+            }
+            fn channel_ranges(&self) -> Vec<RangeInclusive<f64>> {
+                vec![0.0..=1.0]
+            }
+            fn reference_space(&self) -> ReferenceSpace {
+                ReferenceSpace::Srgb
+            }
+            fn to_reference(&self, values: &[f64]) -> [f64; 3] {
+                [values[0] * 255.0; 3]
+            }
+            fn reference_to_values(&self, rgb: [f64; 3]) -> Vec<f64> {
+                vec![rgb[1] / 255.0]
+            }
+            fn format(&self, values: &[f64]) -> String {
+                format!("bufferluma({})", values[0])
+            }
+        }
+
+        let luma = register_color_space(Luma).unwrap();
+        let pixels = [255_u8, 51, 153, 128, 0, 0, 255, 255];
+        let mut values = [0.0_f32; 2];
+        convert_buffer(&pixels, ColorSpace::RGBA, &mut values, luma).unwrap();
+        assert_eq!(values, [0.2, 0.0]);
+
+        let mut rgba = [0_u8; 8];
+        convert_buffer(&values, luma, &mut rgba, ColorSpace::RGBA).unwrap();
+        assert_eq!(rgba, [51, 51, 51, 255, 0, 0, 0, 255]);
+
+        let mut pixels = [0.5_f64, 1.0, 0.0];
+        assert!(convert_buffer_in_place(&mut pixels, luma, ColorSpace::HSL).is_err());
+        convert_buffer_in_place(&mut pixels, luma, luma).unwrap();
+        assert_eq!(pixels, [0.5, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_convert_buffer_err() {
+        let mut out = [0.0_f32; 6];
+        assert!(convert_buffer(&[0_u8; 5], ColorSpace::RGB, &mut out, ColorSpace::HSL).is_err());
+        assert!(convert_buffer(&[0_u8; 3], ColorSpace::RGB, &mut out, ColorSpace::HSL).is_err());
+        assert!(
+            convert_buffer(&[0_u8; 3], ColorSpace::HSL, &mut out[..3], ColorSpace::HSL).is_err()
+        );
+        assert!(convert_buffer_in_place(&mut out, ColorSpace::RGB, ColorSpace::CMYK).is_err());
+        assert!(convert_buffer_in_place(&mut out, ColorSpace::RGB, ColorSpace::Unknown).is_err());
+    }
+}
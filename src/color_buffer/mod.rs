@@ -0,0 +1,5 @@
+mod channel;
+mod convert;
+
+pub use channel::BufferChannel;
+pub use convert::{convert_buffer, convert_buffer_in_place};
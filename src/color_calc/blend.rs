@@ -1,4 +1,4 @@
-use crate::{utils::blend_fn::*, Color, ColorSpace};
+use crate::{utils::blend_fn::*, Color, ColorSpace, PremultipliedColor};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -100,44 +100,28 @@ pub enum BlendMode {
 /// ```
 /// use color_art::{color, BlendMode, blend};
 ///
-/// let color1 = color!(#4cbbfc);
-/// let color2 = color!(#eeee22);
+/// let color1 = color!(#4cbbfc).unwrap();
+/// let color2 = color!(#eeee22).unwrap();
 ///
 /// let blended_color = blend(&color1, &color2, BlendMode::Overlay);
 /// assert_eq!(blended_color.hex(), "#8ef6fa");
 /// ```
 pub fn blend(backdrop_color: &Color, source_color: &Color, mode: BlendMode) -> Color {
-    let backdrop_vec = backdrop_color.vec_of(ColorSpace::RGBA);
-    let source_vec = source_color.vec_of(ColorSpace::RGBA);
-
-    let zip_vec =
-        backdrop_vec
-            .iter()
-            .zip(source_vec.iter())
-            .enumerate()
-            .map(|(index, (&a, &b))| {
-                // aplha
-                if index == 3 {
-                    (a, b)
-                } else {
-                    (a / 255.0, b / 255.0)
-                }
-            });
-
-    let v: Vec<_> = match mode {
-        BlendMode::Normal => zip_vec.map(|(a, b)| normal(a, b)).collect(),
-        BlendMode::Multiply => zip_vec.map(|(a, b)| multiply(a, b)).collect(),
-        BlendMode::Darken => zip_vec.map(|(a, b)| min(a, b)).collect(),
-        BlendMode::Lighten => zip_vec.map(|(a, b)| max(a, b)).collect(),
-        BlendMode::Screen => zip_vec.map(|(a, b)| screen(a, b)).collect(),
-        BlendMode::Overlay => zip_vec.map(|(a, b)| overlay(a, b)).collect(),
-        BlendMode::ColorBurn => zip_vec.map(|(a, b)| burn(a, b)).collect(),
-        BlendMode::ColorDodge => zip_vec.map(|(a, b)| dodge(a, b)).collect(),
-        BlendMode::HardLight => zip_vec.map(|(a, b)| hard_light(a, b)).collect(),
-        BlendMode::SoftLight => zip_vec.map(|(a, b)| soft_light(a, b)).collect(),
-        BlendMode::Difference => zip_vec.map(|(a, b)| difference(a, b)).collect(),
-        BlendMode::Exclusion => zip_vec.map(|(a, b)| exclusion(a, b)).collect(),
-    };
+    let backdrop_vec = backdrop_color.values_of(ColorSpace::RGBA);
+    let source_vec = source_color.values_of(ColorSpace::RGBA);
+
+    let zip_vec: [(f64, f64); 4] = core::array::from_fn(|index| {
+        let (a, b) = (backdrop_vec[index], source_vec[index]);
+        // aplha
+        if index == 3 {
+            (a, b)
+        } else {
+            (a / 255.0, b / 255.0)
+        }
+    });
+
+    let blend_fn = mode.blend_fn();
+    let v = zip_vec.map(|(a, b)| blend_fn(a, b));
 
     let r = v[0] * 255.0;
     let g = v[1] * 255.0;
@@ -147,15 +131,75 @@ pub fn blend(backdrop_color: &Color, source_color: &Color, mode: BlendMode) -> C
     Color::new(r, g, b, a)
 }
 
-#[cfg(test)]
+/// Blends two premultiplied colors with a blending mode and composites the result
+/// over the backdrop.
+///
+/// Follows the [W3C compositing](https://www.w3.org/TR/compositing-1/#blending) formula:
+/// where only one of the colors is opaque it shows through, where both overlap the
+/// blended color is used. A fully transparent source leaves the backdrop unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{blend_premultiplied, color, BlendMode, Color, PremultipliedColor};
+///
+/// let backdrop = color!(#4cbbfc).unwrap().premultiply();
+/// let source = color!(#eeee22).unwrap().premultiply();
+/// let blended = blend_premultiplied(&backdrop, &source, BlendMode::Multiply);
+/// assert_eq!(blended.unpremultiply().hex(), "#47af22");
+///
+/// let transparent = PremultipliedColor::default();
+/// let blended = blend_premultiplied(&backdrop, &transparent, BlendMode::Multiply);
+/// assert_eq!(blended, backdrop);
+/// ```
+pub fn blend_premultiplied(
+    backdrop_color: &PremultipliedColor,
+    source_color: &PremultipliedColor,
+    mode: BlendMode,
+) -> PremultipliedColor {
+    let blend_fn = mode.blend_fn();
+    let (ab, a_s) = (backdrop_color.alpha(), source_color.alpha());
+    let cb = backdrop_color.unpremultiply().rgb;
+    let cs = source_color.unpremultiply().rgb;
+    let pb = backdrop_color.rgb();
+    let ps = source_color.rgb();
+
+    let [r, g, b] = core::array::from_fn(|i| {
+        let mixed = blend_fn(cb[i] / 255.0, cs[i] / 255.0) * 255.0;
+        ps[i] * (1.0 - ab) + pb[i] * (1.0 - a_s) + a_s * ab * mixed
+    });
+
+    PremultipliedColor::new(r, g, b, a_s + ab * (1.0 - a_s))
+}
+
+impl BlendMode {
+    fn blend_fn(&self) -> fn(f64, f64) -> f64 {
+        match self {
+            BlendMode::Normal => normal,
+            BlendMode::Multiply => multiply,
+            BlendMode::Darken => min,
+            BlendMode::Lighten => max,
+            BlendMode::Screen => screen,
+            BlendMode::Overlay => overlay,
+            BlendMode::ColorBurn => burn,
+            BlendMode::ColorDodge => dodge,
+            BlendMode::HardLight => hard_light,
+            BlendMode::SoftLight => soft_light,
+            BlendMode::Difference => difference,
+            BlendMode::Exclusion => exclusion,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::*;
     use BlendMode::*;
 
     #[test]
     fn test_blend() {
-        let c1 = color!(#4cbbfc);
-        let c2 = color!(#eeee22);
+        let c1 = color!(#4cbbfc).unwrap();
+        let c2 = color!(#eeee22).unwrap();
 
         let color = blend(&c1, &c2, Multiply);
         assert_eq!(color.hex(), "#47af22");
@@ -181,4 +225,21 @@ mod tests {
         let color = blend(&c1, &c2, HardLight);
         assert_eq!(color.hex(), "#e7f643");
     }
+
+    #[test]
+    fn test_blend_premultiplied() {
+        let c1 = color!(#4cbbfc).unwrap().premultiply();
+        let c2 = color!(#eeee22).unwrap().premultiply();
+
+        let color = blend_premultiplied(&c1, &c2, Screen);
+        assert_eq!(color.unpremultiply().hex(), "#f3fafc");
+
+        let c2 = color!(rgba(238, 238, 34, 0.5)).unwrap().premultiply();
+        let color = blend_premultiplied(&c1, &c2, Multiply);
+        assert_eq!(color.alpha(), 1.0);
+        assert_eq!(color.unpremultiply().hex(), "#49b58f");
+
+        let color = blend_premultiplied(&PremultipliedColor::default(), &c2, Multiply);
+        assert_eq!(color, c2);
+    }
 }
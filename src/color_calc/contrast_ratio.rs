@@ -11,8 +11,8 @@ use crate::Color;
 /// ```
 /// use color_art::{color, contrast_ratio};
 ///
-/// let color1 = color!(#fefe0e);
-/// let color2 = color!(#fff);
+/// let color1 = color!(#fefe0e).unwrap();
+/// let color2 = color!(#fff).unwrap();
 /// let contrast = contrast_ratio(&color1, &color2);
 ///
 /// assert_eq!(contrast, 1.0826287103122008);
@@ -24,20 +24,20 @@ pub fn contrast_ratio(color1: &Color, color2: &Color) -> f64 {
     (l_max + 0.05) / (l_min + 0.05)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::*;
 
     #[test]
     fn test_distance() {
-        let color1 = color!(#000);
-        let color2 = color!(#fff);
+        let color1 = color!(#000).unwrap();
+        let color2 = color!(#fff).unwrap();
 
         let contrast = contrast_ratio(&color1, &color2);
         assert_eq!(contrast, 21.0);
 
-        let color1 = color!(#fefe0e);
-        let color2 = color!(#fff);
+        let color1 = color!(#fefe0e).unwrap();
+        let color2 = color!(#fff).unwrap();
 
         let contrast = contrast_ratio(&color1, &color2);
         assert_eq!(contrast, 1.0826287103122008);
@@ -1,4 +1,4 @@
-use crate::{Color, ColorSpace};
+use crate::{conversion::lab::rgb2lab, utils::Float, Color};
 
 /// Computes [color difference](https://en.wikipedia.org/wiki/Color_difference#CIEDE2000) as developed by the International Commission on Illumination (CIE) in 2000.
 ///
@@ -6,13 +6,15 @@ use crate::{Color, ColorSpace};
 ///
 /// Resulting values range from 0 (no difference) to 100 (maximum difference), and are a metric for how the human eye percieves color difference.
 ///
+/// The difference is always computed in `f64`, also for `Color<f32>`.
+///
 /// # Examples
 ///
 /// ```
 /// use color_art::{delta_e, color};
 ///
-/// let color1 = color!(#fefe0e);
-/// let color2 = color!(#fff);
+/// let color1 = color!(#fefe0e).unwrap();
+/// let color2 = color!(#fff).unwrap();
 ///
 /// fn is_equal(a: f64, b: f64) -> bool {
 ///   (a - b).abs() < 0.000001
@@ -21,12 +23,9 @@ use crate::{Color, ColorSpace};
 /// let d = delta_e(&color1, &color2);
 /// assert!(is_equal(d, 30.165629067733235));
 /// ```
-pub fn delta_e(color1: &Color, color2: &Color) -> f64 {
-    let lab1 = color1.vec_of(ColorSpace::Lab);
-    let lab2 = color2.vec_of(ColorSpace::Lab);
-
-    let [l1, a1, b1] = [lab1[0], lab1[1], lab1[2]];
-    let [l2, a2, b2] = [lab2[0], lab2[1], lab2[2]];
+pub fn delta_e<T: Float>(color1: &Color<T>, color2: &Color<T>) -> T {
+    let [l1, a1, b1] = rgb2lab(color1.rgb.map(T::to_f64));
+    let [l2, a2, b2] = rgb2lab(color2.rgb.map(T::to_f64));
 
     let avg_l = (l1 + l2) / 2.0;
 
@@ -101,10 +100,10 @@ pub fn delta_e(color1: &Color, color2: &Color) -> f64 {
         + rt * (delta_cp / (kc * sc)) * (delta_hp / (kh * sh)))
         .sqrt();
 
-    result.min(100.0).max(0.0)
+    T::from_f64(result.clamp(0.0, 100.0))
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::*;
 
@@ -114,28 +113,34 @@ mod tests {
 
     #[test]
     fn test_distance() {
-        let color1 = color!(#fefe0e);
-        let color2 = color!(#fff);
+        let color1 = color!(#fefe0e).unwrap();
+        let color2 = color!(#fff).unwrap();
 
         let d = delta_e(&color1, &color2);
         assert!(is_equal(d, 30.165629067733235));
 
-        let color1 = color!(#ededee);
-        let color2 = color!(#edeeed);
+        let color1 = color!(#ededee).unwrap();
+        let color2 = color!(#edeeed).unwrap();
 
         let d = delta_e(&color1, &color2);
         assert!(is_equal(d, 1.2364506278716838));
 
-        let color1 = color!(#e0e0ee);
-        let color2 = color!(#e0eee0);
+        let color1 = color!(#e0e0ee).unwrap();
+        let color2 = color!(#e0eee0).unwrap();
 
         let d = delta_e(&color1, &color2);
         assert!(is_equal(d, 14.618185117695797));
 
-        let color1 = color!(#fff);
-        let color2 = color!(#000);
+        let color1 = color!(#fff).unwrap();
+        let color2 = color!(#000).unwrap();
 
         let d = delta_e(&color1, &color2);
         assert_eq!(d, 100.0);
+
+        let color1: Color<f32> = color!(#fefe0e).unwrap().into();
+        let color2: Color<f32> = color!(#fff).unwrap().into();
+
+        let d = delta_e(&color1, &color2);
+        assert!((d - 30.165629).abs() < 0.0001);
     }
 }
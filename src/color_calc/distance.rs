@@ -1,4 +1,4 @@
-use crate::{Color, ColorSpace};
+use crate::{utils::Float, Color, ColorSpace};
 
 /// Computes the [Euclidean distance](https://en.wikipedia.org/wiki/Euclidean_distance#Three_dimensions) between two colors in a given color space.
 ///
@@ -7,20 +7,21 @@ use crate::{Color, ColorSpace};
 /// ```
 /// use color_art::{distance_with, color, ColorSpace};
 ///
-/// let color1 = color!(#fefe0e);
-/// let color2 = color!(#fff);
+/// let color1 = color!(#fefe0e).unwrap();
+/// let color2 = color!(#fff).unwrap();
 ///
 /// let d = distance_with(&color1, &color2, ColorSpace::HSL);
 /// assert_eq!(d, 60.01007164576413);
 /// ```
-pub fn distance_with(color1: &Color, color2: &Color, color_space: ColorSpace) -> f64 {
-    let vec1 = color1.vec_of(color_space);
-    let vec2 = color2.vec_of(color_space);
+pub fn distance_with<T: Float>(color1: &Color<T>, color2: &Color<T>, color_space: ColorSpace) -> T {
+    let vec1 = color1.values_of(color_space);
+    let vec2 = color2.values_of(color_space);
+    let n = color_space.value_count();
 
-    let mut d = 0.0;
+    let mut d = T::ZERO;
 
-    vec1.iter().zip(vec2.iter()).for_each(|(a, b)| {
-        d += (a - b).powf(2.0);
+    vec1[..n].iter().zip(vec2[..n].iter()).for_each(|(&a, &b)| {
+        d += (a - b).powi(2);
     });
 
     d.sqrt()
@@ -33,24 +34,24 @@ pub fn distance_with(color1: &Color, color2: &Color, color_space: ColorSpace) ->
 /// ```
 /// use color_art::{distance, color};
 ///
-/// let color1 = color!(#fefe0e);
-/// let color2 = color!(#fff);
+/// let color1 = color!(#fefe0e).unwrap();
+/// let color2 = color!(#fff).unwrap();
 ///
 /// let d = distance(&color1, &color2);
 /// assert_eq!(d, 241.00414934187336);
 /// ```
-pub fn distance(color1: &Color, color2: &Color) -> f64 {
+pub fn distance<T: Float>(color1: &Color<T>, color2: &Color<T>) -> T {
     distance_with(color1, color2, ColorSpace::RGBA)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::*;
 
     #[test]
     fn test_distance() {
-        let color1 = color!(#fefe0e);
-        let color2 = color!(#fff);
+        let color1 = color!(#fefe0e).unwrap();
+        let color2 = color!(#fff).unwrap();
 
         let d = distance(&color1, &color2);
         assert_eq!(d, 241.00414934187336);
@@ -61,4 +62,13 @@ mod tests {
         let d = distance_with(&color1, &color2, ColorSpace::HSL);
         assert_eq!(d, 60.01007164576413);
     }
+
+    #[test]
+    fn test_distance_f32() {
+        let color1: Color<f32> = color!(#fefe0e).unwrap().into();
+        let color2: Color<f32> = color!(#fff).unwrap().into();
+
+        let d = distance(&color1, &color2);
+        assert_eq!(d, 241.00415_f32);
+    }
 }
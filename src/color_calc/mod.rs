@@ -2,5 +2,3 @@ pub mod blend;
 pub mod contrast_ratio;
 pub mod delta_e;
 pub mod distance;
-
-pub use blend::BlendMode;
@@ -33,38 +33,43 @@ impl Color {
                 let a = color.alpha;
                 [acc[0] + r, acc[1] + g, acc[2] + b, acc[3] + a]
             })
-            .iter()
-            .map(|v| v / (colors.len() as f64))
-            .collect::<Vec<f64>>();
+            .map(|v| v / (colors.len() as f64));
 
         Color::new(vec[0], vec[1], vec[2], vec[3])
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::*;
     use std::str::FromStr;
 
     #[test]
     fn test_average_colors() {
-        let colors = vec![Color::from_str("red").unwrap(), color!(rgba(0, 0, 0, 0.5))];
+        let colors = vec![
+            Color::from_str("red").unwrap(),
+            color!(rgba(0, 0, 0, 0.5)).unwrap(),
+        ];
 
         let averaged_color = Color::average(&colors);
         assert_eq!(averaged_color.rgba(), "rgba(128, 0, 0, 0.75)");
 
-        let colors = vec![color!(#ff6600), color!(#0000ff)];
+        let colors = vec![color!(#ff6600).unwrap(), color!(#0000ff).unwrap()];
         let averaged_color = Color::average(&colors);
         assert_eq!(averaged_color.hex(), "#803380");
 
-        let colors = vec![color!(#ffff00), color!(#ff0000), color!(#0000ff)];
+        let colors = vec![
+            color!(#ffff00).unwrap(),
+            color!(#ff0000).unwrap(),
+            color!(#0000ff).unwrap(),
+        ];
         let averaged_color = Color::average(&colors);
         assert_eq!(averaged_color.hex(), "#a55");
     }
 
     #[test]
     fn test_average_empty_list() {
-        let averaged_color = Color::average(&vec![]);
+        let averaged_color = Color::average(&[]);
         assert_eq!(averaged_color.rgba(), "rgba(0, 0, 0, 1)");
     }
 }
@@ -1,4 +1,166 @@
-use crate::{Color, Error};
+use crate::{
+    conversion::{hsl, hwb, lab, oklab, predefined},
+    utils::Float,
+    Color, Error, PredefinedSpace,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The color space [`Color::color_mix`] interpolates in.
+///
+/// See [CSS Color 5](https://www.w3.org/TR/css-color-5/#color-mix).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MixSpace {
+    /// One of the RGB or XYZ spaces of the `color()` function.
+    Predefined(PredefinedSpace),
+    /// CIE Lab.
+    Lab,
+    /// CIE LCH, the polar form of Lab.
+    Lch,
+    /// Oklab, the default of `color-mix()`.
+    #[default]
+    Oklab,
+    /// Oklch, the polar form of Oklab.
+    Oklch,
+    /// HSL.
+    Hsl,
+    /// HWB.
+    Hwb,
+}
+
+impl MixSpace {
+    /// The name of the space in `color-mix()`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MixSpace::Predefined(space) => space.name(),
+            MixSpace::Lab => "lab",
+            MixSpace::Lch => "lch",
+            MixSpace::Oklab => "oklab",
+            MixSpace::Oklch => "oklch",
+            MixSpace::Hsl => "hsl",
+            MixSpace::Hwb => "hwb",
+        }
+    }
+    /// Looks up a space by its name in `color-mix()`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let space = match name {
+            "lab" => MixSpace::Lab,
+            "lch" => MixSpace::Lch,
+            "oklab" => MixSpace::Oklab,
+            "oklch" => MixSpace::Oklch,
+            "hsl" => MixSpace::Hsl,
+            "hwb" => MixSpace::Hwb,
+            name => MixSpace::Predefined(PredefinedSpace::from_name(name)?),
+        };
+        Some(space)
+    }
+    /// The index of the hue of the polar spaces.
+    pub(crate) fn hue_index(&self) -> Option<usize> {
+        match self {
+            MixSpace::Hsl | MixSpace::Hwb => Some(0),
+            MixSpace::Lch | MixSpace::Oklch => Some(2),
+            _ => None,
+        }
+    }
+    /// Whether the hue does not matter, like the hue of a gray.
+    fn is_powerless(&self, values: [f64; 3]) -> bool {
+        match self {
+            MixSpace::Hsl => values[1].abs() < 1e-9,
+            MixSpace::Hwb => values[1] + values[2] >= 1.0 - 1e-9,
+            MixSpace::Lch => values[1] < 1e-4,
+            MixSpace::Oklch => values[1] < 1e-6,
+            _ => false,
+        }
+    }
+    fn rgb2values(self, rgb: [f64; 3]) -> [f64; 3] {
+        match self {
+            MixSpace::Predefined(space) => predefined::rgb2predefined(space, rgb),
+            MixSpace::Lab => lab::rgb2lab(rgb),
+            MixSpace::Lch => oklab::lab2lch(lab::rgb2lab(rgb)),
+            MixSpace::Oklab => oklab::rgb2oklab(rgb),
+            MixSpace::Oklch => oklab::lab2lch(oklab::rgb2oklab(rgb)),
+            MixSpace::Hsl => hsl::rgb2hsl(rgb),
+            MixSpace::Hwb => hwb::rgb2hwb(rgb),
+        }
+    }
+    fn values2rgb(self, values: [f64; 3]) -> [f64; 3] {
+        match self {
+            MixSpace::Predefined(space) => predefined::predefined2rgb(space, values),
+            MixSpace::Lab => lab::lab2rgb(values),
+            MixSpace::Lch => lab::lab2rgb(oklab::lch2lab(values)),
+            MixSpace::Oklab => oklab::oklab2rgb(values),
+            MixSpace::Oklch => oklab::oklab2rgb(oklab::lch2lab(values)),
+            MixSpace::Hsl => hsl::hsl2rgb(values),
+            MixSpace::Hwb => hwb::hwb2rgb(values),
+        }
+    }
+}
+
+/// The way [`Color::color_mix`] interpolates the hues of the polar spaces.
+///
+/// See [CSS Color 4](https://www.w3.org/TR/css-color-4/#hue-interpolation).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HueInterpolation {
+    /// The shorter arc between the hues.
+    #[default]
+    Shorter,
+    /// The longer arc between the hues.
+    Longer,
+    /// Counterclockwise, the hue increases.
+    Increasing,
+    /// Clockwise, the hue decreases.
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// The name of the method in `color-mix()`, without the `hue` keyword.
+    pub fn name(&self) -> &'static str {
+        match self {
+            HueInterpolation::Shorter => "shorter",
+            HueInterpolation::Longer => "longer",
+            HueInterpolation::Increasing => "increasing",
+            HueInterpolation::Decreasing => "decreasing",
+        }
+    }
+    /// Looks up a method by its name in `color-mix()`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let method = match name {
+            "shorter" => HueInterpolation::Shorter,
+            "longer" => HueInterpolation::Longer,
+            "increasing" => HueInterpolation::Increasing,
+            "decreasing" => HueInterpolation::Decreasing,
+            _ => return None,
+        };
+        Some(method)
+    }
+    /// Adjusts the hues so that interpolating them linearly follows the method.
+    fn fixup(&self, h1: f64, h2: f64) -> (f64, f64) {
+        let (mut h1, mut h2) = (Float::rem_euclid(h1, 360.0), Float::rem_euclid(h2, 360.0));
+        let delta = h2 - h1;
+        match self {
+            HueInterpolation::Shorter if delta > 180.0 => h1 += 360.0,
+            HueInterpolation::Shorter if delta < -180.0 => h2 += 360.0,
+            HueInterpolation::Longer if 0.0 < delta && delta < 180.0 => h1 += 360.0,
+            HueInterpolation::Longer if -180.0 < delta && delta <= 0.0 => h2 += 360.0,
+            HueInterpolation::Increasing if h2 < h1 => h2 += 360.0,
+            HueInterpolation::Decreasing if h1 < h2 => h1 += 360.0,
+            _ => {}
+        }
+        (h1, h2)
+    }
+}
+
+/// The options of [`Color::color_mix`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MixOptions {
+    /// The color space to interpolate in, Oklab by default.
+    pub space: MixSpace,
+    /// How to interpolate the hues of the polar spaces.
+    pub hue: HueInterpolation,
+}
 
 impl Color {
     /// Mix two colors with a weight.
@@ -14,15 +176,15 @@ impl Color {
     /// ```rust
     /// use color_art::{Color, color};
     ///
-    /// let color1 = color!(#998099);
-    /// let color2 = color!(#191970);
+    /// let color1 = color!(#998099).unwrap();
+    /// let color2 = color!(#191970).unwrap();
     /// let color3 = Color::mix(&color1, &color2, 0.5).unwrap();
     /// assert_eq!(color3.hex(), "#594d85");
     /// ```
     pub fn mix(color1: &Color, color2: &Color, weight: f64) -> Result<Self, Error> {
         if !(0.0..=1.0).contains(&weight) {
             return Err(Error::InvalidParamsError(
-                "weight must be between 0.0 and 1.0".to_string(),
+                "weight must be between 0.0 and 1.0".into(),
             ));
         }
         let (w1, w2) = (weight, 1.0 - weight);
@@ -32,16 +194,106 @@ impl Color {
         let a = color1.alpha * w1 + color2.alpha * w2;
         Ok(Color::new(r, g, b, a))
     }
+
+    /// Mix two colors like the CSS `color-mix()` function.
+    ///
+    /// The percentages are between 0.0 and 1.0. A missing one is the rest of the other, or
+    /// both are 0.5. Percentages that do not sum up to 1.0 are scaled to it, and a sum below
+    /// 1.0 also scales the alpha of the result. The channels are premultiplied by the alpha
+    /// while interpolating.
+    ///
+    /// See [CSS Color 5](https://www.w3.org/TR/css-color-5/#color-mix).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color_art::{color, Color, HueInterpolation, MixOptions, MixSpace};
+    ///
+    /// let options = MixOptions {
+    ///     space: MixSpace::Hsl,
+    ///     ..Default::default()
+    /// };
+    /// let color = Color::color_mix(&color!(#f00).unwrap(), None, &color!(#00f).unwrap(), None, &options).unwrap();
+    /// assert_eq!(color.hex(), "#f0f");
+    ///
+    /// let options = MixOptions {
+    ///     hue: HueInterpolation::Longer,
+    ///     ..options
+    /// };
+    /// let color = Color::color_mix(&color!(#f00).unwrap(), None, &color!(#00f).unwrap(), None, &options).unwrap();
+    /// assert_eq!(color.hex(), "#0f0");
+    /// ```
+    pub fn color_mix(
+        color1: &Color,
+        percentage1: Option<f64>,
+        color2: &Color,
+        percentage2: Option<f64>,
+        options: &MixOptions,
+    ) -> Result<Self, Error> {
+        let (p1, p2) = match (percentage1, percentage2) {
+            (None, None) => (0.5, 0.5),
+            (Some(p1), None) => (p1, 1.0 - p1),
+            (None, Some(p2)) => (1.0 - p2, p2),
+            (Some(p1), Some(p2)) => (p1, p2),
+        };
+        if !(0.0..=1.0).contains(&p1) || !(0.0..=1.0).contains(&p2) {
+            return Err(Error::InvalidParamsError(
+                "percentages must be between 0.0 and 1.0".into(),
+            ));
+        }
+        let sum = p1 + p2;
+        if sum == 0.0 {
+            return Err(Error::InvalidParamsError(
+                "percentages must not both be 0".into(),
+            ));
+        }
+        let (p1, p2) = (p1 / sum, p2 / sum);
+
+        let space = options.space;
+        let hue = space.hue_index();
+        let mut values1 = space.rgb2values(color1.rgb);
+        let mut values2 = space.rgb2values(color2.rgb);
+        if let Some(h) = hue {
+            // a powerless hue takes the hue of the other color
+            match (space.is_powerless(values1), space.is_powerless(values2)) {
+                (true, true) => (values1[h], values2[h]) = (0.0, 0.0),
+                (true, false) => values1[h] = values2[h],
+                (false, true) => values2[h] = values1[h],
+                (false, false) => {}
+            }
+            (values1[h], values2[h]) = options.hue.fixup(values1[h], values2[h]);
+        }
+
+        let (a1, a2) = (color1.alpha, color2.alpha);
+        let alpha = a1 * p1 + a2 * p2;
+        let mut values = [0.0; 3];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = if hue == Some(i) || alpha == 0.0 {
+                values1[i] * p1 + values2[i] * p2
+            } else {
+                (values1[i] * a1 * p1 + values2[i] * a2 * p2) / alpha
+            };
+        }
+        if let Some(h) = hue {
+            values[h] = Float::rem_euclid(values[h], 360.0);
+        }
+
+        let [r, g, b] = space.values2rgb(values);
+        Ok(Color::new(r, g, b, alpha * sum.min(1.0)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::{color, utils::round};
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_mix() {
-        let color1 = color!(#003366);
-        let color2 = color!(#d2e1dd);
+        let color1 = color!(#003366).unwrap();
+        let color2 = color!(#d2e1dd).unwrap();
 
         let color3 = Color::mix(&color1, &color2, 0.5).unwrap();
         assert_eq!(color3.hex(), "#698aa2");
@@ -59,11 +311,146 @@ mod tests {
         assert_eq!(color7.hex(), "#036");
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_mix_error() {
-        let color1 = color!(#003366);
-        let color2 = color!(#d2e1dd);
+        let color1 = color!(#003366).unwrap();
+        let color2 = color!(#d2e1dd).unwrap();
         let result = Color::mix(&color1, &color2, 1.1);
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_color_mix() {
+        let srgb = MixOptions {
+            space: MixSpace::Predefined(PredefinedSpace::Srgb),
+            ..Default::default()
+        };
+        let color = Color::color_mix(
+            &color!(#f00).unwrap(),
+            None,
+            &color!(#00f).unwrap(),
+            None,
+            &srgb,
+        )
+        .unwrap();
+        assert_eq!(color.rgb(), "rgb(128, 0, 128)");
+
+        let color = Color::color_mix(
+            &color!(#f00).unwrap(),
+            Some(0.3),
+            &color!(#00f).unwrap(),
+            None,
+            &srgb,
+        )
+        .unwrap();
+        assert_eq!(color.rgb(), "rgb(77, 0, 179)");
+
+        // the sum scales the alpha
+        let color = Color::color_mix(
+            &color!(#f00).unwrap(),
+            Some(0.2),
+            &color!(#00f).unwrap(),
+            Some(0.2),
+            &srgb,
+        )
+        .unwrap();
+        assert_eq!(color.rgba(), "rgba(128, 0, 128, 0.4)");
+
+        // a transparent color does not tint the other one
+        let transparent = Color::new(0.0, 0.0, 255.0, 0.0);
+        let color =
+            Color::color_mix(&color!(#f00).unwrap(), None, &transparent, None, &srgb).unwrap();
+        assert_eq!(color.rgba(), "rgba(255, 0, 0, 0.5)");
+
+        let color = Color::color_mix(
+            &color!(#f00).unwrap(),
+            None,
+            &color!(#00f).unwrap(),
+            None,
+            &Default::default(),
+        )
+        .unwrap();
+        assert_eq!(color.hex(), "#8c53a2");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_color_mix_hue() {
+        let mix = |space, hue| {
+            let options = MixOptions { space, hue };
+            Color::color_mix(
+                &color!(#f00).unwrap(),
+                None,
+                &color!(#00f).unwrap(),
+                None,
+                &options,
+            )
+            .unwrap()
+            .hsl()
+        };
+        assert_eq!(
+            mix(MixSpace::Hsl, HueInterpolation::Shorter),
+            "hsl(300, 100%, 50%)"
+        );
+        assert_eq!(
+            mix(MixSpace::Hsl, HueInterpolation::Longer),
+            "hsl(120, 100%, 50%)"
+        );
+        assert_eq!(
+            mix(MixSpace::Hsl, HueInterpolation::Increasing),
+            "hsl(120, 100%, 50%)"
+        );
+        assert_eq!(
+            mix(MixSpace::Hsl, HueInterpolation::Decreasing),
+            "hsl(300, 100%, 50%)"
+        );
+
+        // the powerless hue of white takes the hue of red
+        let options = MixOptions {
+            space: MixSpace::Oklch,
+            ..Default::default()
+        };
+        let color = Color::color_mix(
+            &color!(#f00).unwrap(),
+            None,
+            &color!(#fff).unwrap(),
+            None,
+            &options,
+        )
+        .unwrap();
+        let hue = |color: &Color| round(MixSpace::Oklch.rgb2values(color.rgb)[2], 6);
+        assert_eq!(hue(&color), hue(&color!(#f00).unwrap()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_color_mix_error() {
+        let options = MixOptions::default();
+        let (red, blue) = (color!(#f00).unwrap(), color!(#00f).unwrap());
+        assert!(Color::color_mix(&red, Some(0.0), &blue, Some(0.0), &options).is_err());
+        assert!(Color::color_mix(&red, Some(1.5), &blue, None, &options).is_err());
+        assert!(Color::color_mix(&red, None, &blue, Some(-0.1), &options).is_err());
+    }
+
+    #[test]
+    fn test_mix_space_name() {
+        for space in [
+            MixSpace::Predefined(PredefinedSpace::DisplayP3),
+            MixSpace::Lab,
+            MixSpace::Lch,
+            MixSpace::Oklab,
+            MixSpace::Oklch,
+            MixSpace::Hsl,
+            MixSpace::Hwb,
+        ] {
+            assert_eq!(MixSpace::from_name(space.name()), Some(space));
+        }
+        assert_eq!(MixSpace::from_name("hsv"), None);
+        assert_eq!(
+            HueInterpolation::from_name("longer"),
+            Some(HueInterpolation::Longer)
+        );
+    }
 }
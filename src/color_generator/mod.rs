@@ -1,3 +1,4 @@
 pub mod average;
 pub mod mix;
+#[cfg(feature = "std")]
 pub mod random;
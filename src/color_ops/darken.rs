@@ -1,4 +1,4 @@
-use crate::{Color, ColorSpace};
+use crate::{Color, ColorSpace, Hsl};
 
 impl Color {
     /// Decrease the lightness of a color in the HSL color space by an absolute amount.
@@ -11,17 +11,17 @@ impl Color {
     /// ```
     /// use color_art::color;
     ///
-    /// let color = color!(#426105);
+    /// let color = color!(#426105).unwrap();
     /// let color = color.darken(0.1);
     /// assert_eq!(color.hex(), "#213102");
     /// ```
     pub fn darken(&self, amount: f64) -> Self {
-        let color = self.vec_of(ColorSpace::HSL);
+        let color = self.values_of(ColorSpace::HSL);
         let h = color[0];
         let s = color[1];
         let l = color[2];
-        let l = (l - amount).min(1.0).max(0.0);
-        Color::from_hsl(h, s, l).unwrap()
+        let l = (l - amount).clamp(0.0, 1.0);
+        Hsl { h, s, l }.into()
     }
     /// Increase the lightness of a color in the HSL color space by an absolute amount.
     ///
@@ -33,7 +33,7 @@ impl Color {
     /// ```
     /// use color_art::color;
     ///
-    /// let color = color!(#80e619);
+    /// let color = color!(#80e619).unwrap();
     /// let color = color.lighten(0.2);
     /// assert_eq!(color.hex(), "#b3f075");
     /// ```
@@ -42,36 +42,36 @@ impl Color {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::*;
 
     #[test]
     fn test_color_darken() {
-        let color = color!(#426105);
+        let color = color!(#426105).unwrap();
         let color = color.darken(0.1);
         assert_eq!(color.hex(), "#213102");
 
-        let color = color!(#426105);
+        let color = color!(#426105).unwrap();
         let color = color.darken(0.5);
         assert_eq!(color.hex(), "#000");
 
-        let color = color!(#80e619);
+        let color = color!(#80e619).unwrap();
         let color = color.darken(0.2);
         assert_eq!(color.hex(), "#4d8a0f");
     }
 
     #[test]
     fn test_color_lighten() {
-        let color = color!(#426105);
+        let color = color!(#426105).unwrap();
         let color = color.lighten(0.1);
         assert_eq!(color.hex(), "#639207");
 
-        let color = color!(#426105);
+        let color = color!(#426105).unwrap();
         let color = color.lighten(0.5);
         assert_eq!(color.hex(), "#c9f86d");
 
-        let color = color!(#80e619);
+        let color = color!(#80e619).unwrap();
         let color = color.lighten(0.2);
         assert_eq!(color.hex(), "#b3f075");
     }
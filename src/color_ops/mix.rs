@@ -13,13 +13,13 @@ impl Color {
     /// ```rust
     /// use color_art::color;
     ///
-    /// let color1 = color!(#998099);
-    /// let color2 = color!(#d2e1dd);
+    /// let color1 = color!(#998099).unwrap();
+    /// let color2 = color!(#d2e1dd).unwrap();
     /// let color3 = color1.mix_with(&color2, 0.5);
     /// assert_eq!(color3.hex(), "#b6b1bb");
     /// ```
     pub fn mix_with(&self, new_color: &Color, weight: f64) -> Self {
-        let weight = weight.min(1.0).max(0.0);
+        let weight = weight.clamp(0.0, 1.0);
         let old_weight = 1.0 - weight;
 
         let rgb1 = self.rgb;
@@ -43,7 +43,7 @@ impl Color {
     /// ```rust
     /// use color_art::color;
     ///
-    /// let color = color!(#ff00ff);
+    /// let color = color!(#ff00ff).unwrap();
     /// let color = color.tint(0.5);
     /// assert_eq!(color.hex(), "#ff80ff");
     /// ```
@@ -62,7 +62,7 @@ impl Color {
     /// ```rust
     /// use color_art::color;
     ///
-    /// let color = color!(#ff00ff);
+    /// let color = color!(#ff00ff).unwrap();
     /// let color = color.shade(0.5);
     /// assert_eq!(color.hex(), "#800080");
     /// ```
@@ -72,41 +72,41 @@ impl Color {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::*;
 
     #[test]
     fn test_mix() {
-        let color1 = color!(#003366);
-        let color2 = color!(#d2e1dd);
+        let color1 = color!(#003366).unwrap();
+        let color2 = color!(#d2e1dd).unwrap();
         let color3 = color1.mix_with(&color2, 0.5);
         assert_eq!(color3.hex(), "#698aa2");
 
-        let color1 = color!(#ff0000);
-        let color2 = color!(#0000ff);
+        let color1 = color!(#ff0000).unwrap();
+        let color2 = color!(#0000ff).unwrap();
         let color3 = color1.mix_with(&color2, 0.5);
         assert_eq!(color3.hex(), "#800080");
     }
 
     #[test]
     fn test_tint() {
-        let color = color!(rgba(0, 0, 255, 0.5));
+        let color = color!(rgba(0, 0, 255, 0.5)).unwrap();
         let color = color.tint(0.5);
         assert_eq!(color.rgba(), "rgba(128, 128, 255, 0.75)");
 
-        let color = color!(rgb(255, 0, 0));
+        let color = color!(rgb(255, 0, 0)).unwrap();
         let color = color.tint(0.5);
         assert_eq!(color.hex(), "#ff8080");
     }
 
     #[test]
     fn test_shade() {
-        let color = color!(rgba(0, 0, 255, 0.5));
+        let color = color!(rgba(0, 0, 255, 0.5)).unwrap();
         let color = color.shade(0.5);
         assert_eq!(color.rgba(), "rgba(0, 0, 128, 0.75)");
 
-        let color = color!(rgb(255, 0, 0));
+        let color = color!(rgb(255, 0, 0)).unwrap();
         let color = color.shade(0.5);
         assert_eq!(color.hex(), "#800000");
     }
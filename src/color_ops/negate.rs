@@ -8,7 +8,7 @@ impl Color {
     /// ```
     /// use color_art::color;
     ///
-    /// let color1 = color!(#f0f);
+    /// let color1 = color!(#f0f).unwrap();
     /// let color2 = color1.negate();
     /// assert_eq!(color2.hex(), "#0f0");
     /// ```
@@ -22,7 +22,7 @@ impl Color {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::*;
 
@@ -1,4 +1,4 @@
-use crate::{Color, ColorSpace};
+use crate::{Color, ColorSpace, Hsl};
 
 impl Color {
     /// Increase the saturation of a color in the HSL color space by an absolute amount.
@@ -11,17 +11,17 @@ impl Color {
     /// ```
     /// use color_art::color;
     ///
-    /// let color = color!(#80e619);
+    /// let color = color!(#80e619).unwrap();
     /// let color = color.saturate(0.2);
     /// assert_eq!(color.hex(), "#80ff00");
     /// ```
     pub fn saturate(&self, amount: f64) -> Self {
-        let color = self.vec_of(ColorSpace::HSL);
+        let color = self.values_of(ColorSpace::HSL);
         let h = color[0];
         let s = color[1];
         let l = color[2];
-        let s = (s + amount).min(1.0).max(0.0);
-        Color::from_hsl(h, s, l).unwrap()
+        let s = (s + amount).clamp(0.0, 1.0);
+        Hsl { h, s, l }.into()
     }
     /// Decrease the saturation of a color in the HSL color space by an absolute amount.
     ///
@@ -33,7 +33,7 @@ impl Color {
     /// ```
     /// use color_art::color;
     ///
-    /// let color = color!(#80e619);
+    /// let color = color!(#80e619).unwrap();
     /// let color = color.desaturate(0.2);
     /// assert_eq!(color.hex(), "#80cd32");
     /// ```
@@ -50,7 +50,7 @@ impl Color {
     /// use color_art::color;
     /// use std::str::FromStr;
     ///
-    /// let color = color!(#80e619);
+    /// let color = color!(#80e619).unwrap();
     /// let color = color.greyscale();
     /// assert_eq!(color.hex(), "#808080");
     /// ```
@@ -59,7 +59,7 @@ impl Color {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
     use std::str::FromStr;
@@ -1,4 +1,4 @@
-use crate::{Color, ColorSpace};
+use crate::{Color, ColorSpace, Hsl};
 
 impl Color {
     /// Rotate the hue angle of a color in either direction.
@@ -22,12 +22,12 @@ impl Color {
     /// assert_eq!(color.hsl(), "hsl(340, 90%, 50%)");
     /// ```
     pub fn spin(&self, angle: f64) -> Self {
-        let color = self.vec_of(ColorSpace::HSL);
+        let color = self.values_of(ColorSpace::HSL);
         let h = color[0];
         let s = color[1];
         let l = color[2];
         let h = (360.0 + h + angle) % 360.0;
-        Color::from_hsl(h, s, l).unwrap()
+        Hsl { h, s, l }.into()
     }
     /// Returns the [complement](https://en.wikipedia.org/wiki/Complementary_colors) of color.
     pub fn complement(&self) -> Self {
@@ -35,7 +35,7 @@ impl Color {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::*;
     use std::str::FromStr;
@@ -53,15 +53,15 @@ mod tests {
 
     #[test]
     fn test_color_complement() {
-        let color = color!(#6b717f);
+        let color = color!(#6b717f).unwrap();
         let color = color.complement();
         assert_eq!(color.hex(), "#7f796b");
 
-        let color = color!(#d2e1dd);
+        let color = color!(#d2e1dd).unwrap();
         let color = color.complement();
         assert_eq!(color.hex(), "#e1d2d6");
 
-        let color = color!(#036);
+        let color = color!(#036).unwrap();
         let color = color.complement();
         assert_eq!(color.hex(), "#630");
     }
@@ -0,0 +1,282 @@
+//! Finds the color literals in text, see [`scan_colors`] and [`replace_colors`].
+
+use crate::{data::hex_of_name, Color, ColorSpace, Span};
+use alloc::{string::String, vec::Vec};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The kinds of color literals [`scan_colors`] looks for.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{scan_colors, ScanOptions};
+///
+/// let options = ScanOptions {
+///     names: true,
+///     ..Default::default()
+/// };
+/// let matches = scan_colors("border: 1px solid red", &options);
+/// assert_eq!(matches[0].text, "red");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScanOptions {
+    /// Hex colors with 3, 4, 6 or 8 digits, e.g. `#0af`.
+    pub hex: bool,
+    /// The color functions like `rgb(0 170 255)`, `hsl()`, `color()` and `color-mix()`.
+    pub functions: bool,
+    /// The English color names like `red`. Off by default, as they are common words.
+    pub names: bool,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            hex: true,
+            functions: true,
+            names: false,
+        }
+    }
+}
+
+/// A color literal found by [`scan_colors`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ColorMatch<'a> {
+    /// The byte span of the literal in the text.
+    pub span: Span,
+    /// The literal as it is in the text.
+    pub text: &'a str,
+    /// The parsed color.
+    pub color: Color,
+}
+
+/// Finds the color literals in `text`, in order and without overlaps.
+///
+/// A literal stands on its own, `#0af` in `page#0af` or `&#123;` and `red` in `red-500`,
+/// `#red` or `.red` are not colors. Functions that fail to parse are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{scan_colors, ScanOptions, Span};
+///
+/// let css = ".btn { color: #0AF; background: rgb(255 0 0 / 50%); }";
+/// let matches = scan_colors(css, &ScanOptions::default());
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!(matches[0].span, Span::new(14, 18));
+/// assert_eq!(matches[0].color.hex(), "#0af");
+/// assert_eq!(matches[1].text, "rgb(255 0 0 / 50%)");
+/// ```
+pub fn scan_colors<'a>(text: &'a str, options: &ScanOptions) -> Vec<ColorMatch<'a>> {
+    let mut matches = Vec::new();
+    let mut pos = 0;
+    while let Some(c) = text[pos..].chars().next() {
+        let standalone = !matches!(
+            text[..pos].chars().next_back(),
+            Some(prev) if is_identifier_char(prev) || matches!(prev, '&' | '#' | '.')
+        );
+        let found = match c {
+            '#' if standalone && options.hex => scan_hex(text, pos),
+            c if standalone && c.is_ascii_alphabetic() => scan_word(text, pos, options),
+            _ => None,
+        };
+        match found {
+            Some(found) => {
+                pos = found.span.end;
+                matches.push(found);
+            }
+            None => pos += c.len_utf8(),
+        }
+    }
+    matches
+}
+
+/// Replaces the color literals in `text` with the strings of `replace`.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{replace_colors, ScanOptions};
+///
+/// let css = "a { color: #0AF; border-color: #FFF }";
+/// let css = replace_colors(css, &ScanOptions::default(), |m| m.text.to_lowercase());
+/// assert_eq!(css, "a { color: #0af; border-color: #fff }");
+///
+/// let svg = r#"<rect fill="rgb(255, 0, 0)" stroke="blue"/>"#;
+/// let options = ScanOptions {
+///     names: true,
+///     ..Default::default()
+/// };
+/// let svg = replace_colors(svg, &options, |m| m.color.hex());
+/// assert_eq!(svg, r##"<rect fill="#f00" stroke="#00f"/>"##);
+/// ```
+pub fn replace_colors<F>(text: &str, options: &ScanOptions, mut replace: F) -> String
+where
+    F: FnMut(&ColorMatch) -> String,
+{
+    let mut output = String::with_capacity(text.len());
+    let mut last = 0;
+    for found in scan_colors(text, options) {
+        output.push_str(&text[last..found.span.start]);
+        output.push_str(&replace(&found));
+        last = found.span.end;
+    }
+    output.push_str(&text[last..]);
+    output
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// The hex color at the `#` at `start`.
+fn scan_hex(text: &str, start: usize) -> Option<ColorMatch<'_>> {
+    let digits = text[start + 1..]
+        .find(|c: char| !c.is_ascii_hexdigit())
+        .unwrap_or(text.len() - start - 1);
+    let end = start + 1 + digits;
+    if !matches!(digits, 3 | 4 | 6 | 8) || text[end..].starts_with(is_identifier_char) {
+        return None;
+    }
+    let color = Color::from_hex(&text[start..end]).ok()?;
+    Some(ColorMatch {
+        span: Span::new(start, end),
+        text: &text[start..end],
+        color,
+    })
+}
+
+/// The color function or name of the word at `start`.
+fn scan_word<'a>(text: &'a str, start: usize, options: &ScanOptions) -> Option<ColorMatch<'a>> {
+    let word_end = text[start..]
+        .find(|c: char| !is_identifier_char(c))
+        .map_or(text.len(), |len| start + len);
+    let word = text[start..word_end].to_ascii_lowercase();
+
+    let end = if text[word_end..].starts_with('(') {
+        let is_function = ColorSpace::from(&word) != ColorSpace::Unknown
+            || matches!(word.as_str(), "color" | "color-mix" | "light-dark");
+        if !options.functions || !is_function {
+            return None;
+        }
+        word_end + closing_paren(&text[word_end..])? + 1
+    } else if options.names && hex_of_name(&word).is_some() {
+        word_end
+    } else {
+        return None;
+    };
+
+    let color = Color::from_str(&text[start..end]).ok()?;
+    Some(ColorMatch {
+        span: Span::new(start, end),
+        text: &text[start..end],
+        color,
+    })
+}
+
+/// Byte offset of the parenthesis closing the one at the start of `s`.
+fn closing_paren(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 1 => return Some(i),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn texts(text: &str, options: &ScanOptions) -> Vec<String> {
+        scan_colors(text, options)
+            .iter()
+            .map(|m| m.text.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_scan_hex() {
+        let options = ScanOptions::default();
+        assert_eq!(
+            texts("#fff #FFFF #abcdef #abcdef80 #12345", &options),
+            ["#fff", "#FFFF", "#abcdef", "#abcdef80"]
+        );
+        assert!(texts("page#fff &#123; #fffg #0af-1", &options).is_empty());
+        assert_eq!(
+            texts("(#fff)\"#000\",#f00;", &options),
+            ["#fff", "#000", "#f00"]
+        );
+    }
+
+    #[test]
+    fn test_scan_functions() {
+        let options = ScanOptions::default();
+        let text = "rgb(0, 0, 0) HSL(0 0% 0%) color(display-p3 1 0 0) rgb(0 0 300) rgb(0";
+        assert_eq!(
+            texts(text, &options),
+            ["rgb(0, 0, 0)", "HSL(0 0% 0%)", "color(display-p3 1 0 0)"]
+        );
+        assert_eq!(
+            texts(
+                "color-mix(in srgb, #f00, blue) myrgb(0, 0, 0) var(--x, #fff)",
+                &options
+            ),
+            ["color-mix(in srgb, #f00, blue)", "#fff"]
+        );
+        assert_eq!(
+            texts("rgb(from #0af r g b / 0.5)", &options),
+            ["rgb(from #0af r g b / 0.5)"]
+        );
+    }
+
+    #[test]
+    fn test_scan_names() {
+        assert!(texts("red", &ScanOptions::default()).is_empty());
+
+        let options = ScanOptions {
+            names: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            texts("Red; text-red red-500 bored, #red .red teal!", &options),
+            ["Red", "teal"]
+        );
+
+        let options = ScanOptions {
+            hex: false,
+            functions: false,
+            names: true,
+        };
+        assert_eq!(texts("#fff rgb(0, 0, 0) blue", &options), ["blue"]);
+    }
+
+    #[test]
+    fn test_scan_spans() {
+        let text = "é #0af rgb(1 2 3)";
+        let matches = scan_colors(text, &ScanOptions::default());
+        assert_eq!(matches[0].span, Span::new(3, 7));
+        assert_eq!(matches[1].span, Span::new(8, 18));
+        for m in matches {
+            assert_eq!(&text[m.span.range()], m.text);
+        }
+    }
+
+    #[test]
+    fn test_replace_colors() {
+        let text = "a: #FFF; b: rgb(255 0 0); c: none";
+        let output = replace_colors(text, &ScanOptions::default(), |m| m.color.hex());
+        assert_eq!(output, "a: #fff; b: #f00; c: none");
+
+        let output = replace_colors("no colors", &ScanOptions::default(), |m| m.color.hex());
+        assert_eq!(output, "no colors");
+    }
+}
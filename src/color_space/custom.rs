@@ -0,0 +1,348 @@
+use crate::{
+    conversion::utils::{gam_srgb, lin_srgb, lin_srgb_to_xyz, xyz_to_lin_srgb},
+    ColorSpace, Error,
+};
+use std::{
+    ops::RangeInclusive,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+static REGISTRY: RwLock<Vec<Arc<dyn ColorSpaceDef>>> = RwLock::new(Vec::new());
+
+/// The space a [`ColorSpaceDef`] converts to and from.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ReferenceSpace {
+    /// CIE XYZ with a D65 white, the same values as [`ColorSpace::XYZ`].
+    #[default]
+    Xyz,
+    /// Gamma encoded sRGB with channels in `0.0..=255.0`, the same values as [`ColorSpace::RGB`].
+    Srgb,
+}
+
+/// Definition of a user-defined color space.
+///
+/// Once registered with [`register_color_space`], the space is available as a [`ColorSpace`]
+/// and takes part in parsing, formatting, validation, [`Color::vec_of`](crate::Color::vec_of)
+/// and [`distance_with`](crate::distance_with).
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{register_color_space, Color, ColorSpace, ColorSpaceDef, ReferenceSpace};
+/// use std::{ops::RangeInclusive, str::FromStr};
+///
+/// /// sRGB with the channels in 0..1
+/// struct UnitRgb;
+///
+/// impl ColorSpaceDef for UnitRgb {
+///     fn name(&self) -> &str {
+///         "unitrgb"
+///     }
+///     fn channel_ranges(&self) -> Vec<RangeInclusive<f64>> {
+///         vec![0.0..=1.0, 0.0..=1.0, 0.0..=1.0]
+///     }
+///     fn reference_space(&self) -> ReferenceSpace {
+///         ReferenceSpace::Srgb
+///     }
+///     fn to_reference(&self, values: &[f64]) -> [f64; 3] {
+///         [values[0] * 255.0, values[1] * 255.0, values[2] * 255.0]
+///     }
+///     fn reference_to_values(&self, rgb: [f64; 3]) -> Vec<f64> {
+///         rgb.iter().map(|v| v / 255.0).collect()
+///     }
+/// }
+///
+/// let unit_rgb = register_color_space(UnitRgb).unwrap();
+///
+/// let color = Color::from_str("unitrgb(1, 0.2, 0.6)").unwrap();
+/// assert_eq!(color.hex(), "#f39");
+/// assert_eq!(color.vec_of(unit_rgb), vec![1.0, 0.2, 0.6]);
+/// assert_eq!(color.stringify(unit_rgb), "unitrgb(1, 0.2, 0.6)");
+/// assert!(Color::from_str("unitrgb(2, 0, 0)").is_err());
+/// ```
+pub trait ColorSpaceDef: Send + Sync {
+    /// The CSS-like function name of the space, e.g. `led` for `led(0.2, 0.4, 0.6)`.
+    ///
+    /// Names are ASCII letters only and are matched case-insensitively.
+    fn name(&self) -> &str;
+    /// The valid range of each channel, this also defines the number of channels (1 to 4).
+    fn channel_ranges(&self) -> Vec<RangeInclusive<f64>>;
+    /// The space that [`to_reference`](ColorSpaceDef::to_reference) and
+    /// [`reference_to_values`](ColorSpaceDef::reference_to_values) work with.
+    fn reference_space(&self) -> ReferenceSpace {
+        ReferenceSpace::Xyz
+    }
+    /// Converts the channel values to the reference space.
+    fn to_reference(&self, values: &[f64]) -> [f64; 3];
+    /// Converts a color of the reference space to the channel values.
+    fn reference_to_values(&self, reference: [f64; 3]) -> Vec<f64>;
+    /// Formats the channel values, by default as `name(v1, v2, ...)` with 4 decimals.
+    fn format(&self, values: &[f64]) -> String {
+        let values = values
+            .iter()
+            .map(|&v| crate::utils::round(v, 4).to_string())
+            .collect::<Vec<_>>();
+        format!("{}({})", self.name(), values.join(", "))
+    }
+}
+
+/// Handle of a registered user-defined color space, see [`register_color_space`].
+///
+/// With serde, the space is serialized by its name and deserialized by looking the name up in
+/// the registry, the handle itself depends on the order of the registrations.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CustomSpace(usize);
+
+/// Registers a user-defined color space and returns its [`ColorSpace`].
+///
+/// Fails when the name is not made of ASCII letters, is already taken by a built-in or
+/// registered space, or when the space does not have 1 to 4 channels.
+pub fn register_color_space(def: impl ColorSpaceDef + 'static) -> Result<ColorSpace, Error> {
+    let name = def.name();
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(Error::InvalidParamsError(
+            format!(
+                "Color space name must be ASCII letters only, got '{}'",
+                name
+            )
+            .into(),
+        ));
+    }
+    if ColorSpace::from(name) != ColorSpace::Unknown {
+        return Err(Error::InvalidParamsError(
+            format!("Color space '{}' is already defined", name).into(),
+        ));
+    }
+    let channel_count = def.channel_ranges().len();
+    if !(1..=4).contains(&channel_count) {
+        return Err(Error::InvalidParamsError(
+            format!(
+                "Color space must have 1 to 4 channels, got {}",
+                channel_count
+            )
+            .into(),
+        ));
+    }
+
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    // checked again under the write lock in case of a concurrent registration
+    if find_in(&registry, name).is_some() {
+        return Err(Error::InvalidParamsError(
+            format!("Color space '{}' is already defined", name).into(),
+        ));
+    }
+    registry.push(Arc::new(def));
+    Ok(ColorSpace::Custom(CustomSpace(registry.len() - 1)))
+}
+
+fn find_in(registry: &[Arc<dyn ColorSpaceDef>], name: &str) -> Option<usize> {
+    registry
+        .iter()
+        .position(|def| def.name().eq_ignore_ascii_case(name))
+}
+
+impl CustomSpace {
+    /// Looks up a registered space by name.
+    pub(crate) fn find(name: &str) -> Option<CustomSpace> {
+        find_in(
+            &REGISTRY.read().unwrap_or_else(PoisonError::into_inner),
+            name,
+        )
+        .map(CustomSpace)
+    }
+    /// The definition of the space, an error for a handle that is not in the registry.
+    pub(crate) fn def(&self) -> Result<Arc<dyn ColorSpaceDef>, Error> {
+        REGISTRY
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(self.0)
+            .cloned()
+            .ok_or_else(|| {
+                Error::InvalidParamsError(
+                    format!("Custom color space #{} is not registered", self.0).into(),
+                )
+            })
+    }
+    /// The name of the space.
+    pub fn name(&self) -> Result<String, Error> {
+        Ok(self.def()?.name().to_string())
+    }
+    pub(crate) fn value_count(&self) -> Result<usize, Error> {
+        Ok(self.channel_ranges()?.len())
+    }
+    /// The valid range of each channel.
+    pub(crate) fn channel_ranges(&self) -> Result<Vec<RangeInclusive<f64>>, Error> {
+        Ok(self.def()?.channel_ranges())
+    }
+    /// Converts RGB (0..255) to the channel values, padded to 4 values.
+    pub(crate) fn rgb_to_values(self, rgb: [f64; 3]) -> Result<[f64; 4], Error> {
+        Ok(rgb_to_values(&*self.def()?, rgb))
+    }
+    /// Converts the channel values to RGB (0..255).
+    pub(crate) fn values_to_rgb(self, values: &[f64]) -> Result<[f64; 3], Error> {
+        Ok(values_to_rgb(&*self.def()?, values))
+    }
+}
+
+/// Converts RGB (0..255) to the channel values of `def`, padded to 4 values.
+pub(crate) fn rgb_to_values(def: &dyn ColorSpaceDef, rgb: [f64; 3]) -> [f64; 4] {
+    let reference = match def.reference_space() {
+        ReferenceSpace::Xyz => lin_srgb_to_xyz(lin_srgb(rgb.map(|v| v / 255.0))),
+        ReferenceSpace::Srgb => rgb,
+    };
+    let mut values = [0.0; 4];
+    values
+        .iter_mut()
+        .zip(def.reference_to_values(reference))
+        .for_each(|(v, c)| *v = c);
+    values
+}
+
+/// Converts the channel values of `def` to RGB (0..255).
+pub(crate) fn values_to_rgb(def: &dyn ColorSpaceDef, values: &[f64]) -> [f64; 3] {
+    let reference = def.to_reference(values);
+    match def.reference_space() {
+        ReferenceSpace::Xyz => gam_srgb(xyz_to_lin_srgb(reference)).map(|v| v * 255.0),
+        ReferenceSpace::Srgb => reference,
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CustomSpace {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = self.name().map_err(ser::Error::custom)?;
+        serializer.serialize_str(&name)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CustomSpace {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        CustomSpace::find(&name)
+            .ok_or_else(|| de::Error::custom(format!("Unknown custom color space '{}'", name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{color, distance_with, Color};
+    use std::str::FromStr;
+
+    /// XYZ scaled to 0..100
+    struct Xyz100;
+
+    impl ColorSpaceDef for Xyz100 {
+        fn name(&self) -> &str {
+            "xyzhundred"
+        }
+        fn channel_ranges(&self) -> Vec<RangeInclusive<f64>> {
+            vec![0.0..=100.0, 0.0..=100.0, 0.0..=110.0]
+        }
+        fn to_reference(&self, values: &[f64]) -> [f64; 3] {
+            [values[0] / 100.0, values[1] / 100.0, values[2] / 100.0]
+        }
+        fn reference_to_values(&self, xyz: [f64; 3]) -> Vec<f64> {
+            xyz.iter().map(|v| v * 100.0).collect()
+        }
+    }
+
+    struct Gray(&'static str);
+
+    impl ColorSpaceDef for Gray {
+        fn name(&self) -> &str {
+            self.0
+        }
+        fn channel_ranges(&self) -> Vec<RangeInclusive<f64>> {
+            vec![0.0..=1.0]
+        }
+        fn reference_space(&self) -> ReferenceSpace {
+            ReferenceSpace::Srgb
+        }
+        fn to_reference(&self, values: &[f64]) -> [f64; 3] {
+            [values[0] * 255.0; 3]
+        }
+        fn reference_to_values(&self, rgb: [f64; 3]) -> Vec<f64> {
+            vec![(rgb[0] + rgb[1] + rgb[2]) / 3.0 / 255.0]
+        }
+        fn format(&self, values: &[f64]) -> String {
+            format!("{}({}%)", self.name(), (values[0] * 100.0).round())
+        }
+    }
+
+    #[test]
+    fn test_custom_space_xyz_reference() {
+        let space = register_color_space(Xyz100).unwrap();
+        assert_eq!(ColorSpace::from("XyzHundred"), space);
+
+        let color = color!(#7654cd).unwrap();
+        let xyz = color.vec_of(ColorSpace::XYZ);
+        let xyz100 = color.vec_of(space);
+        for (a, b) in xyz.iter().zip(xyz100.iter()) {
+            assert!((a * 100.0 - b).abs() < 1e-9);
+        }
+
+        let color = Color::from_str("xyzhundred(41.2391, 21.2639, 1.9331)").unwrap();
+        assert_eq!(color.hex(), "#f00");
+
+        assert!(distance_with(&color!(#f00).unwrap(), &color!(#0f0).unwrap(), space) > 0.0);
+        assert_eq!(
+            distance_with(&color!(#f00).unwrap(), &color!(#f00).unwrap(), space),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_custom_space_srgb_reference() {
+        let space = register_color_space(Gray("graylevel")).unwrap();
+        let color = Color::from_str("graylevel(50%)").unwrap();
+        assert_eq!(color.rgb(), "rgb(128, 128, 128)");
+        assert_eq!(color.stringify(space), "graylevel(50%)");
+        assert!(Color::from_str("graylevel(0.5, 0.5)").is_err());
+        assert!(Color::from_str("graylevel(1.5)").is_err());
+    }
+
+    #[test]
+    fn test_unregistered_custom_space() {
+        let space = ColorSpace::Custom(CustomSpace(usize::MAX));
+        let color = color!(#f39).unwrap();
+        assert!(CustomSpace(usize::MAX).def().is_err());
+        assert!(color.try_vec_of(space).is_err());
+        assert!(color.try_stringify(space).is_err());
+        assert!(space.valid(&[0.0]).is_err());
+        assert_eq!(color.vec_of(space), Vec::<f64>::new());
+        assert_eq!(color.stringify(space), "#f39");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_custom_space_serde() {
+        let space = register_color_space(Gray("serdegray")).unwrap();
+        let json = serde_json::to_string(&space).unwrap();
+        assert_eq!(json, r#"{"Custom":"serdegray"}"#);
+        assert_eq!(serde_json::from_str::<ColorSpace>(&json).unwrap(), space);
+        assert_eq!(
+            serde_json::from_str::<ColorSpace>(r#"{"Custom":"SerdeGray"}"#).unwrap(),
+            space
+        );
+
+        let err = serde_json::from_str::<ColorSpace>(r#"{"Custom":"nosuchspace"}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unknown custom color space 'nosuchspace'"));
+        assert!(serde_json::from_str::<ColorSpace>(r#"{"Custom":7}"#).is_err());
+        assert!(serde_json::to_string(&CustomSpace(usize::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_register_color_space_err() {
+        assert!(register_color_space(Gray("rgb")).is_err());
+        assert!(register_color_space(Gray("gray2")).is_err());
+        assert!(register_color_space(Gray("dupgray")).is_ok());
+        assert!(register_color_space(Gray("DupGray")).is_err());
+    }
+}
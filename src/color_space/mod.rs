@@ -1,4 +1,13 @@
+#[cfg(feature = "std")]
+pub mod custom;
+pub mod predefined;
 pub mod space;
+pub mod typed;
+#[cfg(feature = "alloc")]
 pub(crate) mod valid;
 
+#[cfg(feature = "std")]
+pub use custom::{register_color_space, ColorSpaceDef, CustomSpace, ReferenceSpace};
+pub use predefined::PredefinedSpace;
 pub use space::ColorSpace;
+pub use typed::*;
@@ -0,0 +1,106 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The predefined color spaces of the CSS `color()` function.
+///
+/// See [CSS Color 4](https://www.w3.org/TR/css-color-4/#predefined).
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{Color, PredefinedSpace};
+/// use std::str::FromStr;
+///
+/// let color = Color::from_str("color(display-p3 1 0 0)").unwrap();
+/// assert!(!color.is_in_gamut());
+/// assert_eq!(color.css_color(PredefinedSpace::DisplayP3), "color(display-p3 1 0 0)");
+/// assert_eq!(PredefinedSpace::DisplayP3.name(), "display-p3");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PredefinedSpace {
+    /// sRGB, the space of `rgb()` and hex colors.
+    Srgb,
+    /// sRGB without the transfer function.
+    SrgbLinear,
+    /// Display P3, the wide gamut of recent displays.
+    DisplayP3,
+    /// Adobe RGB (1998).
+    A98Rgb,
+    /// ProPhoto RGB, a very wide gamut with a D50 white.
+    ProphotoRgb,
+    /// ITU-R BT.2020, the gamut of UHDTV.
+    Rec2020,
+    /// CIE XYZ with a D50 white.
+    XyzD50,
+    /// CIE XYZ with a D65 white, `xyz` is an alias.
+    XyzD65,
+}
+
+impl PredefinedSpace {
+    /// The name of the space in the `color()` function.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PredefinedSpace::Srgb => "srgb",
+            PredefinedSpace::SrgbLinear => "srgb-linear",
+            PredefinedSpace::DisplayP3 => "display-p3",
+            PredefinedSpace::A98Rgb => "a98-rgb",
+            PredefinedSpace::ProphotoRgb => "prophoto-rgb",
+            PredefinedSpace::Rec2020 => "rec2020",
+            PredefinedSpace::XyzD50 => "xyz-d50",
+            PredefinedSpace::XyzD65 => "xyz-d65",
+        }
+    }
+    /// Looks up a space by its name in the `color()` function.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let space = match name {
+            "srgb" => PredefinedSpace::Srgb,
+            "srgb-linear" => PredefinedSpace::SrgbLinear,
+            "display-p3" => PredefinedSpace::DisplayP3,
+            "a98-rgb" => PredefinedSpace::A98Rgb,
+            "prophoto-rgb" => PredefinedSpace::ProphotoRgb,
+            "rec2020" => PredefinedSpace::Rec2020,
+            "xyz-d50" => PredefinedSpace::XyzD50,
+            "xyz-d65" | "xyz" => PredefinedSpace::XyzD65,
+            _ => return None,
+        };
+        Some(space)
+    }
+    /// Whether the channels are X, Y and Z rather than red, green and blue.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn is_xyz(&self) -> bool {
+        matches!(self, PredefinedSpace::XyzD50 | PredefinedSpace::XyzD65)
+    }
+}
+
+impl core::fmt::Display for PredefinedSpace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predefined_space_name() {
+        for space in [
+            PredefinedSpace::Srgb,
+            PredefinedSpace::SrgbLinear,
+            PredefinedSpace::DisplayP3,
+            PredefinedSpace::A98Rgb,
+            PredefinedSpace::ProphotoRgb,
+            PredefinedSpace::Rec2020,
+            PredefinedSpace::XyzD50,
+            PredefinedSpace::XyzD65,
+        ] {
+            assert_eq!(PredefinedSpace::from_name(space.name()), Some(space));
+        }
+        assert_eq!(
+            PredefinedSpace::from_name("xyz"),
+            Some(PredefinedSpace::XyzD65)
+        );
+        assert_eq!(PredefinedSpace::from_name("p3"), None);
+    }
+}
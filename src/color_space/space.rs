@@ -1,9 +1,18 @@
+#[cfg(feature = "std")]
+use super::custom::CustomSpace;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Color space enum.
+///
+/// The enum is non-exhaustive: the `Custom` variant only exists with the `std` feature, and
+/// more color spaces may be added.
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum ColorSpace {
     /// RGB color space.
     ///
@@ -62,18 +71,33 @@ pub enum ColorSpace {
     ///
     /// Lab stands for lightness, a, and b.
     Lab,
+    /// LCH color space, the polar form of Lab.
+    ///
+    /// LCH stands for lightness, chroma, and hue.
+    Lch,
+    /// [Oklab](https://bottosson.github.io/posts/oklab/) color space.
+    ///
+    /// Oklab stands for the perceptual lightness, a, and b.
+    Oklab,
+    /// Oklch color space, the polar form of Oklab.
+    Oklch,
+    /// User-defined color space, see [`register_color_space`](crate::register_color_space).
+    #[cfg(feature = "std")]
+    Custom(CustomSpace),
     /// Unknown color space.
     ///
     /// To be used when the color space is not known.
     Unknown,
 }
 
+#[cfg(feature = "alloc")]
 impl<T> From<T> for ColorSpace
 where
     T: ToString,
 {
     fn from(s: T) -> Self {
-        match s.to_string().to_lowercase().as_str() {
+        let name = s.to_string().to_lowercase();
+        match name.as_str() {
             "rgb" => ColorSpace::RGB,
             "rgba" => ColorSpace::RGBA,
             "hsi" => ColorSpace::HSI,
@@ -89,6 +113,14 @@ where
             "yuv" => ColorSpace::YUV,
             "ycbcr" => ColorSpace::YCbCr,
             "lab" => ColorSpace::Lab,
+            "lch" => ColorSpace::Lch,
+            "oklab" => ColorSpace::Oklab,
+            "oklch" => ColorSpace::Oklch,
+            #[cfg(feature = "std")]
+            _ => CustomSpace::find(&name)
+                .map(ColorSpace::Custom)
+                .unwrap_or(ColorSpace::Unknown),
+            #[cfg(not(feature = "std"))]
             _ => ColorSpace::Unknown,
         }
     }
@@ -112,12 +144,18 @@ impl ColorSpace {
             ColorSpace::YUV => 3,
             ColorSpace::YCbCr => 3,
             ColorSpace::Lab => 3,
+            ColorSpace::Lch => 3,
+            ColorSpace::Oklab => 3,
+            ColorSpace::Oklch => 3,
+            #[cfg(feature = "std")]
+            // an unregistered space has no values, like the unknown one
+            ColorSpace::Custom(space) => space.value_count().unwrap_or(0),
             ColorSpace::Unknown => 0,
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
 
@@ -0,0 +1,382 @@
+use crate::{
+    conversion,
+    utils::{lit, Float},
+    Color, ColorSpace,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Implements the conversions between a typed color struct and [`Color`].
+///
+/// `$to_rgb` converts the channel values (without alpha) back to RGB.
+macro_rules! impl_typed_color {
+    ($name:ident, $color_space:ident, [$($field:ident),+] $(+ $alpha:ident)?, $to_rgb:expr) => {
+        impl<T: Float> From<&Color<T>> for $name<T> {
+            fn from(color: &Color<T>) -> Self {
+                let mut values = color.values_of(ColorSpace::$color_space).into_iter();
+                $name {
+                    $($field: values.next().unwrap_or_default(),)+
+                    $($alpha: color.alpha,)?
+                }
+            }
+        }
+
+        impl<T: Float> From<Color<T>> for $name<T> {
+            fn from(color: Color<T>) -> Self {
+                $name::from(&color)
+            }
+        }
+
+        impl<T: Float> From<$name<T>> for Color<T> {
+            fn from(value: $name<T>) -> Self {
+                let rgb = ($to_rgb)([$(value.$field),+]);
+                #[allow(unused_variables)]
+                let alpha = T::ONE;
+                $(let alpha = value.$alpha;)?
+                Color { rgb, alpha }
+            }
+        }
+    };
+}
+
+/// Wraps the hue into `0.0..360.0`, the hue based conversions expect it in that range.
+fn wrap_hue<T: Float>([h, a, b]: [T; 3]) -> [T; 3] {
+    [h.rem_euclid(lit(360.0)), a, b]
+}
+
+/// Color in the RGB color space.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{color, Color, Rgb};
+///
+/// let rgb = Rgb::from(color!(#ff3399).unwrap());
+/// assert_eq!(rgb, Rgb { r: 255.0, g: 51.0, b: 153.0 });
+///
+/// let color: Color = Rgb { r: 255.0, g: 51.0, b: 153.0 }.into();
+/// assert_eq!(color.hex(), "#f39");
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rgb<T = f64> {
+    /// Red value (0-255)
+    pub r: T,
+    /// Green value (0-255)
+    pub g: T,
+    /// Blue value (0-255)
+    pub b: T,
+}
+
+impl_typed_color!(Rgb, RGB, [r, g, b], |v: [T; 3]| v);
+
+/// Color in the RGB color space with an alpha channel.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rgba<T = f64> {
+    /// Red value (0-255)
+    pub r: T,
+    /// Green value (0-255)
+    pub g: T,
+    /// Blue value (0-255)
+    pub b: T,
+    /// Alpha value (0-1)
+    pub alpha: T,
+}
+
+impl_typed_color!(Rgba, RGB, [r, g, b] + alpha, |v: [T; 3]| v);
+
+/// Color in the HSL color space.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{color, Hsl};
+///
+/// let hsl: Hsl = color!(#80e619).unwrap().into();
+/// assert_eq!(hsl.l, 0.5);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hsl<T = f64> {
+    /// Hue in degrees (0-360)
+    pub h: T,
+    /// Saturation (0-1)
+    pub s: T,
+    /// Lightness (0-1)
+    pub l: T,
+}
+
+impl_typed_color!(Hsl, HSL, [h, s, l], |v| conversion::hsl::hsl2rgb(wrap_hue(
+    v
+)));
+
+/// Color in the HSL color space with an alpha channel.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hsla<T = f64> {
+    /// Hue in degrees (0-360)
+    pub h: T,
+    /// Saturation (0-1)
+    pub s: T,
+    /// Lightness (0-1)
+    pub l: T,
+    /// Alpha value (0-1)
+    pub alpha: T,
+}
+
+impl_typed_color!(Hsla, HSL, [h, s, l] + alpha, |v| conversion::hsl::hsl2rgb(
+    wrap_hue(v)
+));
+
+/// Color in the HSV color space.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hsv<T = f64> {
+    /// Hue in degrees (0-360)
+    pub h: T,
+    /// Saturation (0-1)
+    pub s: T,
+    /// Value (0-1)
+    pub v: T,
+}
+
+impl_typed_color!(Hsv, HSV, [h, s, v], |v| conversion::hsv::hsv2rgb(wrap_hue(
+    v
+)));
+
+/// Color in the HSI color space.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hsi<T = f64> {
+    /// Hue in degrees (0-360)
+    pub h: T,
+    /// Saturation (0-1)
+    pub s: T,
+    /// Intensity (0-1)
+    pub i: T,
+}
+
+impl_typed_color!(Hsi, HSI, [h, s, i], |v| conversion::hsi::hsi2rgb(wrap_hue(
+    v
+)));
+
+/// Color in the HWB color space.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hwb<T = f64> {
+    /// Hue in degrees (0-360)
+    pub h: T,
+    /// Whiteness (0-1)
+    pub w: T,
+    /// Blackness (0-1)
+    pub b: T,
+}
+
+impl_typed_color!(Hwb, HWB, [h, w, b], |v| conversion::hwb::hwb2rgb(wrap_hue(
+    v
+)));
+
+/// Color in the CMYK color space.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{color, Cmyk};
+///
+/// let cmyk = Cmyk::from(color!(#ff3399).unwrap());
+/// assert_eq!(cmyk, Cmyk { c: 0.0, m: 0.8, y: 0.4, k: 0.0 });
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cmyk<T = f64> {
+    /// Cyan (0-1)
+    pub c: T,
+    /// Magenta (0-1)
+    pub m: T,
+    /// Yellow (0-1)
+    pub y: T,
+    /// Black (0-1)
+    pub k: T,
+}
+
+impl_typed_color!(Cmyk, CMYK, [c, m, y, k], conversion::cmyk::cmyk2rgb);
+
+/// Color in the CIE XYZ color space (D65 white).
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Xyz<T = f64> {
+    /// X
+    pub x: T,
+    /// Y, the luminance
+    pub y: T,
+    /// Z
+    pub z: T,
+}
+
+impl_typed_color!(Xyz, XYZ, [x, y, z], conversion::xyz::xyz2rgb);
+
+/// Color in the YIQ color space.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Yiq<T = f64> {
+    /// Luminance (0-1)
+    pub y: T,
+    /// In-phase chrominance
+    pub i: T,
+    /// Quadrature chrominance
+    pub q: T,
+}
+
+impl_typed_color!(Yiq, YIQ, [y, i, q], conversion::yiq::yiq2rgb);
+
+/// Color in the YUV color space.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Yuv<T = f64> {
+    /// Luminance (0-1)
+    pub y: T,
+    /// Blue projection chrominance
+    pub u: T,
+    /// Red projection chrominance
+    pub v: T,
+}
+
+impl_typed_color!(Yuv, YUV, [y, u, v], conversion::yuv::yuv2rgb);
+
+/// Color in the YCbCr color space.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct YCbCr<T = f64> {
+    /// Luminance (0-255)
+    pub y: T,
+    /// Blue-difference chroma (0-255)
+    pub cb: T,
+    /// Red-difference chroma (0-255)
+    pub cr: T,
+}
+
+impl_typed_color!(YCbCr, YCbCr, [y, cb, cr], conversion::ycbcr::ycbcr2rgb);
+
+/// Color in the CIE Lab color space (D50 white).
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{color, Lab};
+///
+/// let lab = Lab::from(color!(#fff).unwrap());
+/// assert_eq!(lab.l.round(), 100.0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lab<T = f64> {
+    /// Lightness (0-100)
+    pub l: T,
+    /// Green-red axis
+    pub a: T,
+    /// Blue-yellow axis
+    pub b: T,
+}
+
+impl_typed_color!(Lab, Lab, [l, a, b], conversion::lab::lab2rgb);
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::color;
+
+    #[test]
+    fn test_typed_from_color() {
+        let color = color!(rgba(255, 51, 153, 0.5)).unwrap();
+
+        assert_eq!(
+            Rgba::from(color),
+            Rgba {
+                r: 255.0,
+                g: 51.0,
+                b: 153.0,
+                alpha: 0.5
+            }
+        );
+        assert_eq!(
+            Hsv::from(color),
+            Hsv {
+                h: 330.0,
+                s: 0.8,
+                v: 1.0
+            }
+        );
+        assert_eq!(Hsla::from(&color).alpha, 0.5);
+        assert_eq!(Hsl::from(&color).h, 330.0);
+        assert_eq!(Hwb::from(&color).w, 0.2);
+    }
+
+    #[test]
+    fn test_typed_into_color() {
+        let color: Color = Hsl {
+            h: 330.0,
+            s: 1.0,
+            l: 0.6,
+        }
+        .into();
+        assert_eq!(color.hex(), "#f39");
+
+        let color: Color = Hsla {
+            h: 360.0,
+            s: 1.0,
+            l: 0.5,
+            alpha: 0.5,
+        }
+        .into();
+        assert_eq!(color.rgba(), "rgba(255, 0, 0, 0.5)");
+
+        let color: Color = Hsv {
+            h: -30.0,
+            s: 0.8,
+            v: 1.0,
+        }
+        .into();
+        assert_eq!(color.hex(), "#f39");
+
+        let color: Color = Cmyk {
+            c: 0.0,
+            m: 0.8,
+            y: 0.4,
+            k: 0.0,
+        }
+        .into();
+        assert_eq!(color.hex(), "#f39");
+    }
+
+    #[test]
+    fn test_typed_round_trip() {
+        let color = color!(#7654cd).unwrap();
+
+        assert_eq!(Color::from(Rgb::from(color)), color);
+        assert_eq!(Color::from(Hsi::from(color)).hex(), "#7654cd");
+        assert_eq!(Color::from(Hwb::from(color)).hex(), "#7654cd");
+        assert_eq!(Color::from(Xyz::from(color)).hex(), "#7654cd");
+        assert_eq!(Color::from(Yiq::from(color)).hex(), "#7654cd");
+        assert_eq!(Color::from(Yuv::from(color)).hex(), "#7654cd");
+        assert_eq!(Color::from(YCbCr::from(color)).hex(), "#7654cd");
+        assert_eq!(Color::from(Lab::from(color)).hex(), "#7654cd");
+    }
+
+    #[test]
+    fn test_typed_f32() {
+        let color: Color<f32> = color!(rgba(255, 51, 153, 0.5)).unwrap().into();
+
+        let hsla = Hsla::from(color);
+        assert_eq!(hsla.h, 330.0_f32);
+        assert_eq!(hsla.alpha, 0.5_f32);
+
+        let color: Color = Color::<f32>::from(hsla).into();
+        assert_eq!(color.rgba(), "rgba(255, 51, 153, 0.5)");
+
+        let lab = Lab::from(Color::<f32>::from(color!(#7654cd).unwrap()));
+        let color: Color = Color::from(lab).into();
+        assert_eq!(color.hex(), "#7654cd");
+    }
+}
@@ -1,269 +1,207 @@
-use crate::{ColorSpace, Error};
+use crate::{ColorSpace, Error, PredefinedSpace, Span};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+const INF: f64 = f64::INFINITY;
+
+/// Hues are angles, any finite hue is wrapped into `0..360`.
+const HUE: (&str, f64, f64) = ("Hue", -INF, INF);
+const UNIT_ALPHA: (&str, f64, f64) = ("Alpha", 0.0, 1.0);
 
 impl ColorSpace {
     /// Check if a vector of values is valid for a given color space.
     pub(crate) fn valid(&self, vec: &[f64]) -> Result<(), Error> {
-        let result = match self {
-            ColorSpace::RGB => valid_rgb(vec),
-            ColorSpace::RGBA => valid_rgba(vec),
-            ColorSpace::HSI => valid_hsi(vec),
-            ColorSpace::HSL => valid_hsl(vec),
-            ColorSpace::HSLA => valid_hsla(vec),
-            ColorSpace::HSV => valid_hsv(vec),
-            ColorSpace::HWB => valid_hwb(vec),
-            ColorSpace::CMYK => valid_cmyk(vec),
-            ColorSpace::XYZ => valid_xyz(vec),
-            ColorSpace::YIQ => valid_yiq(vec),
-            ColorSpace::YUV => valid_yuv(vec),
-            ColorSpace::YCbCr => valid_ycbcr(vec),
-            ColorSpace::Lab => valid_lab(vec),
-            ColorSpace::HEX | ColorSpace::HEXA => Some(
+        self.valid_spanned(vec, &[], Span::default())
+    }
+    /// Check if a vector of values is valid for a given color space.
+    ///
+    /// An out of range value points at its entry of `spans`, a wrong number of values at `span`.
+    pub(crate) fn valid_spanned(
+        &self,
+        vec: &[f64],
+        spans: &[Span],
+        span: Span,
+    ) -> Result<(), Error> {
+        valid_channels(self.function_name(), self.channels()?, vec, spans, span)
+    }
+    /// The name and the valid range of each channel.
+    fn channels(&self) -> Result<Vec<(String, f64, f64)>, Error> {
+        let channels: &[(&str, f64, f64)] = match self {
+            ColorSpace::RGB => &[
+                ("Red", 0.0, 255.0),
+                ("Green", 0.0, 255.0),
+                ("Blue", 0.0, 255.0),
+            ],
+            ColorSpace::RGBA => &[
+                ("Red", 0.0, 255.0),
+                ("Green", 0.0, 255.0),
+                ("Blue", 0.0, 255.0),
+                UNIT_ALPHA,
+            ],
+            ColorSpace::HSI => &[HUE, ("Saturation", 0.0, 1.0), ("Intensity", 0.0, 1.0)],
+            ColorSpace::HSL => &[HUE, ("Saturation", 0.0, 1.0), ("Lightness", 0.0, 1.0)],
+            ColorSpace::HSLA => &[
+                HUE,
+                ("Saturation", 0.0, 1.0),
+                ("Lightness", 0.0, 1.0),
+                UNIT_ALPHA,
+            ],
+            ColorSpace::HSV => &[HUE, ("Saturation", 0.0, 1.0), ("Value", 0.0, 1.0)],
+            ColorSpace::HWB => &[HUE, ("Whiteness", 0.0, 1.0), ("Blackness", 0.0, 1.0)],
+            ColorSpace::CMYK => &[
+                ("Cyan", 0.0, 1.0),
+                ("Magenta", 0.0, 1.0),
+                ("Yellow", 0.0, 1.0),
+                ("Black", 0.0, 1.0),
+            ],
+            // values above the white point are kept, they are outside of the sRGB gamut
+            ColorSpace::XYZ => &[("X", 0.0, INF), ("Y", 0.0, INF), ("Z", 0.0, INF)],
+            ColorSpace::YIQ => &[
+                ("Y", 0.0, 1.0),
+                ("I", -0.5957, 0.5957),
+                ("Q", -0.5226, 0.5226),
+            ],
+            ColorSpace::YUV => &[("Y", 0.0, 1.0), ("U", -0.436, 0.436), ("V", -0.615, 0.615)],
+            ColorSpace::YCbCr => &[("Y", 0.0, 255.0), ("Cb", 0.0, 255.0), ("Cr", 0.0, 255.0)],
+            // a and b are unbounded, wide gamut colors go beyond the usual ±128
+            ColorSpace::Lab => &[("L", 0.0, 100.0), ("A", -INF, INF), ("B", -INF, INF)],
+            ColorSpace::Lch => &[("L", 0.0, 100.0), ("C", 0.0, INF), HUE],
+            ColorSpace::Oklab => &[("L", 0.0, 1.0), ("A", -INF, INF), ("B", -INF, INF)],
+            ColorSpace::Oklch => &[("L", 0.0, 1.0), ("C", 0.0, INF), HUE],
+            #[cfg(feature = "std")]
+            ColorSpace::Custom(space) => {
+                let name = space.name()?;
+                return Ok(space
+                    .channel_ranges()?
+                    .iter()
+                    .enumerate()
+                    .map(|(i, range)| {
+                        let channel = format!("Channel {} of {}", i + 1, name);
+                        (channel, *range.start(), *range.end())
+                    })
+                    .collect());
+            }
+            ColorSpace::HEX | ColorSpace::HEXA => return Err(Error::InvalidParamsError(
                 "HEX color space not implemented yet, please use `ColorSpace::valid_hex` instead"
-                    .to_string(),
-            ),
-            ColorSpace::Unknown => todo!("Unknown color space validation"),
+                    .into(),
+            )),
+            ColorSpace::Unknown => {
+                return Err(Error::InvalidParamsError(
+                    "Unknown color space has no channels to validate".into(),
+                ))
+            }
         };
-        match result {
-            Some(info) => Err(Error::ColorParserError(info)),
-            None => Ok(()),
-        }
+        Ok(channels
+            .iter()
+            .map(|&(name, min, max)| (name.to_string(), min, max))
+            .collect())
     }
-    /// Validate a hex color string
-    pub(crate) fn valid_hex(hex: &str) -> Result<(), Error> {
-        if !hex.chars().skip(1).all(|c| c.is_ascii_hexdigit()) {
-            Err(Error::ColorParserError(
-                "Hex color string must be a valid hex string".to_string(),
-            ))
-        } else if hex.len() != 4 && hex.len() != 5 && hex.len() != 7 && hex.len() != 9 {
-            Err(Error::ColorParserError(
-                "Hex color must be 3, 4, 6, or 8 characters long".to_string(),
-            ))
-        } else {
-            Ok(())
+    /// The function name of the color space in color strings.
+    pub(crate) fn function_name(&self) -> String {
+        match self {
+            #[cfg(feature = "std")]
+            ColorSpace::Custom(space) => space.name().unwrap_or_else(|_| format!("{:?}", space)),
+            ColorSpace::YCbCr => "YCbCr".to_string(),
+            _ => format!("{:?}", self).to_lowercase(),
         }
     }
-}
-
-fn valid_yiq(vec: &[f64]) -> Option<String> {
-    if let [y, i, q] = vec[..] {
-        if !(0.0..=1.0).contains(&y) {
-            Some(format!("Y must be between 0.0 and 1.0, got {}", y))
-        } else if !(-0.5957..=0.5957).contains(&i) {
-            Some(format!("I must be between -0.5957 and 0.5957, got {}", i))
-        } else if !(-0.5226..=0.5226).contains(&q) {
-            Some(format!("Q must be between -0.5226 and 0.5226, got {}", q))
-        } else {
-            None
-        }
-    } else {
-        Some("YIQ color space requires 3 values".to_string())
-    }
-}
-
-fn valid_xyz(vec: &[f64]) -> Option<String> {
-    if let [x, y, z] = vec[..] {
-        if !(0.0..=0.950456).contains(&x) {
-            Some(format!("X must be between 0.0 and 0.950456, got {}", x))
-        } else if !(0.0..=1.0).contains(&y) {
-            Some(format!("Y must be between 0.0 and 1.0, got {}", y))
-        } else if !(0.0..=1.088754).contains(&z) {
-            Some(format!("Z must be between 0.0 and 1.088754, got {}", z))
+    /// Validate an alpha value.
+    pub(crate) fn valid_alpha(alpha: f64, span: Span) -> Result<(), Error> {
+        let (channel, min, max) = UNIT_ALPHA;
+        if alpha.is_finite() && (min..=max).contains(&alpha) {
+            Ok(())
         } else {
-            None
+            Err(Error::ChannelOutOfRange {
+                channel: channel.into(),
+                value: alpha,
+                min,
+                max,
+                span,
+            })
         }
-    } else {
-        Some("XYZ color space requires 3 values".to_string())
     }
-}
-
-fn valid_cmyk(vec: &[f64]) -> Option<String> {
-    if let [c, m, y, k] = vec[..] {
-        if !(0.0..=1.0).contains(&c) {
-            Some(format!("cyan color must be between 0.0 and 1.0, got {}", c))
-        } else if !(0.0..=1.0).contains(&m) {
-            Some(format!(
-                "magenta color must be between 0.0 and 1.0, got {}",
-                m
-            ))
-        } else if !(0.0..=1.0).contains(&y) {
-            Some(format!(
-                "yellow color must be between 0.0 and 1.0, got {}",
-                y
-            ))
-        } else if !(0.0..=1.0).contains(&k) {
-            Some(format!(
-                "black color must be between 0.0 and 1.0, got {}",
-                k
+    /// Validate a hex color string
+    pub(crate) fn valid_hex(hex: &str) -> Result<(), Error> {
+        if !hex.starts_with('#') || !hex.chars().skip(1).all(|c| c.is_ascii_hexdigit()) {
+            Err(Error::ColorParserError(
+                "Hex color string must be a valid hex string".into(),
             ))
+        } else if ![4, 5, 7, 9, 10, 13].contains(&hex.len()) {
+            Err(Error::BadHexLength {
+                length: hex.len() - 1,
+                span: Span::new(0, hex.len()),
+            })
         } else {
-            None
-        }
-    } else {
-        Some("CMYK color space requires 4 values".to_string())
-    }
-}
-
-fn valid_hwb(vec: &[f64]) -> Option<String> {
-    if let [h, w, b] = vec[..] {
-        if !(0.0..=360.0).contains(&h) {
-            Some(format!("Hue must be between 0.0 and 360.0, got {}", h))
-        } else if !(0.0..=1.0).contains(&w) {
-            Some(format!("Whiteness must be between 0.0 and 1.0, got {}", w))
-        } else if !(0.0..=1.0).contains(&b) {
-            Some(format!("Blackness must be between 0.0 and 1.0, got {}", b))
-        } else {
-            None
-        }
-    } else {
-        Some("HWB color space requires 3 values".to_string())
-    }
-}
-
-fn valid_rgb(vec: &[f64]) -> Option<String> {
-    if let [r, g, b] = vec[..] {
-        if !(0.0..=255.0).contains(&r) {
-            Some(format!("Red must be between 0 and 255, got {}", r))
-        } else if !(0.0..=255.0).contains(&g) {
-            Some(format!("Green must be between 0 and 255, got {}", g))
-        } else if !(0.0..=255.0).contains(&b) {
-            Some(format!("Blue must be between 0 and 255, got {}", b))
-        } else {
-            None
-        }
-    } else {
-        Some("RGB color space requires 3 values".to_string())
-    }
-}
-
-fn valid_rgba(vec: &[f64]) -> Option<String> {
-    if let [r, g, b, a] = vec[..] {
-        if !(0.0..=255.0).contains(&r) {
-            Some(format!("Red must be between 0 and 255, got {}", r))
-        } else if !(0.0..=255.0).contains(&g) {
-            Some(format!("Green must be between 0 and 255, got {}", g))
-        } else if !(0.0..=255.0).contains(&b) {
-            Some(format!("Blue must be between 0 and 255, got {}", b))
-        } else if !(0.0..=1.0).contains(&a) {
-            Some(format!("Alpha must be between 0.0 and 1.0, got {}", a))
-        } else {
-            None
-        }
-    } else {
-        Some("RGBA color space requires 4 values".to_string())
-    }
-}
-
-fn valid_hsi(vec: &[f64]) -> Option<String> {
-    if let [h, s, i] = vec[..] {
-        if !(0.0..=360.0).contains(&h) {
-            Some(format!("Hue must be between 0.0 and 360.0, got {}", h))
-        } else if !(0.0..=1.0).contains(&s) {
-            Some(format!("Saturation must be between 0.0 and 1.0, got {}", s))
-        } else if !(0.0..=1.0).contains(&i) {
-            Some(format!("Intensity must be between 0.0 and 1.0, got {}", i))
-        } else {
-            None
-        }
-    } else {
-        Some("HSI color space requires 3 values".to_string())
-    }
-}
-
-fn valid_hsl(vec: &[f64]) -> Option<String> {
-    if let [h, s, l] = vec[..] {
-        if !(0.0..=360.0).contains(&h) {
-            Some(format!("Hue must be between 0.0 and 360.0, got {}", h))
-        } else if !(0.0..=1.0).contains(&s) {
-            Some(format!("Saturation must be between 0.0 and 1.0, got {}", s))
-        } else if !(0.0..=1.0).contains(&l) {
-            Some(format!("Lightness must be between 0.0 and 1.0, got {}", l))
-        } else {
-            None
-        }
-    } else {
-        Some("HSL color space requires 3 values".to_string())
-    }
-}
-
-fn valid_hsla(vec: &[f64]) -> Option<String> {
-    if let [h, s, l, a] = vec[..] {
-        if !(0.0..=360.0).contains(&h) {
-            Some(format!("Hue must be between 0.0 and 360.0, got {}", h))
-        } else if !(0.0..=1.0).contains(&s) {
-            Some(format!("Saturation must be between 0.0 and 1.0, got {}", s))
-        } else if !(0.0..=1.0).contains(&l) {
-            Some(format!("Lightness must be between 0.0 and 1.0, got {}", l))
-        } else if !(0.0..=1.0).contains(&a) {
-            Some(format!("Alpha must be between 0.0 and 1.0, got {}", a))
-        } else {
-            None
-        }
-    } else {
-        Some("HSLA color space requires 4 values".to_string())
-    }
-}
-
-fn valid_hsv(vec: &[f64]) -> Option<String> {
-    if let [h, s, v] = vec[..] {
-        if !(0.0..=360.0).contains(&h) {
-            Some(format!("Hue must be between 0.0 and 360.0, got {}", h))
-        } else if !(0.0..=1.0).contains(&s) {
-            Some(format!("Saturation must be between 0.0 and 1.0, got {}", s))
-        } else if !(0.0..=1.0).contains(&v) {
-            Some(format!("Value must be between 0.0 and 1.0, got {}", v))
-        } else {
-            None
+            Ok(())
         }
-    } else {
-        Some("HSV color space requires 3 values".to_string())
     }
 }
 
-fn valid_lab(vec: &[f64]) -> Option<String> {
-    if let [l, a, b] = vec[..] {
-        if !(0.0..=100.0).contains(&l) {
-            Some(format!("L must be between 0.0 and 100.0, got {}", l))
-        } else if !(-128.0..=127.0).contains(&a) {
-            Some(format!("A must be between -128.0 and 127.0, got {}", a))
-        } else if !(-128.0..=127.0).contains(&b) {
-            Some(format!("B must be between -128.0 and 127.0, got {}", b))
+impl PredefinedSpace {
+    /// Check if a vector of values is valid for the `color()` function, see
+    /// [`ColorSpace::valid_spanned`].
+    ///
+    /// The values are not bounded, wide gamut colors go beyond `0..=1`.
+    pub(crate) fn valid_spanned(
+        &self,
+        vec: &[f64],
+        spans: &[Span],
+        span: Span,
+    ) -> Result<(), Error> {
+        let names = if self.is_xyz() {
+            ["X", "Y", "Z"]
         } else {
-            None
-        }
-    } else {
-        Some("Lab color space requires 3 values".to_string())
+            ["Red", "Green", "Blue"]
+        };
+        let channels = names
+            .iter()
+            .map(|name| (name.to_string(), -INF, INF))
+            .collect();
+        valid_channels(
+            format!("color({})", self.name()),
+            channels,
+            vec,
+            spans,
+            span,
+        )
     }
 }
 
-fn valid_yuv(vec: &[f64]) -> Option<String> {
-    if let [y, u, v] = vec[..] {
-        if !(0.0..=1.0).contains(&y) {
-            Some(format!("Y must be between 0.0 and 1.0, got {}", y))
-        } else if !(-0.436..=0.436).contains(&u) {
-            Some(format!("U must be between -0.436 and 0.436, got {}", u))
-        } else if !(-0.615..=0.615).contains(&v) {
-            Some(format!("V must be between -0.615 and 0.615, got {}", v))
-        } else {
-            None
-        }
-    } else {
-        Some("YUV color space requires 3 values".to_string())
+/// Checks the number of values and that each value is inside of the range of its channel.
+///
+/// An out of range value points at its entry of `spans`, a wrong number of values at `span`.
+fn valid_channels(
+    function: String,
+    channels: Vec<(String, f64, f64)>,
+    vec: &[f64],
+    spans: &[Span],
+    span: Span,
+) -> Result<(), Error> {
+    if vec.len() != channels.len() {
+        return Err(Error::WrongArity {
+            function: function.into(),
+            expected: channels.len(),
+            found: vec.len(),
+            span,
+        });
     }
-}
-
-fn valid_ycbcr(vec: &[f64]) -> Option<String> {
-    if let [y, cb, cr] = vec[..] {
-        if !(0.0..=255.0).contains(&y) {
-            Some(format!("Y must be between 0.0 and 255.0, got {}", y))
-        } else if !(0.0..=255.0).contains(&cb) {
-            Some(format!("Cb must be between 0.0 and 255.0, got {}", cb))
-        } else if !(0.0..=255.0).contains(&cr) {
-            Some(format!("Cr must be between 0.0 and 255.0, got {}", cr))
-        } else {
-            None
-        }
-    } else {
-        Some("YCbCr color space requires 3 values".to_string())
+    match channels
+        .into_iter()
+        .zip(vec.iter())
+        .enumerate()
+        .find(|(_, ((_, min, max), v))| !(v.is_finite() && (*min..=*max).contains(*v)))
+    {
+        Some((i, ((channel, min, max), &value))) => Err(Error::ChannelOutOfRange {
+            channel: channel.into(),
+            value,
+            min,
+            max,
+            span: spans.get(i).copied().unwrap_or(span),
+        }),
+        None => Ok(()),
     }
 }
 
@@ -273,23 +211,53 @@ mod tests {
 
     #[test]
     fn test_valid_yuv() {
-        let yuv = [0.0, 0.0, 0.0, 0.0];
-        let result = valid_yuv(&yuv);
+        let result = ColorSpace::YUV.valid(&[0.0, 0.0, 0.0, 0.0]);
         assert_eq!(
-            result,
-            Some("YUV color space requires 3 values".to_string())
+            result.unwrap_err().to_string(),
+            "yuv requires 3 values, got 4 at 0..0"
         );
 
-        let yuv = [0.0, 10.0, 0.0];
-        let result = valid_yuv(&yuv);
+        let result = ColorSpace::YUV.valid(&[0.0, 10.0, 0.0]);
         assert_eq!(
             result,
-            Some("U must be between -0.436 and 0.436, got 10".to_string())
+            Err(Error::ChannelOutOfRange {
+                channel: "U".into(),
+                value: 10.0,
+                min: -0.436,
+                max: 0.436,
+                span: Span::default(),
+            })
+        );
+
+        assert!(ColorSpace::YUV.valid(&[0.0, 0.0, 0.0]).is_ok());
+    }
+
+    #[test]
+    fn test_valid_spanned() {
+        let spans = [Span::new(4, 7), Span::new(9, 12), Span::new(14, 17)];
+        let result = ColorSpace::RGB.valid_spanned(&[255.0, 256.0, 0.0], &spans, Span::new(0, 18));
+        assert_eq!(result.unwrap_err().span(), Some(Span::new(9, 12)));
+
+        let result = ColorSpace::Lab.valid(&[50.0, f64::NAN, 0.0]);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "A must be a finite number, got NaN at 0..0"
         );
+        assert!(ColorSpace::Lab.valid(&[50.0, 300.0, -300.0]).is_ok());
+        assert!(ColorSpace::XYZ.valid(&[2.0, 2.0, 2.0]).is_ok());
+        assert!(ColorSpace::XYZ.valid(&[-0.1, 0.0, 0.0]).is_err());
 
-        let yuv = [0.0, 0.0, 0.0];
-        let result = valid_yuv(&yuv);
-        assert_eq!(result, None);
+        assert!(ColorSpace::Unknown.valid(&[]).is_err());
+
+        let result = PredefinedSpace::DisplayP3.valid_spanned(&[1.2, -0.1], &[], Span::new(0, 26));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "color(display-p3) requires 3 values, got 2 at 0..26"
+        );
+        assert!(PredefinedSpace::XyzD50
+            .valid_spanned(&[1.2, -0.1, 0.0], &[], Span::default())
+            .is_ok());
+        assert!(ColorSpace::HEX.valid(&[0.0, 0.0, 0.0]).is_err());
     }
 
     #[test]
@@ -306,8 +274,17 @@ mod tests {
         let hex = "#ff3399ff";
         assert!(ColorSpace::valid_hex(hex).is_ok());
 
+        let hex = "#fff333999";
+        assert!(ColorSpace::valid_hex(hex).is_ok());
+
         let hex = "#ff333";
-        assert!(ColorSpace::valid_hex(hex).is_err());
+        assert_eq!(
+            ColorSpace::valid_hex(hex),
+            Err(Error::BadHexLength {
+                length: 5,
+                span: Span::new(0, 6)
+            })
+        );
 
         let hex = "#zzz";
         assert!(ColorSpace::valid_hex(hex).is_err());
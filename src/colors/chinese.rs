@@ -0,0 +1,1061 @@
+//! Chinese traditional colors, named by their pinyin.
+//!
+//! Names sharing the same pinyin get a numeric suffix in table order, e.g. `YOU_HUANG` (鼬黄)
+//! and `YOU_HUANG_2` (柚黄).
+//!
+//! reference: <https://github.com/jufjzq/Chinese-Traditional-Colors/blob/master/src/assets/colors.json>
+
+use crate::Color;
+
+/// 乳白, #f9f4dc
+pub const RU_BAI: Color = Color::new_const(249.0, 244.0, 220.0, 1.0);
+/// 杏仁黄, #f7e8aa
+pub const XING_REN_HUANG: Color = Color::new_const(247.0, 232.0, 170.0, 1.0);
+/// 茉莉黄, #f8df72
+pub const MO_LI_HUANG: Color = Color::new_const(248.0, 223.0, 114.0, 1.0);
+/// 麦秆黄, #f8df70
+pub const MAI_GAN_HUANG: Color = Color::new_const(248.0, 223.0, 112.0, 1.0);
+/// 油菜花黄, #fbda41
+pub const YOU_CAI_HUA_HUANG: Color = Color::new_const(251.0, 218.0, 65.0, 1.0);
+/// 佛手黄, #fed71a
+pub const FO_SHOU_HUANG: Color = Color::new_const(254.0, 215.0, 26.0, 1.0);
+/// 篾黄, #f7de98
+pub const MIE_HUANG: Color = Color::new_const(247.0, 222.0, 152.0, 1.0);
+/// 葵扇黄, #f8d86a
+pub const KUI_SHAN_HUANG: Color = Color::new_const(248.0, 216.0, 106.0, 1.0);
+/// 柠檬黄, #fcd337
+pub const NING_MENG_HUANG: Color = Color::new_const(252.0, 211.0, 55.0, 1.0);
+/// 金瓜黄, #fcd217
+pub const JIN_GUA_HUANG: Color = Color::new_const(252.0, 210.0, 23.0, 1.0);
+/// 藤黄, #ffd111
+pub const TENG_HUANG: Color = Color::new_const(255.0, 209.0, 17.0, 1.0);
+/// 酪黄, #f6dead
+pub const LAO_HUANG: Color = Color::new_const(246.0, 222.0, 173.0, 1.0);
+/// 香水玫瑰黄, #f7da94
+pub const XIANG_SHUI_MEI_GUI_HUANG: Color = Color::new_const(247.0, 218.0, 148.0, 1.0);
+/// 淡密黄, #f9d367
+pub const DAN_MI_HUANG: Color = Color::new_const(249.0, 211.0, 103.0, 1.0);
+/// 大豆黄, #fbcd31
+pub const DA_DOU_HUANG: Color = Color::new_const(251.0, 205.0, 49.0, 1.0);
+/// 素馨黄, #fccb16
+pub const SU_XIN_HUANG: Color = Color::new_const(252.0, 203.0, 22.0, 1.0);
+/// 向日葵黄, #fecc11
+pub const XIANG_RI_KUI_HUANG: Color = Color::new_const(254.0, 204.0, 17.0, 1.0);
+/// 雅梨黄, #fbc82f
+pub const YA_LI_HUANG: Color = Color::new_const(251.0, 200.0, 47.0, 1.0);
+/// 黄连黄, #fcc515
+pub const HUANG_LIAN_HUANG: Color = Color::new_const(252.0, 197.0, 21.0, 1.0);
+/// 金盏黄, #fcc307
+pub const JIN_ZHAN_HUANG: Color = Color::new_const(252.0, 195.0, 7.0, 1.0);
+/// 蛋壳黄, #f8c387
+pub const DAN_KE_HUANG: Color = Color::new_const(248.0, 195.0, 135.0, 1.0);
+/// 肉色, #f7c173
+pub const ROU_SE: Color = Color::new_const(247.0, 193.0, 115.0, 1.0);
+/// 鹅掌黄, #fbb929
+pub const E_ZHANG_HUANG: Color = Color::new_const(251.0, 185.0, 41.0, 1.0);
+/// 鸡蛋黄, #fbb612
+pub const JI_DAN_HUANG: Color = Color::new_const(251.0, 182.0, 18.0, 1.0);
+/// 鼬黄, #fcb70a
+pub const YOU_HUANG: Color = Color::new_const(252.0, 183.0, 10.0, 1.0);
+/// 榴萼黄, #f9a633
+pub const LIU_E_HUANG: Color = Color::new_const(249.0, 166.0, 51.0, 1.0);
+/// 淡橘橙, #fba414
+pub const DAN_JU_CHENG: Color = Color::new_const(251.0, 164.0, 20.0, 1.0);
+/// 枇杷黄, #fca106
+pub const PI_PA_HUANG: Color = Color::new_const(252.0, 161.0, 6.0, 1.0);
+/// 橙皮黄, #fca104
+pub const CHENG_PI_HUANG: Color = Color::new_const(252.0, 161.0, 4.0, 1.0);
+/// 北瓜黄, #fc8c23
+pub const BEI_GUA_HUANG: Color = Color::new_const(252.0, 140.0, 35.0, 1.0);
+/// 杏黄, #f28e16
+pub const XING_HUANG: Color = Color::new_const(242.0, 142.0, 22.0, 1.0);
+/// 雄黄, #ff9900
+pub const XIONG_HUANG: Color = Color::new_const(255.0, 153.0, 0.0, 1.0);
+/// 万寿菊黄, #fb8b05
+pub const WAN_SHOU_JU_HUANG: Color = Color::new_const(251.0, 139.0, 5.0, 1.0);
+/// 菊蕾白, #e9ddb6
+pub const JU_LEI_BAI: Color = Color::new_const(233.0, 221.0, 182.0, 1.0);
+/// 秋葵黄, #eed045
+pub const QIU_KUI_HUANG: Color = Color::new_const(238.0, 208.0, 69.0, 1.0);
+/// 硫华黄, #f2ce2b
+pub const LIU_HUA_HUANG: Color = Color::new_const(242.0, 206.0, 43.0, 1.0);
+/// 柚黄, #f1ca17
+pub const YOU_HUANG_2: Color = Color::new_const(241.0, 202.0, 23.0, 1.0);
+/// 芒果黄, #ddc871
+pub const MANG_GUO_HUANG: Color = Color::new_const(221.0, 200.0, 113.0, 1.0);
+/// 蒿黄, #dfc243
+pub const HAO_HUANG: Color = Color::new_const(223.0, 194.0, 67.0, 1.0);
+/// 姜黄, #e2c027
+pub const JIANG_HUANG: Color = Color::new_const(226.0, 192.0, 39.0, 1.0);
+/// 香蕉黄, #e4bf11
+pub const XIANG_JIAO_HUANG: Color = Color::new_const(228.0, 191.0, 17.0, 1.0);
+/// 草黄, #d2b42c
+pub const CAO_HUANG: Color = Color::new_const(210.0, 180.0, 44.0, 1.0);
+/// 新禾绿, #d2b116
+pub const XIN_HE_LV: Color = Color::new_const(210.0, 177.0, 22.0, 1.0);
+/// 月灰, #b7ae8f
+pub const YUE_HUI: Color = Color::new_const(183.0, 174.0, 143.0, 1.0);
+/// 淡灰绿, #ad9e5f
+pub const DAN_HUI_LV: Color = Color::new_const(173.0, 158.0, 95.0, 1.0);
+/// 草灰绿, #8e804b
+pub const CAO_HUI_LV: Color = Color::new_const(142.0, 128.0, 75.0, 1.0);
+/// 苔绿, #887322
+pub const TAI_LV: Color = Color::new_const(136.0, 115.0, 34.0, 1.0);
+/// 碧螺春绿, #867018
+pub const BI_LUO_CHUN_LV: Color = Color::new_const(134.0, 112.0, 24.0, 1.0);
+/// 燕羽灰, #685e48
+pub const YAN_YU_HUI: Color = Color::new_const(104.0, 94.0, 72.0, 1.0);
+/// 蟹壳灰, #695e45
+pub const XIE_KE_HUI: Color = Color::new_const(105.0, 94.0, 69.0, 1.0);
+/// 潭水绿, #645822
+pub const TAN_SHUI_LV: Color = Color::new_const(100.0, 88.0, 34.0, 1.0);
+/// 橄榄绿, #5e5314
+pub const GAN_LAN_LV: Color = Color::new_const(94.0, 83.0, 20.0, 1.0);
+/// 蚌肉白, #f9f1db
+pub const BANG_ROU_BAI: Color = Color::new_const(249.0, 241.0, 219.0, 1.0);
+/// 豆汁黄, #f8e8c1
+pub const DOU_ZHI_HUANG: Color = Color::new_const(248.0, 232.0, 193.0, 1.0);
+/// 淡茧黄, #f9d770
+pub const DAN_JIAN_HUANG: Color = Color::new_const(249.0, 215.0, 112.0, 1.0);
+/// 乳鸭黄, #ffc90c
+pub const RU_YA_HUANG: Color = Color::new_const(255.0, 201.0, 12.0, 1.0);
+/// 荔肉白, #f2e6ce
+pub const LI_ROU_BAI: Color = Color::new_const(242.0, 230.0, 206.0, 1.0);
+/// 象牙黄, #f0d695
+pub const XIANG_YA_HUANG: Color = Color::new_const(240.0, 214.0, 149.0, 1.0);
+/// 炒米黄, #f4ce69
+pub const CHAO_MI_HUANG: Color = Color::new_const(244.0, 206.0, 105.0, 1.0);
+/// 鹦鹉冠黄, #f6c430
+pub const YING_WU_GUAN_HUANG: Color = Color::new_const(246.0, 196.0, 48.0, 1.0);
+/// 木瓜黄, #f9c116
+pub const MU_GUA_HUANG: Color = Color::new_const(249.0, 193.0, 22.0, 1.0);
+/// 浅烙黄, #f9bd10
+pub const QIAN_LAO_HUANG: Color = Color::new_const(249.0, 189.0, 16.0, 1.0);
+/// 莲子白, #e5d3aa
+pub const LIAN_ZI_BAI: Color = Color::new_const(229.0, 211.0, 170.0, 1.0);
+/// 谷黄, #e8b004
+pub const GU_HUANG: Color = Color::new_const(232.0, 176.0, 4.0, 1.0);
+/// 栀子黄, #ebb10d
+pub const ZHI_ZI_HUANG: Color = Color::new_const(235.0, 177.0, 13.0, 1.0);
+/// 芥黄, #d9a40e
+pub const JIE_HUANG: Color = Color::new_const(217.0, 164.0, 14.0, 1.0);
+/// 银鼠灰, #b5aa90
+pub const YIN_SHU_HUI: Color = Color::new_const(181.0, 170.0, 144.0, 1.0);
+/// 尘灰, #b6a476
+pub const CHEN_HUI: Color = Color::new_const(182.0, 164.0, 118.0, 1.0);
+/// 枯绿, #b78d12
+pub const KU_LV: Color = Color::new_const(183.0, 141.0, 18.0, 1.0);
+/// 鲛青, #87723e
+pub const JIAO_QING: Color = Color::new_const(135.0, 114.0, 62.0, 1.0);
+/// 粽叶绿, #876818
+pub const ZONG_YE_LV: Color = Color::new_const(135.0, 104.0, 24.0, 1.0);
+/// 灰绿, #8a6913
+pub const HUI_LV: Color = Color::new_const(138.0, 105.0, 19.0, 1.0);
+/// 鹤灰, #4a4035
+pub const HE_HUI: Color = Color::new_const(74.0, 64.0, 53.0, 1.0);
+/// 淡松烟, #4d4030
+pub const DAN_SONG_YAN: Color = Color::new_const(77.0, 64.0, 48.0, 1.0);
+/// 暗海水绿, #584717
+pub const AN_HAI_SHUI_LV: Color = Color::new_const(88.0, 71.0, 23.0, 1.0);
+/// 棕榈绿, #5b4913
+pub const ZONG_LV_LV: Color = Color::new_const(91.0, 73.0, 19.0, 1.0);
+/// 米色, #f9e9cd
+pub const MI_SE: Color = Color::new_const(249.0, 233.0, 205.0, 1.0);
+/// 淡肉色, #f8e0b0
+pub const DAN_ROU_SE: Color = Color::new_const(248.0, 224.0, 176.0, 1.0);
+/// 麦芽糖黄, #f9d27d
+pub const MAI_YA_TANG_HUANG: Color = Color::new_const(249.0, 210.0, 125.0, 1.0);
+/// 琥珀黄, #feba07
+pub const HU_PO_HUANG: Color = Color::new_const(254.0, 186.0, 7.0, 1.0);
+/// 甘草黄, #f3bf4c
+pub const GAN_CAO_HUANG: Color = Color::new_const(243.0, 191.0, 76.0, 1.0);
+/// 初熟杏黄, #f8bc31
+pub const CHU_SHU_XING_HUANG: Color = Color::new_const(248.0, 188.0, 49.0, 1.0);
+/// 浅驼色, #e2c17c
+pub const QIAN_TUO_SE: Color = Color::new_const(226.0, 193.0, 124.0, 1.0);
+/// 沙石黄, #e5b751
+pub const SHA_SHI_HUANG: Color = Color::new_const(229.0, 183.0, 81.0, 1.0);
+/// 虎皮黄, #eaad1a
+pub const HU_PI_HUANG: Color = Color::new_const(234.0, 173.0, 26.0, 1.0);
+/// 土黄, #d6a01d
+pub const TU_HUANG: Color = Color::new_const(214.0, 160.0, 29.0, 1.0);
+/// 百灵鸟灰, #b4a992
+pub const BAI_LING_NIAO_HUI: Color = Color::new_const(180.0, 169.0, 146.0, 1.0);
+/// 山鸡黄, #b78b26
+pub const SHAN_JI_HUANG: Color = Color::new_const(183.0, 139.0, 38.0, 1.0);
+/// 龟背黄, #826b48
+pub const GUI_BEI_HUANG: Color = Color::new_const(130.0, 107.0, 72.0, 1.0);
+/// 苍黄, #806332
+pub const CANG_HUANG: Color = Color::new_const(128.0, 99.0, 50.0, 1.0);
+/// 莱阳梨黄, #815f25
+pub const LAI_YANG_LI_HUANG: Color = Color::new_const(129.0, 95.0, 37.0, 1.0);
+/// 蜴蜊绿, #835e1d
+pub const YI_LI_LV: Color = Color::new_const(131.0, 94.0, 29.0, 1.0);
+/// 松鼠灰, #4f4032
+pub const SONG_SHU_HUI: Color = Color::new_const(79.0, 64.0, 50.0, 1.0);
+/// 橄榄灰, #503e2a
+pub const GAN_LAN_HUI: Color = Color::new_const(80.0, 62.0, 42.0, 1.0);
+/// 蟹壳绿, #513c20
+pub const XIE_KE_LV: Color = Color::new_const(81.0, 60.0, 32.0, 1.0);
+/// 古铜绿, #533c1b
+pub const GU_TONG_LV: Color = Color::new_const(83.0, 60.0, 27.0, 1.0);
+/// 焦茶绿, #553b18
+pub const JIAO_CHA_LV: Color = Color::new_const(85.0, 59.0, 24.0, 1.0);
+/// 粉白, #fbf2e3
+pub const FEN_BAI: Color = Color::new_const(251.0, 242.0, 227.0, 1.0);
+/// 落英淡粉, #f9e8d0
+pub const LUO_YING_DAN_FEN: Color = Color::new_const(249.0, 232.0, 208.0, 1.0);
+/// 瓜瓤粉, #f9cb8b
+pub const GUA_RANG_FEN: Color = Color::new_const(249.0, 203.0, 139.0, 1.0);
+/// 蜜黄, #fbb957
+pub const MI_HUANG: Color = Color::new_const(251.0, 185.0, 87.0, 1.0);
+/// 金叶黄, #ffa60f
+pub const JIN_YE_HUANG: Color = Color::new_const(255.0, 166.0, 15.0, 1.0);
+/// 金莺黄, #f4a83a
+pub const JIN_YING_HUANG: Color = Color::new_const(244.0, 168.0, 58.0, 1.0);
+/// 鹿角棕, #e3bd8d
+pub const LU_JIAO_ZONG: Color = Color::new_const(227.0, 189.0, 141.0, 1.0);
+/// 凋叶棕, #e7a23f
+pub const DIAO_YE_ZONG: Color = Color::new_const(231.0, 162.0, 63.0, 1.0);
+/// 玳瑁黄, #daa45a
+pub const DAI_MAO_HUANG: Color = Color::new_const(218.0, 164.0, 90.0, 1.0);
+/// 软木黄, #de9e44
+pub const RUAN_MU_HUANG: Color = Color::new_const(222.0, 158.0, 68.0, 1.0);
+/// 风帆黄, #dc9123
+pub const FENG_FAN_HUANG: Color = Color::new_const(220.0, 145.0, 35.0, 1.0);
+/// 桂皮淡棕, #c09351
+pub const GUI_PI_DAN_ZONG: Color = Color::new_const(192.0, 147.0, 81.0, 1.0);
+/// 猴毛灰, #97846c
+pub const HOU_MAO_HUI: Color = Color::new_const(151.0, 132.0, 108.0, 1.0);
+/// 山鸡褐, #986524
+pub const SHAN_JI_HE: Color = Color::new_const(152.0, 101.0, 36.0, 1.0);
+/// 驼色, #66462a
+pub const TUO_SE: Color = Color::new_const(102.0, 70.0, 42.0, 1.0);
+/// 茶褐, #5d3d21
+pub const CHA_HE: Color = Color::new_const(93.0, 61.0, 33.0, 1.0);
+/// 古铜褐, #5c3719
+pub const GU_TONG_HE: Color = Color::new_const(92.0, 55.0, 25.0, 1.0);
+/// 荷花白, #fbecde
+pub const HE_HUA_BAI: Color = Color::new_const(251.0, 236.0, 222.0, 1.0);
+/// 玫瑰粉, #f8b37f
+pub const MEI_GUI_FEN: Color = Color::new_const(248.0, 179.0, 127.0, 1.0);
+/// 橘橙, #f97d1c
+pub const JU_CHENG: Color = Color::new_const(249.0, 125.0, 28.0, 1.0);
+/// 美人焦橙, #fa7e23
+pub const MEI_REN_JIAO_CHENG: Color = Color::new_const(250.0, 126.0, 35.0, 1.0);
+/// 润红, #f7cdbc
+pub const RUN_HONG: Color = Color::new_const(247.0, 205.0, 188.0, 1.0);
+/// 淡桃红, #f6cec1
+pub const DAN_TAO_HONG: Color = Color::new_const(246.0, 206.0, 193.0, 1.0);
+/// 海螺橙, #f0945d
+pub const HAI_LUO_CHENG: Color = Color::new_const(240.0, 148.0, 93.0, 1.0);
+/// 桃红, #f0ada0
+pub const TAO_HONG: Color = Color::new_const(240.0, 173.0, 160.0, 1.0);
+/// 颊红, #eeaa9c
+pub const JIA_HONG: Color = Color::new_const(238.0, 170.0, 156.0, 1.0);
+/// 淡罂粟红, #eea08c
+pub const DAN_YING_SU_HONG: Color = Color::new_const(238.0, 160.0, 140.0, 1.0);
+/// 晨曦红, #ea8958
+pub const CHEN_XI_HONG: Color = Color::new_const(234.0, 137.0, 88.0, 1.0);
+/// 蟹壳红, #f27635
+pub const XIE_KE_HONG: Color = Color::new_const(242.0, 118.0, 53.0, 1.0);
+/// 金莲花橙, #f86b1d
+pub const JIN_LIAN_HUA_CHENG: Color = Color::new_const(248.0, 107.0, 29.0, 1.0);
+/// 草莓红, #ef6f48
+pub const CAO_MEI_HONG: Color = Color::new_const(239.0, 111.0, 72.0, 1.0);
+/// 龙睛鱼红, #ef632b
+pub const LONG_JING_YU_HONG: Color = Color::new_const(239.0, 99.0, 43.0, 1.0);
+/// 蜻蜓红, #f1441d
+pub const QING_TING_HONG: Color = Color::new_const(241.0, 68.0, 29.0, 1.0);
+/// 大红, #f04b22
+pub const DA_HONG: Color = Color::new_const(240.0, 75.0, 34.0, 1.0);
+/// 柿红, #f2481b
+pub const SHI_HONG: Color = Color::new_const(242.0, 72.0, 27.0, 1.0);
+/// 榴花红, #f34718
+pub const LIU_HUA_HONG: Color = Color::new_const(243.0, 71.0, 24.0, 1.0);
+/// 银朱, #f43e06
+pub const YIN_ZHU: Color = Color::new_const(244.0, 62.0, 6.0, 1.0);
+/// 朱红, #ed5126
+pub const ZHU_HONG: Color = Color::new_const(237.0, 81.0, 38.0, 1.0);
+/// 鲑鱼红, #f09c5a
+pub const GUI_YU_HONG: Color = Color::new_const(240.0, 156.0, 90.0, 1.0);
+/// 金黄, #f26b1f
+pub const JIN_HUANG: Color = Color::new_const(242.0, 107.0, 31.0, 1.0);
+/// 鹿皮褐, #d99156
+pub const LU_PI_HE: Color = Color::new_const(217.0, 145.0, 86.0, 1.0);
+/// 醉瓜肉, #db8540
+pub const ZUI_GUA_ROU: Color = Color::new_const(219.0, 133.0, 64.0, 1.0);
+/// 麂棕, #de7622
+pub const JI_ZONG: Color = Color::new_const(222.0, 118.0, 34.0, 1.0);
+/// 淡银灰, #c1b2a3
+pub const DAN_YIN_HUI: Color = Color::new_const(193.0, 178.0, 163.0, 1.0);
+/// 淡赭, #be7e4a
+pub const DAN_ZHE: Color = Color::new_const(190.0, 126.0, 74.0, 1.0);
+/// 槟榔综, #c1651a
+pub const BIN_LANG_ZONG: Color = Color::new_const(193.0, 101.0, 26.0, 1.0);
+/// 银灰, #918072
+pub const YIN_HUI: Color = Color::new_const(145.0, 128.0, 114.0, 1.0);
+/// 海鸥灰, #9a8878
+pub const HAI_OU_HUI: Color = Color::new_const(154.0, 136.0, 120.0, 1.0);
+/// 淡咖啡, #945833
+pub const DAN_KA_FEI: Color = Color::new_const(148.0, 88.0, 51.0, 1.0);
+/// 岩石棕, #964d22
+pub const YAN_SHI_ZONG: Color = Color::new_const(150.0, 77.0, 34.0, 1.0);
+/// 芒果棕, #954416
+pub const MANG_GUO_ZONG: Color = Color::new_const(149.0, 68.0, 22.0, 1.0);
+/// 石板灰, #624941
+pub const SHI_BAN_HUI: Color = Color::new_const(98.0, 73.0, 65.0, 1.0);
+/// 珠母灰, #64483d
+pub const ZHU_MU_HUI: Color = Color::new_const(100.0, 72.0, 61.0, 1.0);
+/// 丁香棕, #71361d
+pub const DING_XIANG_ZONG: Color = Color::new_const(113.0, 54.0, 29.0, 1.0);
+/// 咖啡, #753117
+pub const KA_FEI: Color = Color::new_const(117.0, 49.0, 23.0, 1.0);
+/// 筍皮棕, #732e12
+pub const SUN_PI_ZONG: Color = Color::new_const(115.0, 46.0, 18.0, 1.0);
+/// 燕颔红, #fc6315
+pub const YAN_HAN_HONG: Color = Color::new_const(252.0, 99.0, 21.0, 1.0);
+/// 玉粉红, #e8b49a
+pub const YU_FEN_HONG: Color = Color::new_const(232.0, 180.0, 154.0, 1.0);
+/// 金驼, #e46828
+pub const JIN_TUO: Color = Color::new_const(228.0, 104.0, 40.0, 1.0);
+/// 铁棕, #d85916
+pub const TIE_ZONG: Color = Color::new_const(216.0, 89.0, 22.0, 1.0);
+/// 蛛网灰, #b7a091
+pub const ZHU_WANG_HUI: Color = Color::new_const(183.0, 160.0, 145.0, 1.0);
+/// 淡可可棕, #b7511d
+pub const DAN_KE_KE_ZONG: Color = Color::new_const(183.0, 81.0, 29.0, 1.0);
+/// 中红灰, #8b614d
+pub const ZHONG_HONG_HUI: Color = Color::new_const(139.0, 97.0, 77.0, 1.0);
+/// 淡土黄, #8c4b31
+pub const DAN_TU_HUANG: Color = Color::new_const(140.0, 75.0, 49.0, 1.0);
+/// 淡豆沙, #873d24
+pub const DAN_DOU_SHA: Color = Color::new_const(135.0, 61.0, 36.0, 1.0);
+/// 椰壳棕, #883a1e
+pub const YE_KE_ZONG: Color = Color::new_const(136.0, 58.0, 30.0, 1.0);
+/// 淡铁灰, #5b423a
+pub const DAN_TIE_HUI: Color = Color::new_const(91.0, 66.0, 58.0, 1.0);
+/// 中灰驼, #603d30
+pub const ZHONG_HUI_TUO: Color = Color::new_const(96.0, 61.0, 48.0, 1.0);
+/// 淡栗棕, #673424
+pub const DAN_LI_ZONG: Color = Color::new_const(103.0, 52.0, 36.0, 1.0);
+/// 可可棕, #652b1c
+pub const KE_KE_ZONG: Color = Color::new_const(101.0, 43.0, 28.0, 1.0);
+/// 柞叶棕, #692a1b
+pub const ZUO_YE_ZONG: Color = Color::new_const(105.0, 42.0, 27.0, 1.0);
+/// 野蔷薇红, #fb9968
+pub const YE_QIANG_WEI_HONG: Color = Color::new_const(251.0, 153.0, 104.0, 1.0);
+/// 菠萝红, #fc7930
+pub const BO_LUO_HONG: Color = Color::new_const(252.0, 121.0, 48.0, 1.0);
+/// 藕荷, #edc3ae
+pub const OU_HE: Color = Color::new_const(237.0, 195.0, 174.0, 1.0);
+/// 陶瓷红, #e16723
+pub const TAO_CI_HONG: Color = Color::new_const(225.0, 103.0, 35.0, 1.0);
+/// 晓灰, #d4c4b7
+pub const XIAO_HUI: Color = Color::new_const(212.0, 196.0, 183.0, 1.0);
+/// 余烬红, #cf7543
+pub const YU_JIN_HONG: Color = Color::new_const(207.0, 117.0, 67.0, 1.0);
+/// 火砖红, #cd6227
+pub const HUO_ZHUAN_HONG: Color = Color::new_const(205.0, 98.0, 39.0, 1.0);
+/// 火泥棕, #aa6a4c
+pub const HUO_NI_ZONG: Color = Color::new_const(170.0, 106.0, 76.0, 1.0);
+/// 绀红, #a6522c
+pub const GAN_HONG: Color = Color::new_const(166.0, 82.0, 44.0, 1.0);
+/// 橡树棕, #773d31
+pub const XIANG_SHU_ZONG: Color = Color::new_const(119.0, 61.0, 49.0, 1.0);
+/// 海报灰, #483332
+pub const HAI_BAO_HUI: Color = Color::new_const(72.0, 51.0, 50.0, 1.0);
+/// 玫瑰灰, #4b2e2b
+pub const MEI_GUI_HUI: Color = Color::new_const(75.0, 46.0, 43.0, 1.0);
+/// 火山棕, #482522
+pub const HUO_SHAN_ZONG: Color = Color::new_const(72.0, 37.0, 34.0, 1.0);
+/// 豆沙, #481e1c
+pub const DOU_SHA: Color = Color::new_const(72.0, 30.0, 28.0, 1.0);
+/// 淡米粉, #fbeee2
+pub const DAN_MI_FEN: Color = Color::new_const(251.0, 238.0, 226.0, 1.0);
+/// 初桃粉红, #f6dcce
+pub const CHU_TAO_FEN_HONG: Color = Color::new_const(246.0, 220.0, 206.0, 1.0);
+/// 介壳淡粉红, #f7cfba
+pub const JIE_KE_DAN_FEN_HONG: Color = Color::new_const(247.0, 207.0, 186.0, 1.0);
+/// 淡藏花红, #f6ad8f
+pub const DAN_ZANG_HUA_HONG: Color = Color::new_const(246.0, 173.0, 143.0, 1.0);
+/// 瓜瓤红, #f68c60
+pub const GUA_RANG_HONG: Color = Color::new_const(246.0, 140.0, 96.0, 1.0);
+/// 芙蓉红, #f9723d
+pub const FU_RONG_HONG: Color = Color::new_const(249.0, 114.0, 61.0, 1.0);
+/// 莓酱红, #fa5d19
+pub const MEI_JIANG_HONG: Color = Color::new_const(250.0, 93.0, 25.0, 1.0);
+/// 法螺红, #ee8055
+pub const FA_LUO_HONG: Color = Color::new_const(238.0, 128.0, 85.0, 1.0);
+/// 落霞红, #cf4813
+pub const LUO_XIA_HONG: Color = Color::new_const(207.0, 72.0, 19.0, 1.0);
+/// 淡玫瑰灰, #b89485
+pub const DAN_MEI_GUI_HUI: Color = Color::new_const(184.0, 148.0, 133.0, 1.0);
+/// 蟹蝥红, #b14b28
+pub const XIE_MAO_HONG: Color = Color::new_const(177.0, 75.0, 40.0, 1.0);
+/// 火岩棕, #863020
+pub const HUO_YAN_ZONG: Color = Color::new_const(134.0, 48.0, 32.0, 1.0);
+/// 赭石, #862617
+pub const ZHE_SHI: Color = Color::new_const(134.0, 38.0, 23.0, 1.0);
+/// 暗驼棕, #592620
+pub const AN_TUO_ZONG: Color = Color::new_const(89.0, 38.0, 32.0, 1.0);
+/// 酱棕, #5a1f1b
+pub const JIANG_ZONG: Color = Color::new_const(90.0, 31.0, 27.0, 1.0);
+/// 栗棕, #5c1e19
+pub const LI_ZONG: Color = Color::new_const(92.0, 30.0, 25.0, 1.0);
+/// 洋水仙红, #f4c7ba
+pub const YANG_SHUI_XIAN_HONG: Color = Color::new_const(244.0, 199.0, 186.0, 1.0);
+/// 谷鞘红, #f17666
+pub const GU_QIAO_HONG: Color = Color::new_const(241.0, 118.0, 102.0, 1.0);
+/// 苹果红, #f15642
+pub const PING_GUO_HONG: Color = Color::new_const(241.0, 86.0, 66.0, 1.0);
+/// 铁水红, #f5391c
+pub const TIE_SHUI_HONG: Color = Color::new_const(245.0, 57.0, 28.0, 1.0);
+/// 桂红, #f25a47
+pub const GUI_HONG: Color = Color::new_const(242.0, 90.0, 71.0, 1.0);
+/// 极光红, #f33b1f
+pub const JI_GUANG_HONG: Color = Color::new_const(243.0, 59.0, 31.0, 1.0);
+/// 粉红, #f2b9b2
+pub const FEN_HONG: Color = Color::new_const(242.0, 185.0, 178.0, 1.0);
+/// 舌红, #f19790
+pub const SHE_HONG: Color = Color::new_const(241.0, 151.0, 144.0, 1.0);
+/// 曲红, #f05a46
+pub const QU_HONG: Color = Color::new_const(240.0, 90.0, 70.0, 1.0);
+/// 红汞红, #f23e23
+pub const HONG_GONG_HONG: Color = Color::new_const(242.0, 62.0, 35.0, 1.0);
+/// 淡绯, #f2cac9
+pub const DAN_FEI: Color = Color::new_const(242.0, 202.0, 201.0, 1.0);
+/// 无花果红, #efafad
+pub const WU_HUA_GUO_HONG: Color = Color::new_const(239.0, 175.0, 173.0, 1.0);
+/// 榴子红, #f1908c
+pub const LIU_ZI_HONG: Color = Color::new_const(241.0, 144.0, 140.0, 1.0);
+/// 胭脂红, #f03f24
+pub const YAN_ZHI_HONG: Color = Color::new_const(240.0, 63.0, 36.0, 1.0);
+/// 合欢红, #f0a1a8
+pub const HE_HUAN_HONG: Color = Color::new_const(240.0, 161.0, 168.0, 1.0);
+/// 春梅红, #f1939c
+pub const CHUN_MEI_HONG: Color = Color::new_const(241.0, 147.0, 156.0, 1.0);
+/// 香叶红, #f07c82
+pub const XIANG_YE_HONG: Color = Color::new_const(240.0, 124.0, 130.0, 1.0);
+/// 珊瑚红, #f04a3a
+pub const SHAN_HU_HONG: Color = Color::new_const(240.0, 74.0, 58.0, 1.0);
+/// 萝卜红, #f13c22
+pub const LUO_BO_HONG: Color = Color::new_const(241.0, 60.0, 34.0, 1.0);
+/// 淡茜红, #e77c8e
+pub const DAN_QIAN_HONG: Color = Color::new_const(231.0, 124.0, 142.0, 1.0);
+/// 艳红, #ed5a65
+pub const YAN_HONG: Color = Color::new_const(237.0, 90.0, 101.0, 1.0);
+/// 淡菽红, #ed4845
+pub const DAN_SHU_HONG: Color = Color::new_const(237.0, 72.0, 69.0, 1.0);
+/// 鱼鳃红, #ed3b2f
+pub const YU_SAI_HONG: Color = Color::new_const(237.0, 59.0, 47.0, 1.0);
+/// 樱桃红, #ed3321
+pub const YING_TAO_HONG: Color = Color::new_const(237.0, 51.0, 33.0, 1.0);
+/// 淡蕊香红, #ee4866
+pub const DAN_RUI_XIANG_HONG: Color = Color::new_const(238.0, 72.0, 102.0, 1.0);
+/// 石竹红, #ee4863
+pub const SHI_ZHU_HONG: Color = Color::new_const(238.0, 72.0, 99.0, 1.0);
+/// 草茉莉红, #ef475d
+pub const CAO_MO_LI_HONG: Color = Color::new_const(239.0, 71.0, 93.0, 1.0);
+/// 茶花红, #ee3f4d
+pub const CHA_HUA_HONG: Color = Color::new_const(238.0, 63.0, 77.0, 1.0);
+/// 枸枢红, #ed3333
+pub const GOU_SHU_HONG: Color = Color::new_const(237.0, 51.0, 51.0, 1.0);
+/// 秋海棠红, #ec2b24
+pub const QIU_HAI_TANG_HONG: Color = Color::new_const(236.0, 43.0, 36.0, 1.0);
+/// 丽春红, #eb261a
+pub const LI_CHUN_HONG: Color = Color::new_const(235.0, 38.0, 26.0, 1.0);
+/// 夕阳红, #de2a18
+pub const XI_YANG_HONG: Color = Color::new_const(222.0, 42.0, 24.0, 1.0);
+/// 鹤顶红, #d42517
+pub const HE_DING_HONG: Color = Color::new_const(212.0, 37.0, 23.0, 1.0);
+/// 鹅血石红, #ab372f
+pub const E_XUE_SHI_HONG: Color = Color::new_const(171.0, 55.0, 47.0, 1.0);
+/// 覆盆子红, #ac1f18
+pub const FU_PEN_ZI_HONG: Color = Color::new_const(172.0, 31.0, 24.0, 1.0);
+/// 貂紫, #5d3131
+pub const DIAO_ZI: Color = Color::new_const(93.0, 49.0, 49.0, 1.0);
+/// 暗玉紫, #5c2223
+pub const AN_YU_ZI: Color = Color::new_const(92.0, 34.0, 35.0, 1.0);
+/// 栗紫, #5a191b
+pub const LI_ZI: Color = Color::new_const(90.0, 25.0, 27.0, 1.0);
+/// 葡萄酱紫, #5a1216
+pub const PU_TAO_JIANG_ZI: Color = Color::new_const(90.0, 18.0, 22.0, 1.0);
+/// 牡丹粉红, #eea2a4
+pub const MU_DAN_FEN_HONG: Color = Color::new_const(238.0, 162.0, 164.0, 1.0);
+/// 山茶红, #ed556a
+pub const SHAN_CHA_HONG: Color = Color::new_const(237.0, 85.0, 106.0, 1.0);
+/// 海棠红, #f03752
+pub const HAI_TANG_HONG: Color = Color::new_const(240.0, 55.0, 82.0, 1.0);
+/// 玉红, #c04851
+pub const YU_HONG: Color = Color::new_const(192.0, 72.0, 81.0, 1.0);
+/// 高粱红, #c02c38
+pub const GAO_LIANG_HONG: Color = Color::new_const(192.0, 44.0, 56.0, 1.0);
+/// 满江红, #a7535a
+pub const MAN_JIANG_HONG: Color = Color::new_const(167.0, 83.0, 90.0, 1.0);
+/// 枣红, #7c1823
+pub const ZAO_HONG: Color = Color::new_const(124.0, 24.0, 35.0, 1.0);
+/// 葡萄紫, #4c1f24
+pub const PU_TAO_ZI: Color = Color::new_const(76.0, 31.0, 36.0, 1.0);
+/// 酱紫, #4d1018
+pub const JIANG_ZI: Color = Color::new_const(77.0, 16.0, 24.0, 1.0);
+/// 淡曙红, #ee2746
+pub const DAN_SHU_HONG_2: Color = Color::new_const(238.0, 39.0, 70.0, 1.0);
+/// 唐菖蒲红, #de1c31
+pub const TANG_CHANG_PU_HONG: Color = Color::new_const(222.0, 28.0, 49.0, 1.0);
+/// 鹅冠红, #d11a2d
+pub const E_GUAN_HONG: Color = Color::new_const(209.0, 26.0, 45.0, 1.0);
+/// 莓红, #c45a65
+pub const MEI_HONG: Color = Color::new_const(196.0, 90.0, 101.0, 1.0);
+/// 枫叶红, #c21f30
+pub const FENG_YE_HONG: Color = Color::new_const(194.0, 31.0, 48.0, 1.0);
+/// 苋菜红, #a61b29
+pub const XIAN_CAI_HONG: Color = Color::new_const(166.0, 27.0, 41.0, 1.0);
+/// 烟红, #894e54
+pub const YAN_HONG_2: Color = Color::new_const(137.0, 78.0, 84.0, 1.0);
+/// 暗紫苑红, #82202b
+pub const AN_ZI_YUAN_HONG: Color = Color::new_const(130.0, 32.0, 43.0, 1.0);
+/// 殷红, #82111f
+pub const YIN_HONG: Color = Color::new_const(130.0, 17.0, 31.0, 1.0);
+/// 猪肝紫, #541e24
+pub const ZHU_GAN_ZI: Color = Color::new_const(84.0, 30.0, 36.0, 1.0);
+/// 金鱼紫, #500a16
+pub const JIN_YU_ZI: Color = Color::new_const(80.0, 10.0, 22.0, 1.0);
+/// 草珠红, #f8ebe6
+pub const CAO_ZHU_HONG: Color = Color::new_const(248.0, 235.0, 230.0, 1.0);
+/// 淡绛红, #ec7696
+pub const DAN_JIANG_HONG: Color = Color::new_const(236.0, 118.0, 150.0, 1.0);
+/// 品红, #ef3473
+pub const PIN_HONG: Color = Color::new_const(239.0, 52.0, 115.0, 1.0);
+/// 凤仙花红, #ea7293
+pub const FENG_XIAN_HUA_HONG: Color = Color::new_const(234.0, 114.0, 147.0, 1.0);
+/// 粉团花红, #ec9bad
+pub const FEN_TUAN_HUA_HONG: Color = Color::new_const(236.0, 155.0, 173.0, 1.0);
+/// 夹竹桃红, #eb507e
+pub const JIA_ZHU_TAO_HONG: Color = Color::new_const(235.0, 80.0, 126.0, 1.0);
+/// 榲桲红, #ed2f6a
+pub const WEN_PO_HONG: Color = Color::new_const(237.0, 47.0, 106.0, 1.0);
+/// 姜红, #eeb8c3
+pub const JIANG_HONG: Color = Color::new_const(238.0, 184.0, 195.0, 1.0);
+/// 莲瓣红, #ea517f
+pub const LIAN_BAN_HONG: Color = Color::new_const(234.0, 81.0, 127.0, 1.0);
+/// 水红, #f1c4cd
+pub const SHUI_HONG: Color = Color::new_const(241.0, 196.0, 205.0, 1.0);
+/// 报春红, #ec8aa4
+pub const BAO_CHUN_HONG: Color = Color::new_const(236.0, 138.0, 164.0, 1.0);
+/// 月季红, #ce5777
+pub const YUE_JI_HONG: Color = Color::new_const(206.0, 87.0, 119.0, 1.0);
+/// 豇豆红, #ed9db2
+pub const JIANG_DOU_HONG: Color = Color::new_const(237.0, 157.0, 178.0, 1.0);
+/// 霞光红, #ef82a0
+pub const XIA_GUANG_HONG: Color = Color::new_const(239.0, 130.0, 160.0, 1.0);
+/// 松叶牡丹红, #eb3c70
+pub const SONG_YE_MU_DAN_HONG: Color = Color::new_const(235.0, 60.0, 112.0, 1.0);
+/// 喜蛋红, #ec2c64
+pub const XI_DAN_HONG: Color = Color::new_const(236.0, 44.0, 100.0, 1.0);
+/// 鼠鼻红, #e3b4b8
+pub const SHU_BI_HONG: Color = Color::new_const(227.0, 180.0, 184.0, 1.0);
+/// 尖晶玉红, #cc163a
+pub const JIAN_JING_YU_HONG: Color = Color::new_const(204.0, 22.0, 58.0, 1.0);
+/// 山黎豆红, #c27c88
+pub const SHAN_LI_DOU_HONG: Color = Color::new_const(194.0, 124.0, 136.0, 1.0);
+/// 锦葵红, #bf3553
+pub const JIN_KUI_HONG: Color = Color::new_const(191.0, 53.0, 83.0, 1.0);
+/// 鼠背灰, #73575c
+pub const SHU_BEI_HUI: Color = Color::new_const(115.0, 87.0, 92.0, 1.0);
+/// 甘蔗紫, #621624
+pub const GAN_ZHE_ZI: Color = Color::new_const(98.0, 22.0, 36.0, 1.0);
+/// 石竹紫, #63071c
+pub const SHI_ZHU_ZI: Color = Color::new_const(99.0, 7.0, 28.0, 1.0);
+/// 苍蝇灰, #36282b
+pub const CANG_YING_HUI: Color = Color::new_const(54.0, 40.0, 43.0, 1.0);
+/// 卵石紫, #30161c
+pub const LUAN_SHI_ZI: Color = Color::new_const(48.0, 22.0, 28.0, 1.0);
+/// 李紫, #2b1216
+pub const LI_ZI_2: Color = Color::new_const(43.0, 18.0, 22.0, 1.0);
+/// 茄皮紫, #2d0c13
+pub const QIE_PI_ZI: Color = Color::new_const(45.0, 12.0, 19.0, 1.0);
+/// 吊钟花红, #ce5e8a
+pub const DIAO_ZHONG_HUA_HONG: Color = Color::new_const(206.0, 94.0, 138.0, 1.0);
+/// 兔眼红, #ec4e8a
+pub const TU_YAN_HONG: Color = Color::new_const(236.0, 78.0, 138.0, 1.0);
+/// 紫荆红, #ee2c79
+pub const ZI_JING_HONG: Color = Color::new_const(238.0, 44.0, 121.0, 1.0);
+/// 菜头紫, #951c48
+pub const CAI_TOU_ZI: Color = Color::new_const(149.0, 28.0, 72.0, 1.0);
+/// 鹞冠紫, #621d34
+pub const YAO_GUAN_ZI: Color = Color::new_const(98.0, 29.0, 52.0, 1.0);
+/// 葡萄酒红, #62102e
+pub const PU_TAO_JIU_HONG: Color = Color::new_const(98.0, 16.0, 46.0, 1.0);
+/// 磨石紫, #382129
+pub const MO_SHI_ZI: Color = Color::new_const(56.0, 33.0, 41.0, 1.0);
+/// 檀紫, #381924
+pub const TAN_ZI: Color = Color::new_const(56.0, 25.0, 36.0, 1.0);
+/// 火鹅紫, #33141e
+pub const HUO_E_ZI: Color = Color::new_const(51.0, 20.0, 30.0, 1.0);
+/// 墨紫, #310f1b
+pub const MO_ZI: Color = Color::new_const(49.0, 15.0, 27.0, 1.0);
+/// 晶红, #eea6b7
+pub const JING_HONG: Color = Color::new_const(238.0, 166.0, 183.0, 1.0);
+/// 扁豆花红, #ef498b
+pub const BIAN_DOU_HUA_HONG: Color = Color::new_const(239.0, 73.0, 139.0, 1.0);
+/// 白芨红, #de7897
+pub const BAI_JI_HONG: Color = Color::new_const(222.0, 120.0, 151.0, 1.0);
+/// 嫩菱红, #de3f7c
+pub const NEN_LING_HONG: Color = Color::new_const(222.0, 63.0, 124.0, 1.0);
+/// 菠根红, #d13c74
+pub const BO_GEN_HONG: Color = Color::new_const(209.0, 60.0, 116.0, 1.0);
+/// 酢酱草红, #c5708b
+pub const CU_JIANG_CAO_HONG: Color = Color::new_const(197.0, 112.0, 139.0, 1.0);
+/// 洋葱紫, #a8456b
+pub const YANG_CONG_ZI: Color = Color::new_const(168.0, 69.0, 107.0, 1.0);
+/// 海象紫, #4b1e2f
+pub const HAI_XIANG_ZI: Color = Color::new_const(75.0, 30.0, 47.0, 1.0);
+/// 绀紫, #461629
+pub const GAN_ZI: Color = Color::new_const(70.0, 22.0, 41.0, 1.0);
+/// 古铜紫, #440e25
+pub const GU_TONG_ZI: Color = Color::new_const(68.0, 14.0, 37.0, 1.0);
+/// 石蕊红, #f0c9cf
+pub const SHI_RUI_HONG: Color = Color::new_const(240.0, 201.0, 207.0, 1.0);
+/// 芍药耕红, #eba0b3
+pub const SHAO_YAO_GENG_HONG: Color = Color::new_const(235.0, 160.0, 179.0, 1.0);
+/// 藏花红, #ec2d7a
+pub const ZANG_HUA_HONG: Color = Color::new_const(236.0, 45.0, 122.0, 1.0);
+/// 初荷红, #e16c96
+pub const CHU_HE_HONG: Color = Color::new_const(225.0, 108.0, 150.0, 1.0);
+/// 马鞭草紫, #ede3e7
+pub const MA_BIAN_CAO_ZI: Color = Color::new_const(237.0, 227.0, 231.0, 1.0);
+/// 丁香淡紫, #e9d7df
+pub const DING_XIANG_DAN_ZI: Color = Color::new_const(233.0, 215.0, 223.0, 1.0);
+/// 丹紫红, #d2568c
+pub const DAN_ZI_HONG: Color = Color::new_const(210.0, 86.0, 140.0, 1.0);
+/// 玫瑰红, #d2357d
+pub const MEI_GUI_HONG: Color = Color::new_const(210.0, 53.0, 125.0, 1.0);
+/// 淡牵牛紫, #d1c2d3
+pub const DAN_QIAN_NIU_ZI: Color = Color::new_const(209.0, 194.0, 211.0, 1.0);
+/// 凤信紫, #c8adc4
+pub const FENG_XIN_ZI: Color = Color::new_const(200.0, 173.0, 196.0, 1.0);
+/// 萝兰紫, #c08eaf
+pub const LUO_LAN_ZI: Color = Color::new_const(192.0, 142.0, 175.0, 1.0);
+/// 玫瑰紫, #ba2f7b
+pub const MEI_GUI_ZI: Color = Color::new_const(186.0, 47.0, 123.0, 1.0);
+/// 藤萝紫, #8076a3
+pub const TENG_LUO_ZI: Color = Color::new_const(128.0, 118.0, 163.0, 1.0);
+/// 槿紫, #806d9e
+pub const JIN_ZI: Color = Color::new_const(128.0, 109.0, 158.0, 1.0);
+/// 蕈紫, #815c94
+pub const XUN_ZI: Color = Color::new_const(129.0, 92.0, 148.0, 1.0);
+/// 桔梗紫, #813c85
+pub const JU_GENG_ZI: Color = Color::new_const(129.0, 60.0, 133.0, 1.0);
+/// 魏紫, #7e1671
+pub const WEI_ZI: Color = Color::new_const(126.0, 22.0, 113.0, 1.0);
+/// 芝兰紫, #e9ccd3
+pub const ZHI_LAN_ZI: Color = Color::new_const(233.0, 204.0, 211.0, 1.0);
+/// 菱锰红, #d276a3
+pub const LING_MENG_HONG: Color = Color::new_const(210.0, 118.0, 163.0, 1.0);
+/// 龙须红, #cc5595
+pub const LONG_XU_HONG: Color = Color::new_const(204.0, 85.0, 149.0, 1.0);
+/// 蓟粉红, #e6d2d5
+pub const JI_FEN_HONG: Color = Color::new_const(230.0, 210.0, 213.0, 1.0);
+/// 电气石红, #c35691
+pub const DIAN_QI_SHI_HONG: Color = Color::new_const(195.0, 86.0, 145.0, 1.0);
+/// 樱草紫, #c06f98
+pub const YING_CAO_ZI: Color = Color::new_const(192.0, 111.0, 152.0, 1.0);
+/// 芦穗灰, #bdaead
+pub const LU_SUI_HUI: Color = Color::new_const(189.0, 174.0, 173.0, 1.0);
+/// 隐红灰, #b598a1
+pub const YIN_HONG_HUI: Color = Color::new_const(181.0, 152.0, 161.0, 1.0);
+/// 苋菜紫, #9b1e64
+pub const XIAN_CAI_ZI: Color = Color::new_const(155.0, 30.0, 100.0, 1.0);
+/// 芦灰, #856d72
+pub const LU_HUI: Color = Color::new_const(133.0, 109.0, 114.0, 1.0);
+/// 暮云灰, #4f383e
+pub const MU_YUN_HUI: Color = Color::new_const(79.0, 56.0, 62.0, 1.0);
+/// 斑鸠灰, #482936
+pub const BAN_JIU_HUI: Color = Color::new_const(72.0, 41.0, 54.0, 1.0);
+/// 淡藤萝紫, #f2e7e5
+pub const DAN_TENG_LUO_ZI: Color = Color::new_const(242.0, 231.0, 229.0, 1.0);
+/// 淡青紫, #e0c8d1
+pub const DAN_QING_ZI: Color = Color::new_const(224.0, 200.0, 209.0, 1.0);
+/// 青蛤壳紫, #bc84a8
+pub const QING_GE_KE_ZI: Color = Color::new_const(188.0, 132.0, 168.0, 1.0);
+/// 豆蔻紫, #ad6598
+pub const DOU_KOU_ZI: Color = Color::new_const(173.0, 101.0, 152.0, 1.0);
+/// 扁豆紫, #a35c8f
+pub const BIAN_DOU_ZI: Color = Color::new_const(163.0, 92.0, 143.0, 1.0);
+/// 芥花紫, #983680
+pub const JIE_HUA_ZI: Color = Color::new_const(152.0, 54.0, 128.0, 1.0);
+/// 青莲, #8b2671
+pub const QING_LIAN: Color = Color::new_const(139.0, 38.0, 113.0, 1.0);
+/// 芓紫, #894276
+pub const ZI_ZI: Color = Color::new_const(137.0, 66.0, 118.0, 1.0);
+/// 葛巾紫, #7e2065
+pub const GE_JIN_ZI: Color = Color::new_const(126.0, 32.0, 101.0, 1.0);
+/// 牵牛紫, #681752
+pub const QIAN_NIU_ZI: Color = Color::new_const(104.0, 23.0, 82.0, 1.0);
+/// 紫灰, #5d3f51
+pub const ZI_HUI: Color = Color::new_const(93.0, 63.0, 81.0, 1.0);
+/// 龙睛鱼紫, #4e2a40
+pub const LONG_JING_YU_ZI: Color = Color::new_const(78.0, 42.0, 64.0, 1.0);
+/// 荸荠紫, #411c35
+pub const BI_JI_ZI: Color = Color::new_const(65.0, 28.0, 53.0, 1.0);
+/// 古鼎灰, #36292f
+pub const GU_DING_HUI: Color = Color::new_const(54.0, 41.0, 47.0, 1.0);
+/// 乌梅紫, #1e131d
+pub const WU_MEI_ZI: Color = Color::new_const(30.0, 19.0, 29.0, 1.0);
+/// 深牵牛紫, #1c0d1a
+pub const SHEN_QIAN_NIU_ZI: Color = Color::new_const(28.0, 13.0, 26.0, 1.0);
+/// 银白, #f1f0ed
+pub const YIN_BAI: Color = Color::new_const(241.0, 240.0, 237.0, 1.0);
+/// 芡食白, #e2e1e4
+pub const QIAN_SHI_BAI: Color = Color::new_const(226.0, 225.0, 228.0, 1.0);
+/// 远山紫, #ccccd6
+pub const YUAN_SHAN_ZI: Color = Color::new_const(204.0, 204.0, 214.0, 1.0);
+/// 淡蓝紫, #a7a8bd
+pub const DAN_LAN_ZI: Color = Color::new_const(167.0, 168.0, 189.0, 1.0);
+/// 山梗紫, #61649f
+pub const SHAN_GENG_ZI: Color = Color::new_const(97.0, 100.0, 159.0, 1.0);
+/// 螺甸紫, #74759b
+pub const LUO_DIAN_ZI: Color = Color::new_const(116.0, 117.0, 155.0, 1.0);
+/// 玛瑙灰, #cfccc9
+pub const MA_NAO_HUI: Color = Color::new_const(207.0, 204.0, 201.0, 1.0);
+/// 野菊紫, #525288
+pub const YE_JU_ZI: Color = Color::new_const(82.0, 82.0, 136.0, 1.0);
+/// 满天星紫, #2e317c
+pub const MAN_TIAN_XING_ZI: Color = Color::new_const(46.0, 49.0, 124.0, 1.0);
+/// 锌灰, #7a7374
+pub const XIN_HUI: Color = Color::new_const(122.0, 115.0, 116.0, 1.0);
+/// 野葡萄紫, #302f4b
+pub const YE_PU_TAO_ZI: Color = Color::new_const(48.0, 47.0, 75.0, 1.0);
+/// 剑锋紫, #3e3841
+pub const JIAN_FENG_ZI: Color = Color::new_const(62.0, 56.0, 65.0, 1.0);
+/// 龙葵紫, #322f3b
+pub const LONG_KUI_ZI: Color = Color::new_const(50.0, 47.0, 59.0, 1.0);
+/// 暗龙胆紫, #22202e
+pub const AN_LONG_DAN_ZI: Color = Color::new_const(34.0, 32.0, 46.0, 1.0);
+/// 晶石紫, #1f2040
+pub const JING_SHI_ZI: Color = Color::new_const(31.0, 32.0, 64.0, 1.0);
+/// 暗蓝紫, #131124
+pub const AN_LAN_ZI: Color = Color::new_const(19.0, 17.0, 36.0, 1.0);
+/// 景泰蓝, #2775b6
+pub const JING_TAI_LAN: Color = Color::new_const(39.0, 117.0, 182.0, 1.0);
+/// 尼罗蓝, #2474b5
+pub const NI_LUO_LAN: Color = Color::new_const(36.0, 116.0, 181.0, 1.0);
+/// 远天蓝, #d0dfe6
+pub const YUAN_TIAN_LAN: Color = Color::new_const(208.0, 223.0, 230.0, 1.0);
+/// 星蓝, #93b5cf
+pub const XING_LAN: Color = Color::new_const(147.0, 181.0, 207.0, 1.0);
+/// 羽扇豆蓝, #619ac3
+pub const YU_SHAN_DOU_LAN: Color = Color::new_const(97.0, 154.0, 195.0, 1.0);
+/// 花青, #2376b7
+pub const HUA_QING: Color = Color::new_const(35.0, 118.0, 183.0, 1.0);
+/// 睛蓝, #5698c3
+pub const JING_LAN: Color = Color::new_const(86.0, 152.0, 195.0, 1.0);
+/// 虹蓝, #2177b8
+pub const HONG_LAN: Color = Color::new_const(33.0, 119.0, 184.0, 1.0);
+/// 湖水蓝, #b0d5df
+pub const HU_SHUI_LAN: Color = Color::new_const(176.0, 213.0, 223.0, 1.0);
+/// 秋波蓝, #8abcd1
+pub const QIU_BO_LAN: Color = Color::new_const(138.0, 188.0, 209.0, 1.0);
+/// 涧石蓝, #66a9c9
+pub const JIAN_SHI_LAN: Color = Color::new_const(102.0, 169.0, 201.0, 1.0);
+/// 潮蓝, #2983bb
+pub const CHAO_LAN: Color = Color::new_const(41.0, 131.0, 187.0, 1.0);
+/// 群青, #1772b4
+pub const QUN_QING: Color = Color::new_const(23.0, 114.0, 180.0, 1.0);
+/// 霁青, #63bbd0
+pub const JI_QING: Color = Color::new_const(99.0, 187.0, 208.0, 1.0);
+/// 碧青, #5cb3cc
+pub const BI_QING: Color = Color::new_const(92.0, 179.0, 204.0, 1.0);
+/// 宝石蓝, #2486b9
+pub const BAO_SHI_LAN: Color = Color::new_const(36.0, 134.0, 185.0, 1.0);
+/// 天蓝, #1677b3
+pub const TIAN_LAN: Color = Color::new_const(22.0, 119.0, 179.0, 1.0);
+/// 柏林蓝, #126bae
+pub const BAI_LIN_LAN: Color = Color::new_const(18.0, 107.0, 174.0, 1.0);
+/// 海青, #22a2c3
+pub const HAI_QING: Color = Color::new_const(34.0, 162.0, 195.0, 1.0);
+/// 钴蓝, #1a94bc
+pub const GU_LAN: Color = Color::new_const(26.0, 148.0, 188.0, 1.0);
+/// 鸢尾蓝, #158bb8
+pub const YUAN_WEI_LAN: Color = Color::new_const(21.0, 139.0, 184.0, 1.0);
+/// 牵牛花蓝, #1177b0
+pub const QIAN_NIU_HUA_LAN: Color = Color::new_const(17.0, 119.0, 176.0, 1.0);
+/// 飞燕草蓝, #0f59a4
+pub const FEI_YAN_CAO_LAN: Color = Color::new_const(15.0, 89.0, 164.0, 1.0);
+/// 品蓝, #2b73af
+pub const PIN_LAN: Color = Color::new_const(43.0, 115.0, 175.0, 1.0);
+/// 银鱼白, #cdd1d3
+pub const YIN_YU_BAI: Color = Color::new_const(205.0, 209.0, 211.0, 1.0);
+/// 安安蓝, #3170a7
+pub const AN_AN_LAN: Color = Color::new_const(49.0, 112.0, 167.0, 1.0);
+/// 鱼尾灰, #5e616d
+pub const YU_WEI_HUI: Color = Color::new_const(94.0, 97.0, 109.0, 1.0);
+/// 鲸鱼灰, #475164
+pub const JING_YU_HUI: Color = Color::new_const(71.0, 81.0, 100.0, 1.0);
+/// 海参灰, #fffefa
+pub const HAI_SHEN_HUI: Color = Color::new_const(255.0, 254.0, 250.0, 1.0);
+/// 沙鱼灰, #35333c
+pub const SHA_YU_HUI: Color = Color::new_const(53.0, 51.0, 60.0, 1.0);
+/// 钢蓝, #0f1423
+pub const GANG_LAN: Color = Color::new_const(15.0, 20.0, 35.0, 1.0);
+/// 云水蓝, #baccd9
+pub const YUN_SHUI_LAN: Color = Color::new_const(186.0, 204.0, 217.0, 1.0);
+/// 晴山蓝, #8fb2c9
+pub const QING_SHAN_LAN: Color = Color::new_const(143.0, 178.0, 201.0, 1.0);
+/// 靛青, #1661ab
+pub const DIAN_QING: Color = Color::new_const(22.0, 97.0, 171.0, 1.0);
+/// 大理石灰, #c4cbcf
+pub const DA_LI_SHI_HUI: Color = Color::new_const(196.0, 203.0, 207.0, 1.0);
+/// 海涛蓝, #15559a
+pub const HAI_TAO_LAN: Color = Color::new_const(21.0, 85.0, 154.0, 1.0);
+/// 蝶翅蓝, #4e7ca1
+pub const DIE_CHI_LAN: Color = Color::new_const(78.0, 124.0, 161.0, 1.0);
+/// 海军蓝, #346c9c
+pub const HAI_JUN_LAN: Color = Color::new_const(52.0, 108.0, 156.0, 1.0);
+/// 水牛灰, #2f2f35
+pub const SHUI_NIU_HUI: Color = Color::new_const(47.0, 47.0, 53.0, 1.0);
+/// 牛角灰, #2d2e36
+pub const NIU_JIAO_HUI: Color = Color::new_const(45.0, 46.0, 54.0, 1.0);
+/// 燕颔蓝, #131824
+pub const YAN_HAN_LAN: Color = Color::new_const(19.0, 24.0, 36.0, 1.0);
+/// 云峰白, #d8e3e7
+pub const YUN_FENG_BAI: Color = Color::new_const(216.0, 227.0, 231.0, 1.0);
+/// 井天蓝, #c3d7df
+pub const JING_TIAN_LAN: Color = Color::new_const(195.0, 215.0, 223.0, 1.0);
+/// 云山蓝, #2f90b9
+pub const YUN_SHAN_LAN: Color = Color::new_const(47.0, 144.0, 185.0, 1.0);
+/// 釉蓝, #1781b5
+pub const YOU_LAN: Color = Color::new_const(23.0, 129.0, 181.0, 1.0);
+/// 鸥蓝, #c7d2d4
+pub const OU_LAN: Color = Color::new_const(199.0, 210.0, 212.0, 1.0);
+/// 搪磁蓝, #11659a
+pub const TANG_CI_LAN: Color = Color::new_const(17.0, 101.0, 154.0, 1.0);
+/// 月影白, #c0c4c3
+pub const YUE_YING_BAI: Color = Color::new_const(192.0, 196.0, 195.0, 1.0);
+/// 星灰, #b2bbbe
+pub const XING_HUI: Color = Color::new_const(178.0, 187.0, 190.0, 1.0);
+/// 淡蓝灰, #5e7987
+pub const DAN_LAN_HUI: Color = Color::new_const(94.0, 121.0, 135.0, 1.0);
+/// 鷃蓝, #144a74
+pub const YAN_LAN: Color = Color::new_const(20.0, 74.0, 116.0, 1.0);
+/// 嫩灰, #74787a
+pub const NEN_HUI: Color = Color::new_const(116.0, 120.0, 122.0, 1.0);
+/// 战舰灰, #495c69
+pub const ZHAN_JIAN_HUI: Color = Color::new_const(73.0, 92.0, 105.0, 1.0);
+/// 瓦罐灰, #47484c
+pub const WA_GUAN_HUI: Color = Color::new_const(71.0, 72.0, 76.0, 1.0);
+/// 青灰, #2b333e
+pub const QING_HUI: Color = Color::new_const(43.0, 51.0, 62.0, 1.0);
+/// 鸽蓝, #1c2938
+pub const GE_LAN: Color = Color::new_const(28.0, 41.0, 56.0, 1.0);
+/// 钢青, #142334
+pub const GANG_QING: Color = Color::new_const(20.0, 35.0, 52.0, 1.0);
+/// 暗蓝, #101f30
+pub const AN_LAN: Color = Color::new_const(16.0, 31.0, 48.0, 1.0);
+/// 月白, #eef7f2
+pub const YUE_BAI: Color = Color::new_const(238.0, 247.0, 242.0, 1.0);
+/// 海天蓝, #c6e6e8
+pub const HAI_TIAN_LAN: Color = Color::new_const(198.0, 230.0, 232.0, 1.0);
+/// 清水蓝, #93d5dc
+pub const QING_SHUI_LAN: Color = Color::new_const(147.0, 213.0, 220.0, 1.0);
+/// 瀑布蓝, #51c4d3
+pub const PU_BU_LAN: Color = Color::new_const(81.0, 196.0, 211.0, 1.0);
+/// 蔚蓝, #29b7cb
+pub const WEI_LAN: Color = Color::new_const(41.0, 183.0, 203.0, 1.0);
+/// 孔雀蓝, #0eb0c9
+pub const KONG_QUE_LAN: Color = Color::new_const(14.0, 176.0, 201.0, 1.0);
+/// 甸子蓝, #10aec2
+pub const DIAN_ZI_LAN: Color = Color::new_const(16.0, 174.0, 194.0, 1.0);
+/// 石绿, #57c3c2
+pub const SHI_LV: Color = Color::new_const(87.0, 195.0, 194.0, 1.0);
+/// 竹篁绿, #b9dec9
+pub const ZHU_HUANG_LV: Color = Color::new_const(185.0, 222.0, 201.0, 1.0);
+/// 粉绿, #83cbac
+pub const FEN_LV: Color = Color::new_const(131.0, 203.0, 172.0, 1.0);
+/// 美蝶绿, #12aa9c
+pub const MEI_DIE_LV: Color = Color::new_const(18.0, 170.0, 156.0, 1.0);
+/// 毛绿, #66c18c
+pub const MAO_LV: Color = Color::new_const(102.0, 193.0, 140.0, 1.0);
+/// 蔻梢绿, #5dbe8a
+pub const KOU_SHAO_LV: Color = Color::new_const(93.0, 190.0, 138.0, 1.0);
+/// 麦苗绿, #55bb8a
+pub const MAI_MIAO_LV: Color = Color::new_const(85.0, 187.0, 138.0, 1.0);
+/// 蛙绿, #45b787
+pub const WA_LV: Color = Color::new_const(69.0, 183.0, 135.0, 1.0);
+/// 铜绿, #2bae85
+pub const TONG_LV: Color = Color::new_const(43.0, 174.0, 133.0, 1.0);
+/// 竹绿, #1ba784
+pub const ZHU_LV: Color = Color::new_const(27.0, 167.0, 132.0, 1.0);
+/// 蓝绿, #12a182
+pub const LAN_LV: Color = Color::new_const(18.0, 161.0, 130.0, 1.0);
+/// 穹灰, #c4d7d6
+pub const QIONG_HUI: Color = Color::new_const(196.0, 215.0, 214.0, 1.0);
+/// 翠蓝, #1e9eb3
+pub const CUI_LAN: Color = Color::new_const(30.0, 158.0, 179.0, 1.0);
+/// 胆矾蓝, #0f95b0
+pub const DAN_FAN_LAN: Color = Color::new_const(15.0, 149.0, 176.0, 1.0);
+/// 樫鸟蓝, #1491a8
+pub const JIAN_NIAO_LAN: Color = Color::new_const(20.0, 145.0, 168.0, 1.0);
+/// 闪蓝, #7cabb1
+pub const SHAN_LAN: Color = Color::new_const(124.0, 171.0, 177.0, 1.0);
+/// 冰山蓝, #a4aca7
+pub const BING_SHAN_LAN: Color = Color::new_const(164.0, 172.0, 167.0, 1.0);
+/// 虾壳青, #869d9d
+pub const XIA_KE_QING: Color = Color::new_const(134.0, 157.0, 157.0, 1.0);
+/// 晚波蓝, #648e93
+pub const WAN_BO_LAN: Color = Color::new_const(100.0, 142.0, 147.0, 1.0);
+/// 蜻蜓蓝, #3b818c
+pub const QING_TING_LAN: Color = Color::new_const(59.0, 129.0, 140.0, 1.0);
+/// 玉鈫蓝, #126e82
+pub const YU_QIN_LAN: Color = Color::new_const(18.0, 110.0, 130.0, 1.0);
+/// 垩灰, #737c7b
+pub const E_HUI: Color = Color::new_const(115.0, 124.0, 123.0, 1.0);
+/// 夏云灰, #617172
+pub const XIA_YUN_HUI: Color = Color::new_const(97.0, 113.0, 114.0, 1.0);
+/// 苍蓝, #134857
+pub const CANG_LAN: Color = Color::new_const(19.0, 72.0, 87.0, 1.0);
+/// 黄昏灰, #474b4c
+pub const HUANG_HUN_HUI: Color = Color::new_const(71.0, 75.0, 76.0, 1.0);
+/// 灰蓝, #21373d
+pub const HUI_LAN: Color = Color::new_const(33.0, 55.0, 61.0, 1.0);
+/// 深灰蓝, #132c33
+pub const SHEN_HUI_LAN: Color = Color::new_const(19.0, 44.0, 51.0, 1.0);
+/// 玉簪绿, #a4cab6
+pub const YU_ZAN_LV: Color = Color::new_const(164.0, 202.0, 182.0, 1.0);
+/// 青矾绿, #2c9678
+pub const QING_FAN_LV: Color = Color::new_const(44.0, 150.0, 120.0, 1.0);
+/// 草原远绿, #9abeaf
+pub const CAO_YUAN_YUAN_LV: Color = Color::new_const(154.0, 190.0, 175.0, 1.0);
+/// 梧枝绿, #69a794
+pub const WU_ZHI_LV: Color = Color::new_const(105.0, 167.0, 148.0, 1.0);
+/// 浪花绿, #92b3a5
+pub const LANG_HUA_LV: Color = Color::new_const(146.0, 179.0, 165.0, 1.0);
+/// 海王绿, #248067
+pub const HAI_WANG_LV: Color = Color::new_const(36.0, 128.0, 103.0, 1.0);
+/// 亚丁绿, #428675
+pub const YA_DING_LV: Color = Color::new_const(66.0, 134.0, 117.0, 1.0);
+/// 镍灰, #9fa39a
+pub const NIE_HUI: Color = Color::new_const(159.0, 163.0, 154.0, 1.0);
+/// 明灰, #8a988e
+pub const MING_HUI: Color = Color::new_const(138.0, 152.0, 142.0, 1.0);
+/// 淡绿灰, #70887d
+pub const DAN_LV_HUI: Color = Color::new_const(112.0, 136.0, 125.0, 1.0);
+/// 飞泉绿, #497568
+pub const FEI_QUAN_LV: Color = Color::new_const(73.0, 117.0, 104.0, 1.0);
+/// 狼烟灰, #5d655f
+pub const LANG_YAN_HUI: Color = Color::new_const(93.0, 101.0, 95.0, 1.0);
+/// 绿灰, #314a43
+pub const LV_HUI: Color = Color::new_const(49.0, 74.0, 67.0, 1.0);
+/// 苍绿, #223e36
+pub const CANG_LV: Color = Color::new_const(34.0, 62.0, 54.0, 1.0);
+/// 深海绿, #1a3b32
+pub const SHEN_HAI_LV: Color = Color::new_const(26.0, 59.0, 50.0, 1.0);
+/// 长石灰, #363433
+pub const CHANG_SHI_HUI: Color = Color::new_const(54.0, 52.0, 51.0, 1.0);
+/// 苷蓝绿, #1f2623
+pub const GAN_LAN_LV_2: Color = Color::new_const(31.0, 38.0, 35.0, 1.0);
+/// 莽丛绿, #141e1b
+pub const MANG_CONG_LV: Color = Color::new_const(20.0, 30.0, 27.0, 1.0);
+/// 淡翠绿, #c6dfc8
+pub const DAN_CUI_LV: Color = Color::new_const(198.0, 223.0, 200.0, 1.0);
+/// 明绿, #9eccab
+pub const MING_LV: Color = Color::new_const(158.0, 204.0, 171.0, 1.0);
+/// 田园绿, #68b88e
+pub const TIAN_YUAN_LV: Color = Color::new_const(104.0, 184.0, 142.0, 1.0);
+/// 翠绿, #20a162
+pub const CUI_LV: Color = Color::new_const(32.0, 161.0, 98.0, 1.0);
+/// 淡绿, #61ac85
+pub const DAN_LV: Color = Color::new_const(97.0, 172.0, 133.0, 1.0);
+/// 葱绿, #40a070
+pub const CONG_LV: Color = Color::new_const(64.0, 160.0, 112.0, 1.0);
+/// 孔雀绿, #229453
+pub const KONG_QUE_LV: Color = Color::new_const(34.0, 148.0, 83.0, 1.0);
+/// 艾绿, #cad3c3
+pub const AI_LV: Color = Color::new_const(202.0, 211.0, 195.0, 1.0);
+/// 蟾绿, #3c9566
+pub const CHAN_LV: Color = Color::new_const(60.0, 149.0, 102.0, 1.0);
+/// 宫殿绿, #20894d
+pub const GONG_DIAN_LV: Color = Color::new_const(32.0, 137.0, 77.0, 1.0);
+/// 松霜绿, #83a78d
+pub const SONG_SHUANG_LV: Color = Color::new_const(131.0, 167.0, 141.0, 1.0);
+/// 蛋白石绿, #579572
+pub const DAN_BAI_SHI_LV: Color = Color::new_const(87.0, 149.0, 114.0, 1.0);
+/// 薄荷绿, #207f4c
+pub const BO_HE_LV: Color = Color::new_const(32.0, 127.0, 76.0, 1.0);
+/// 瓦松绿, #6e8b74
+pub const WA_SONG_LV: Color = Color::new_const(110.0, 139.0, 116.0, 1.0);
+/// 荷叶绿, #1a6840
+pub const HE_YE_LV: Color = Color::new_const(26.0, 104.0, 64.0, 1.0);
+/// 田螺绿, #5e665b
+pub const TIAN_LUO_LV: Color = Color::new_const(94.0, 102.0, 91.0, 1.0);
+/// 白屈菜绿, #485b4d
+pub const BAI_QU_CAI_LV: Color = Color::new_const(72.0, 91.0, 77.0, 1.0);
+/// 河豚灰, #393733
+pub const HE_TUN_HUI: Color = Color::new_const(57.0, 55.0, 51.0, 1.0);
+/// 蒽油绿, #373834
+pub const EN_YOU_LV: Color = Color::new_const(55.0, 56.0, 52.0, 1.0);
+/// 槲寄生绿, #2b312c
+pub const HU_JI_SHENG_LV: Color = Color::new_const(43.0, 49.0, 44.0, 1.0);
+/// 云杉绿, #15231b
+pub const YUN_SHAN_LV: Color = Color::new_const(21.0, 35.0, 27.0, 1.0);
+/// 嫩菊绿, #f0f5e5
+pub const NEN_JU_LV: Color = Color::new_const(240.0, 245.0, 229.0, 1.0);
+/// 艾背绿, #dfecd5
+pub const AI_BEI_LV: Color = Color::new_const(223.0, 236.0, 213.0, 1.0);
+/// 嘉陵水绿, #add5a2
+pub const JIA_LING_SHUI_LV: Color = Color::new_const(173.0, 213.0, 162.0, 1.0);
+/// 玉髓绿, #41b349
+pub const YU_SUI_LV: Color = Color::new_const(65.0, 179.0, 73.0, 1.0);
+/// 鲜绿, #43b244
+pub const XIAN_LV: Color = Color::new_const(67.0, 178.0, 68.0, 1.0);
+/// 宝石绿, #41ae3c
+pub const BAO_SHI_LV: Color = Color::new_const(65.0, 174.0, 60.0, 1.0);
+/// 海沬绿, #e2e7bf
+pub const HAI_MEI_LV: Color = Color::new_const(226.0, 231.0, 191.0, 1.0);
+/// 姚黄, #d0deaa
+pub const YAO_HUANG: Color = Color::new_const(208.0, 222.0, 170.0, 1.0);
+/// 橄榄石绿, #b2cf87
+pub const GAN_LAN_SHI_LV: Color = Color::new_const(178.0, 207.0, 135.0, 1.0);
+/// 水绿, #8cc269
+pub const SHUI_LV: Color = Color::new_const(140.0, 194.0, 105.0, 1.0);
+/// 芦苇绿, #b7d07a
+pub const LU_WEI_LV: Color = Color::new_const(183.0, 208.0, 122.0, 1.0);
+/// 槐花黄绿, #d2d97a
+pub const HUAI_HUA_HUANG_LV: Color = Color::new_const(210.0, 217.0, 122.0, 1.0);
+/// 苹果绿, #bacf65
+pub const PING_GUO_LV: Color = Color::new_const(186.0, 207.0, 101.0, 1.0);
+/// 芽绿, #96c24e
+pub const YA_LV: Color = Color::new_const(150.0, 194.0, 78.0, 1.0);
+/// 蝶黄, #e2d849
+pub const DIE_HUANG: Color = Color::new_const(226.0, 216.0, 73.0, 1.0);
+/// 橄榄黄绿, #bec936
+pub const GAN_LAN_HUANG_LV: Color = Color::new_const(190.0, 201.0, 54.0, 1.0);
+/// 鹦鹉绿, #5bae23
+pub const YING_WU_LV: Color = Color::new_const(91.0, 174.0, 35.0, 1.0);
+/// 油绿, #253d24
+pub const YOU_LV: Color = Color::new_const(37.0, 61.0, 36.0, 1.0);
+/// 象牙白, #fffef8
+pub const XIANG_YA_BAI: Color = Color::new_const(255.0, 254.0, 248.0, 1.0);
+/// 汉白玉, #f8f4ed
+pub const HAN_BAI_YU: Color = Color::new_const(248.0, 244.0, 237.0, 1.0);
+/// 雪白, #fffef9
+pub const XUE_BAI: Color = Color::new_const(255.0, 254.0, 249.0, 1.0);
+/// 鱼肚白, #f7f4ed
+pub const YU_DU_BAI: Color = Color::new_const(247.0, 244.0, 237.0, 1.0);
+/// 珍珠灰, #e4dfd7
+pub const ZHEN_ZHU_HUI: Color = Color::new_const(228.0, 223.0, 215.0, 1.0);
+/// 浅灰, #dad4cb
+pub const QIAN_HUI: Color = Color::new_const(218.0, 212.0, 203.0, 1.0);
+/// 铅灰, #bbb5ac
+pub const QIAN_HUI_2: Color = Color::new_const(187.0, 181.0, 172.0, 1.0);
+/// 中灰, #bbb5ac
+pub const ZHONG_HUI: Color = Color::new_const(187.0, 181.0, 172.0, 1.0);
+/// 瓦灰, #867e76
+pub const WA_HUI: Color = Color::new_const(134.0, 126.0, 118.0, 1.0);
+/// 夜灰, #847c74
+pub const YE_HUI: Color = Color::new_const(132.0, 124.0, 116.0, 1.0);
+/// 雁灰, #80766e
+pub const YAN_HUI: Color = Color::new_const(128.0, 118.0, 110.0, 1.0);
+/// 深灰, #81776e
+pub const SHEN_HUI: Color = Color::new_const(129.0, 119.0, 110.0, 1.0);
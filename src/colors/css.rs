@@ -0,0 +1,314 @@
+//! CSS and X11 named colors.
+//!
+//! reference: <https://www.w3.org/TR/css-color-3/#svg-color>
+
+use crate::Color;
+
+/// `aliceblue`, #f0f8ff
+pub const ALICE_BLUE: Color = Color::new_const(240.0, 248.0, 255.0, 1.0);
+/// `antiquewhite`, #faebd7
+pub const ANTIQUE_WHITE: Color = Color::new_const(250.0, 235.0, 215.0, 1.0);
+/// `aqua`, #00ffff
+pub const AQUA: Color = Color::new_const(0.0, 255.0, 255.0, 1.0);
+/// `aquamarine`, #7fffd4
+pub const AQUAMARINE: Color = Color::new_const(127.0, 255.0, 212.0, 1.0);
+/// `azure`, #f0ffff
+pub const AZURE: Color = Color::new_const(240.0, 255.0, 255.0, 1.0);
+/// `beige`, #f5f5dc
+pub const BEIGE: Color = Color::new_const(245.0, 245.0, 220.0, 1.0);
+/// `bisque`, #ffe4c4
+pub const BISQUE: Color = Color::new_const(255.0, 228.0, 196.0, 1.0);
+/// `black`, #000000
+pub const BLACK: Color = Color::new_const(0.0, 0.0, 0.0, 1.0);
+/// `blanchedalmond`, #ffebcd
+pub const BLANCHED_ALMOND: Color = Color::new_const(255.0, 235.0, 205.0, 1.0);
+/// `blue`, #0000ff
+pub const BLUE: Color = Color::new_const(0.0, 0.0, 255.0, 1.0);
+/// `blueviolet`, #8a2be2
+pub const BLUE_VIOLET: Color = Color::new_const(138.0, 43.0, 226.0, 1.0);
+/// `brown`, #a52a2a
+pub const BROWN: Color = Color::new_const(165.0, 42.0, 42.0, 1.0);
+/// `burlywood`, #deb887
+pub const BURLY_WOOD: Color = Color::new_const(222.0, 184.0, 135.0, 1.0);
+/// `cadetblue`, #5f9ea0
+pub const CADET_BLUE: Color = Color::new_const(95.0, 158.0, 160.0, 1.0);
+/// `chartreuse`, #7fff00
+pub const CHARTREUSE: Color = Color::new_const(127.0, 255.0, 0.0, 1.0);
+/// `chocolate`, #d2691e
+pub const CHOCOLATE: Color = Color::new_const(210.0, 105.0, 30.0, 1.0);
+/// `coral`, #ff7f50
+pub const CORAL: Color = Color::new_const(255.0, 127.0, 80.0, 1.0);
+/// `cornflowerblue`, #6495ed
+pub const CORNFLOWER_BLUE: Color = Color::new_const(100.0, 149.0, 237.0, 1.0);
+/// `cornsilk`, #fff8dc
+pub const CORNSILK: Color = Color::new_const(255.0, 248.0, 220.0, 1.0);
+/// `crimson`, #dc143c
+pub const CRIMSON: Color = Color::new_const(220.0, 20.0, 60.0, 1.0);
+/// `cyan`, #00ffff
+pub const CYAN: Color = Color::new_const(0.0, 255.0, 255.0, 1.0);
+/// `darkblue`, #00008b
+pub const DARK_BLUE: Color = Color::new_const(0.0, 0.0, 139.0, 1.0);
+/// `darkcyan`, #008b8b
+pub const DARK_CYAN: Color = Color::new_const(0.0, 139.0, 139.0, 1.0);
+/// `darkgoldenrod`, #b8860b
+pub const DARK_GOLDENROD: Color = Color::new_const(184.0, 134.0, 11.0, 1.0);
+/// `darkgray`, #a9a9a9
+pub const DARK_GRAY: Color = Color::new_const(169.0, 169.0, 169.0, 1.0);
+/// `darkgreen`, #006400
+pub const DARK_GREEN: Color = Color::new_const(0.0, 100.0, 0.0, 1.0);
+/// `darkgrey`, #a9a9a9
+pub const DARK_GREY: Color = Color::new_const(169.0, 169.0, 169.0, 1.0);
+/// `darkkhaki`, #bdb76b
+pub const DARK_KHAKI: Color = Color::new_const(189.0, 183.0, 107.0, 1.0);
+/// `darkmagenta`, #8b008b
+pub const DARK_MAGENTA: Color = Color::new_const(139.0, 0.0, 139.0, 1.0);
+/// `darkolivegreen`, #556b2f
+pub const DARK_OLIVE_GREEN: Color = Color::new_const(85.0, 107.0, 47.0, 1.0);
+/// `darkorange`, #ff8c00
+pub const DARK_ORANGE: Color = Color::new_const(255.0, 140.0, 0.0, 1.0);
+/// `darkorchid`, #9932cc
+pub const DARK_ORCHID: Color = Color::new_const(153.0, 50.0, 204.0, 1.0);
+/// `darkred`, #8b0000
+pub const DARK_RED: Color = Color::new_const(139.0, 0.0, 0.0, 1.0);
+/// `darksalmon`, #e9967a
+pub const DARK_SALMON: Color = Color::new_const(233.0, 150.0, 122.0, 1.0);
+/// `darkseagreen`, #8fbc8f
+pub const DARK_SEA_GREEN: Color = Color::new_const(143.0, 188.0, 143.0, 1.0);
+/// `darkslateblue`, #483d8b
+pub const DARK_SLATE_BLUE: Color = Color::new_const(72.0, 61.0, 139.0, 1.0);
+/// `darkslategray`, #2f4f4f
+pub const DARK_SLATE_GRAY: Color = Color::new_const(47.0, 79.0, 79.0, 1.0);
+/// `darkslategrey`, #2f4f4f
+pub const DARK_SLATE_GREY: Color = Color::new_const(47.0, 79.0, 79.0, 1.0);
+/// `darkturquoise`, #00ced1
+pub const DARK_TURQUOISE: Color = Color::new_const(0.0, 206.0, 209.0, 1.0);
+/// `darkviolet`, #9400d3
+pub const DARK_VIOLET: Color = Color::new_const(148.0, 0.0, 211.0, 1.0);
+/// `deeppink`, #ff1493
+pub const DEEP_PINK: Color = Color::new_const(255.0, 20.0, 147.0, 1.0);
+/// `deepskyblue`, #00bfff
+pub const DEEP_SKY_BLUE: Color = Color::new_const(0.0, 191.0, 255.0, 1.0);
+/// `dimgray`, #696969
+pub const DIM_GRAY: Color = Color::new_const(105.0, 105.0, 105.0, 1.0);
+/// `dimgrey`, #696969
+pub const DIM_GREY: Color = Color::new_const(105.0, 105.0, 105.0, 1.0);
+/// `dodgerblue`, #1e90ff
+pub const DODGER_BLUE: Color = Color::new_const(30.0, 144.0, 255.0, 1.0);
+/// `firebrick`, #b22222
+pub const FIRE_BRICK: Color = Color::new_const(178.0, 34.0, 34.0, 1.0);
+/// `floralwhite`, #fffaf0
+pub const FLORAL_WHITE: Color = Color::new_const(255.0, 250.0, 240.0, 1.0);
+/// `forestgreen`, #228b22
+pub const FOREST_GREEN: Color = Color::new_const(34.0, 139.0, 34.0, 1.0);
+/// `fuchsia`, #ff00ff
+pub const FUCHSIA: Color = Color::new_const(255.0, 0.0, 255.0, 1.0);
+/// `gainsboro`, #dcdcdc
+pub const GAINSBORO: Color = Color::new_const(220.0, 220.0, 220.0, 1.0);
+/// `ghostwhite`, #f8f8ff
+pub const GHOST_WHITE: Color = Color::new_const(248.0, 248.0, 255.0, 1.0);
+/// `gold`, #ffd700
+pub const GOLD: Color = Color::new_const(255.0, 215.0, 0.0, 1.0);
+/// `goldenrod`, #daa520
+pub const GOLDENROD: Color = Color::new_const(218.0, 165.0, 32.0, 1.0);
+/// `gray`, #808080
+pub const GRAY: Color = Color::new_const(128.0, 128.0, 128.0, 1.0);
+/// `green`, #008000
+pub const GREEN: Color = Color::new_const(0.0, 128.0, 0.0, 1.0);
+/// `greenyellow`, #adff2f
+pub const GREEN_YELLOW: Color = Color::new_const(173.0, 255.0, 47.0, 1.0);
+/// `grey`, #808080
+pub const GREY: Color = Color::new_const(128.0, 128.0, 128.0, 1.0);
+/// `honeydew`, #f0fff0
+pub const HONEYDEW: Color = Color::new_const(240.0, 255.0, 240.0, 1.0);
+/// `hotpink`, #ff69b4
+pub const HOT_PINK: Color = Color::new_const(255.0, 105.0, 180.0, 1.0);
+/// `indianred`, #cd5c5c
+pub const INDIAN_RED: Color = Color::new_const(205.0, 92.0, 92.0, 1.0);
+/// `indigo`, #4b0082
+pub const INDIGO: Color = Color::new_const(75.0, 0.0, 130.0, 1.0);
+/// `ivory`, #fffff0
+pub const IVORY: Color = Color::new_const(255.0, 255.0, 240.0, 1.0);
+/// `khaki`, #f0e68c
+pub const KHAKI: Color = Color::new_const(240.0, 230.0, 140.0, 1.0);
+/// `laserlemon`, #ffff54
+pub const LASER_LEMON: Color = Color::new_const(255.0, 255.0, 84.0, 1.0);
+/// `lavender`, #e6e6fa
+pub const LAVENDER: Color = Color::new_const(230.0, 230.0, 250.0, 1.0);
+/// `lavenderblush`, #fff0f5
+pub const LAVENDER_BLUSH: Color = Color::new_const(255.0, 240.0, 245.0, 1.0);
+/// `lawngreen`, #7cfc00
+pub const LAWN_GREEN: Color = Color::new_const(124.0, 252.0, 0.0, 1.0);
+/// `lemonchiffon`, #fffacd
+pub const LEMON_CHIFFON: Color = Color::new_const(255.0, 250.0, 205.0, 1.0);
+/// `lightblue`, #add8e6
+pub const LIGHT_BLUE: Color = Color::new_const(173.0, 216.0, 230.0, 1.0);
+/// `lightcoral`, #f08080
+pub const LIGHT_CORAL: Color = Color::new_const(240.0, 128.0, 128.0, 1.0);
+/// `lightcyan`, #e0ffff
+pub const LIGHT_CYAN: Color = Color::new_const(224.0, 255.0, 255.0, 1.0);
+/// `lightgoldenrod`, #fafad2
+pub const LIGHT_GOLDENROD: Color = Color::new_const(250.0, 250.0, 210.0, 1.0);
+/// `lightgoldenrodyellow`, #fafad2
+pub const LIGHT_GOLDENROD_YELLOW: Color = Color::new_const(250.0, 250.0, 210.0, 1.0);
+/// `lightgray`, #d3d3d3
+pub const LIGHT_GRAY: Color = Color::new_const(211.0, 211.0, 211.0, 1.0);
+/// `lightgreen`, #90ee90
+pub const LIGHT_GREEN: Color = Color::new_const(144.0, 238.0, 144.0, 1.0);
+/// `lightgrey`, #d3d3d3
+pub const LIGHT_GREY: Color = Color::new_const(211.0, 211.0, 211.0, 1.0);
+/// `lightpink`, #ffb6c1
+pub const LIGHT_PINK: Color = Color::new_const(255.0, 182.0, 193.0, 1.0);
+/// `lightsalmon`, #ffa07a
+pub const LIGHT_SALMON: Color = Color::new_const(255.0, 160.0, 122.0, 1.0);
+/// `lightseagreen`, #20b2aa
+pub const LIGHT_SEA_GREEN: Color = Color::new_const(32.0, 178.0, 170.0, 1.0);
+/// `lightskyblue`, #87cefa
+pub const LIGHT_SKY_BLUE: Color = Color::new_const(135.0, 206.0, 250.0, 1.0);
+/// `lightslategray`, #778899
+pub const LIGHT_SLATE_GRAY: Color = Color::new_const(119.0, 136.0, 153.0, 1.0);
+/// `lightslategrey`, #778899
+pub const LIGHT_SLATE_GREY: Color = Color::new_const(119.0, 136.0, 153.0, 1.0);
+/// `lightsteelblue`, #b0c4de
+pub const LIGHT_STEEL_BLUE: Color = Color::new_const(176.0, 196.0, 222.0, 1.0);
+/// `lightyellow`, #ffffe0
+pub const LIGHT_YELLOW: Color = Color::new_const(255.0, 255.0, 224.0, 1.0);
+/// `lime`, #00ff00
+pub const LIME: Color = Color::new_const(0.0, 255.0, 0.0, 1.0);
+/// `limegreen`, #32cd32
+pub const LIME_GREEN: Color = Color::new_const(50.0, 205.0, 50.0, 1.0);
+/// `linen`, #faf0e6
+pub const LINEN: Color = Color::new_const(250.0, 240.0, 230.0, 1.0);
+/// `magenta`, #ff00ff
+pub const MAGENTA: Color = Color::new_const(255.0, 0.0, 255.0, 1.0);
+/// `maroon`, #800000
+pub const MAROON: Color = Color::new_const(128.0, 0.0, 0.0, 1.0);
+/// `maroon2`, #7f0000
+pub const MAROON2: Color = Color::new_const(127.0, 0.0, 0.0, 1.0);
+/// `maroon3`, #b03060
+pub const MAROON3: Color = Color::new_const(176.0, 48.0, 96.0, 1.0);
+/// `mediumaquamarine`, #66cdaa
+pub const MEDIUM_AQUAMARINE: Color = Color::new_const(102.0, 205.0, 170.0, 1.0);
+/// `mediumblue`, #0000cd
+pub const MEDIUM_BLUE: Color = Color::new_const(0.0, 0.0, 205.0, 1.0);
+/// `mediumorchid`, #ba55d3
+pub const MEDIUM_ORCHID: Color = Color::new_const(186.0, 85.0, 211.0, 1.0);
+/// `mediumpurple`, #9370db
+pub const MEDIUM_PURPLE: Color = Color::new_const(147.0, 112.0, 219.0, 1.0);
+/// `mediumseagreen`, #3cb371
+pub const MEDIUM_SEA_GREEN: Color = Color::new_const(60.0, 179.0, 113.0, 1.0);
+/// `mediumslateblue`, #7b68ee
+pub const MEDIUM_SLATE_BLUE: Color = Color::new_const(123.0, 104.0, 238.0, 1.0);
+/// `mediumspringgreen`, #00fa9a
+pub const MEDIUM_SPRING_GREEN: Color = Color::new_const(0.0, 250.0, 154.0, 1.0);
+/// `mediumturquoise`, #48d1cc
+pub const MEDIUM_TURQUOISE: Color = Color::new_const(72.0, 209.0, 204.0, 1.0);
+/// `mediumvioletred`, #c71585
+pub const MEDIUM_VIOLET_RED: Color = Color::new_const(199.0, 21.0, 133.0, 1.0);
+/// `midnightblue`, #191970
+pub const MIDNIGHT_BLUE: Color = Color::new_const(25.0, 25.0, 112.0, 1.0);
+/// `mintcream`, #f5fffa
+pub const MINT_CREAM: Color = Color::new_const(245.0, 255.0, 250.0, 1.0);
+/// `mistyrose`, #ffe4e1
+pub const MISTY_ROSE: Color = Color::new_const(255.0, 228.0, 225.0, 1.0);
+/// `moccasin`, #ffe4b5
+pub const MOCCASIN: Color = Color::new_const(255.0, 228.0, 181.0, 1.0);
+/// `navajowhite`, #ffdead
+pub const NAVAJO_WHITE: Color = Color::new_const(255.0, 222.0, 173.0, 1.0);
+/// `navy`, #000080
+pub const NAVY: Color = Color::new_const(0.0, 0.0, 128.0, 1.0);
+/// `oldlace`, #fdf5e6
+pub const OLD_LACE: Color = Color::new_const(253.0, 245.0, 230.0, 1.0);
+/// `olive`, #808000
+pub const OLIVE: Color = Color::new_const(128.0, 128.0, 0.0, 1.0);
+/// `olivedrab`, #6b8e23
+pub const OLIVE_DRAB: Color = Color::new_const(107.0, 142.0, 35.0, 1.0);
+/// `orange`, #ffa500
+pub const ORANGE: Color = Color::new_const(255.0, 165.0, 0.0, 1.0);
+/// `orangered`, #ff4500
+pub const ORANGE_RED: Color = Color::new_const(255.0, 69.0, 0.0, 1.0);
+/// `orchid`, #da70d6
+pub const ORCHID: Color = Color::new_const(218.0, 112.0, 214.0, 1.0);
+/// `palegoldenrod`, #eee8aa
+pub const PALE_GOLDENROD: Color = Color::new_const(238.0, 232.0, 170.0, 1.0);
+/// `palegreen`, #98fb98
+pub const PALE_GREEN: Color = Color::new_const(152.0, 251.0, 152.0, 1.0);
+/// `paleturquoise`, #afeeee
+pub const PALE_TURQUOISE: Color = Color::new_const(175.0, 238.0, 238.0, 1.0);
+/// `palevioletred`, #db7093
+pub const PALE_VIOLET_RED: Color = Color::new_const(219.0, 112.0, 147.0, 1.0);
+/// `papayawhip`, #ffefd5
+pub const PAPAYA_WHIP: Color = Color::new_const(255.0, 239.0, 213.0, 1.0);
+/// `peachpuff`, #ffdab9
+pub const PEACH_PUFF: Color = Color::new_const(255.0, 218.0, 185.0, 1.0);
+/// `peru`, #cd853f
+pub const PERU: Color = Color::new_const(205.0, 133.0, 63.0, 1.0);
+/// `pink`, #ffc0cb
+pub const PINK: Color = Color::new_const(255.0, 192.0, 203.0, 1.0);
+/// `plum`, #dda0dd
+pub const PLUM: Color = Color::new_const(221.0, 160.0, 221.0, 1.0);
+/// `powderblue`, #b0e0e6
+pub const POWDER_BLUE: Color = Color::new_const(176.0, 224.0, 230.0, 1.0);
+/// `purple`, #800080
+pub const PURPLE: Color = Color::new_const(128.0, 0.0, 128.0, 1.0);
+/// `purple2`, #7f007f
+pub const PURPLE2: Color = Color::new_const(127.0, 0.0, 127.0, 1.0);
+/// `purple3`, #a020f0
+pub const PURPLE3: Color = Color::new_const(160.0, 32.0, 240.0, 1.0);
+/// `rebeccapurple`, #663399
+pub const REBECCA_PURPLE: Color = Color::new_const(102.0, 51.0, 153.0, 1.0);
+/// `red`, #ff0000
+pub const RED: Color = Color::new_const(255.0, 0.0, 0.0, 1.0);
+/// `rosybrown`, #bc8f8f
+pub const ROSY_BROWN: Color = Color::new_const(188.0, 143.0, 143.0, 1.0);
+/// `royalblue`, #4169e1
+pub const ROYAL_BLUE: Color = Color::new_const(65.0, 105.0, 225.0, 1.0);
+/// `saddlebrown`, #8b4513
+pub const SADDLE_BROWN: Color = Color::new_const(139.0, 69.0, 19.0, 1.0);
+/// `salmon`, #fa8072
+pub const SALMON: Color = Color::new_const(250.0, 128.0, 114.0, 1.0);
+/// `sandybrown`, #f4a460
+pub const SANDY_BROWN: Color = Color::new_const(244.0, 164.0, 96.0, 1.0);
+/// `seagreen`, #2e8b57
+pub const SEA_GREEN: Color = Color::new_const(46.0, 139.0, 87.0, 1.0);
+/// `seashell`, #fff5ee
+pub const SEASHELL: Color = Color::new_const(255.0, 245.0, 238.0, 1.0);
+/// `sienna`, #a0522d
+pub const SIENNA: Color = Color::new_const(160.0, 82.0, 45.0, 1.0);
+/// `silver`, #c0c0c0
+pub const SILVER: Color = Color::new_const(192.0, 192.0, 192.0, 1.0);
+/// `skyblue`, #87ceeb
+pub const SKY_BLUE: Color = Color::new_const(135.0, 206.0, 235.0, 1.0);
+/// `slateblue`, #6a5acd
+pub const SLATE_BLUE: Color = Color::new_const(106.0, 90.0, 205.0, 1.0);
+/// `slategray`, #708090
+pub const SLATE_GRAY: Color = Color::new_const(112.0, 128.0, 144.0, 1.0);
+/// `slategrey`, #708090
+pub const SLATE_GREY: Color = Color::new_const(112.0, 128.0, 144.0, 1.0);
+/// `snow`, #fffafa
+pub const SNOW: Color = Color::new_const(255.0, 250.0, 250.0, 1.0);
+/// `springgreen`, #00ff7f
+pub const SPRING_GREEN: Color = Color::new_const(0.0, 255.0, 127.0, 1.0);
+/// `steelblue`, #4682b4
+pub const STEEL_BLUE: Color = Color::new_const(70.0, 130.0, 180.0, 1.0);
+/// `tan`, #d2b48c
+pub const TAN: Color = Color::new_const(210.0, 180.0, 140.0, 1.0);
+/// `teal`, #008080
+pub const TEAL: Color = Color::new_const(0.0, 128.0, 128.0, 1.0);
+/// `thistle`, #d8bfd8
+pub const THISTLE: Color = Color::new_const(216.0, 191.0, 216.0, 1.0);
+/// `tomato`, #ff6347
+pub const TOMATO: Color = Color::new_const(255.0, 99.0, 71.0, 1.0);
+/// `turquoise`, #40e0d0
+pub const TURQUOISE: Color = Color::new_const(64.0, 224.0, 208.0, 1.0);
+/// `violet`, #ee82ee
+pub const VIOLET: Color = Color::new_const(238.0, 130.0, 238.0, 1.0);
+/// `wheat`, #f5deb3
+pub const WHEAT: Color = Color::new_const(245.0, 222.0, 179.0, 1.0);
+/// `white`, #ffffff
+pub const WHITE: Color = Color::new_const(255.0, 255.0, 255.0, 1.0);
+/// `whitesmoke`, #f5f5f5
+pub const WHITE_SMOKE: Color = Color::new_const(245.0, 245.0, 245.0, 1.0);
+/// `yellow`, #ffff00
+pub const YELLOW: Color = Color::new_const(255.0, 255.0, 0.0, 1.0);
+/// `yellowgreen`, #9acd32
+pub const YELLOW_GREEN: Color = Color::new_const(154.0, 205.0, 50.0, 1.0);
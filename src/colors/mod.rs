@@ -0,0 +1,89 @@
+//! Named colors as constants.
+//!
+//! The same colors [`Color::from_name`](crate::Color::from_name) looks up, available in
+//! const contexts and without `alloc`.
+//!
+//! # Examples
+//!
+//! ```
+//! use color_art::{colors, Color};
+//!
+//! const PRIMARY: Color = colors::css::DEEP_PINK;
+//! assert_eq!(PRIMARY.hex(), "#ff1493");
+//! assert_eq!(colors::chinese::SHUI_LV, Color::from_name("水绿").unwrap());
+//! ```
+
+pub mod chinese;
+pub mod css;
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{chinese_color::CHINESE_COLOR, w3cx11::W3CX11},
+        Color,
+    };
+    use alloc::vec::Vec;
+
+    /// The documented name and hex and the `new_const` values of every constant in `source`.
+    fn constants(source: &str) -> Vec<(&str, &str, Vec<f64>)> {
+        let lines = source.lines().collect::<Vec<_>>();
+        lines
+            .windows(2)
+            .filter_map(|pair| {
+                let (name, hex) = pair[0].strip_prefix("/// ")?.split_once(", ")?;
+                let (_, args) = pair[1]
+                    .strip_prefix("pub const ")?
+                    .split_once("new_const(")?;
+                let values = args
+                    .trim_end_matches(");")
+                    .split(", ")
+                    .map(|v| v.parse().unwrap())
+                    .collect();
+                Some((name.trim_matches('`'), hex, values))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_constants_match_data() {
+        let modules = [
+            (include_str!("css.rs"), W3CX11),
+            (include_str!("chinese.rs"), CHINESE_COLOR),
+        ];
+        for (source, data) in modules {
+            let constants = constants(source);
+            assert_eq!(constants.len(), data.len());
+            for (&(name, hex), (doc_name, doc_hex, values)) in data.iter().zip(constants) {
+                assert_eq!((doc_name, doc_hex), (name, hex));
+                let [r, g, b, alpha] = values[..] else {
+                    panic!("{} has {} values", name, values.len())
+                };
+                let color = Color::new(r, g, b, alpha);
+                assert_eq!(color, Color::from_hex(hex).unwrap(), "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_css_colors() {
+        assert_eq!(css::DEEP_PINK, Color::from_name("deeppink").unwrap());
+        assert_eq!(
+            css::REBECCA_PURPLE,
+            Color::from_name("rebeccapurple").unwrap()
+        );
+        assert_eq!(
+            css::LIGHT_GOLDENROD_YELLOW,
+            Color::from_name("lightgoldenrodyellow").unwrap()
+        );
+        assert_eq!(css::MAROON2.hex(), "#7f0000");
+    }
+
+    #[test]
+    fn test_chinese_colors() {
+        assert_eq!(chinese::SHUI_LV, Color::from_name("水绿").unwrap());
+        assert_eq!(chinese::FO_SHOU_HUANG, Color::from_name("佛手黄").unwrap());
+        assert_eq!(chinese::YOU_HUANG, Color::from_name("鼬黄").unwrap());
+        assert_eq!(chinese::YOU_HUANG_2, Color::from_name("柚黄").unwrap());
+    }
+}
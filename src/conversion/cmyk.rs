@@ -1,32 +1,307 @@
 use crate::utils::*;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Black generation curve used by a CMYK separation.
+///
+/// The curve maps the gray component of a color (the amount of ink that
+/// cyan, magenta and yellow have in common, `0.0..=1.0`) to the amount of
+/// black ink that should replace it.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BlackGeneration {
+    /// No black ink is generated, grays are built from CMY only.
+    None,
+    /// Black starts in the three-quarter tones and ramps up slowly.
+    Light,
+    /// Black starts in the midtones.
+    Medium,
+    /// Black starts in the quarter tones and follows the gray component closely.
+    Heavy,
+    /// All of the gray component is printed with black ink.
+    Maximum,
+    /// Custom curve `((gray - start) / (1 - start))^gamma`.
+    ///
+    /// `start` is the gray level below which no black is generated,
+    /// `gamma` bends the curve (values above 1.0 hold black back).
+    Custom { start: f64, gamma: f64 },
+}
+
+impl BlackGeneration {
+    fn curve(&self) -> (f64, f64) {
+        match *self {
+            BlackGeneration::None => (1.0, 1.0),
+            BlackGeneration::Light => (0.5, 2.0),
+            BlackGeneration::Medium => (0.25, 1.5),
+            BlackGeneration::Heavy => (0.1, 1.0),
+            BlackGeneration::Maximum => (0.0, 1.0),
+            BlackGeneration::Custom { start, gamma } => (start, gamma),
+        }
+    }
+    /// Amount of black ink generated for a gray component.
+    pub(crate) fn black_of(&self, gray: f64) -> f64 {
+        let (start, gamma) = self.curve();
+        if gray <= start || start >= 1.0 {
+            return 0.0;
+        }
+        ((gray - start) / (1.0 - start)).powf(gamma).clamp(0.0, 1.0)
+    }
+}
+
+/// How the generated black replaces the colored inks.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SeparationMode {
+    /// Gray component replacement, black replaces the gray component of every color.
+    Gcr,
+    /// Under color removal, black only replaces CMY in neutral and near-neutral colors.
+    Ucr,
+}
+
+/// Options of a print-oriented CMYK separation.
+///
+/// The default options reproduce the plain `1 - max(rgb)` split of [`Color::cmyk`](crate::Color::cmyk),
+/// without any ink limit.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{color, BlackGeneration, SeparationOptions};
+///
+/// let options = SeparationOptions {
+///     black_generation: BlackGeneration::Medium,
+///     total_ink_limit: 3.0,
+///     ..Default::default()
+/// };
+///
+/// let cmyk = color!(#1a1a1a).unwrap().to_cmyk_with(&options);
+/// assert!(cmyk.iter().sum::<f64>() <= 3.0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SeparationOptions {
+    /// The black generation curve.
+    pub black_generation: BlackGeneration,
+    /// Whether black replaces the gray component everywhere (GCR) or only in neutrals (UCR).
+    pub mode: SeparationMode,
+    /// Strength of the replacement, between 0.0 (no black) and 1.0 (the full curve).
+    pub strength: f64,
+    /// Total area coverage limit, the maximum sum of the four inks (e.g. `3.0` for 300%).
+    pub total_ink_limit: f64,
+    /// Maximum amount of black ink, between 0.0 and 1.0.
+    pub black_ink_limit: f64,
+    /// Ink recipe used for pure black instead of a single black ink, e.g. `[0.6, 0.4, 0.4, 1.0]`.
+    pub rich_black: Option<[f64; 4]>,
+}
+
+impl Default for SeparationOptions {
+    fn default() -> Self {
+        SeparationOptions {
+            black_generation: BlackGeneration::Maximum,
+            mode: SeparationMode::Gcr,
+            strength: 1.0,
+            total_ink_limit: 4.0,
+            black_ink_limit: 1.0,
+            rich_black: None,
+        }
+    }
+}
+
+/// colors with every channel below this value (in 0..1) are treated as pure black
+const RICH_BLACK_THRESHOLD: f64 = 0.5 / 255.0;
+
 /// [RGB to CMYK color conversion](https://www.rapidtables.com/convert/color/rgb-to-cmyk.html)
-pub fn rgb2cmyk(color: &[f64]) -> Vec<f64> {
+///
+/// The separation itself is always computed in `f64`.
+pub fn rgb2cmyk<T: Float>(color: [T; 3]) -> [T; 4] {
+    rgb2cmyk_with(color.map(T::to_f64), &SeparationOptions::default()).map(T::from_f64)
+}
+
+/// [CMYK to RGB color conversion](https://www.rapidtables.com/convert/color/cmyk-to-rgb.html)
+pub fn cmyk2rgb<T: Float>(color: [T; 4]) -> [T; 3] {
+    let [c, m, y, k] = color;
+    let white = (T::ONE - k) * lit(255.0);
+
+    [
+        (T::ONE - c) * white,
+        (T::ONE - m) * white,
+        (T::ONE - y) * white,
+    ]
+}
+
+/// Convert RGB to CMYK with black generation, GCR/UCR and ink limits.
+pub fn rgb2cmyk_with(color: [f64; 3], options: &SeparationOptions) -> [f64; 4] {
     let color = normalize_color(color);
-    let r = color[0];
-    let g = color[1];
-    let b = color[2];
+    let r = color[0].clamp(0.0, 1.0);
+    let g = color[1].clamp(0.0, 1.0);
+    let b = color[2].clamp(0.0, 1.0);
+
+    if let Some(rich_black) = options.rich_black {
+        if r.max(g).max(b) < RICH_BLACK_THRESHOLD {
+            return rich_black;
+        }
+    }
+
+    let cmy = [1.0 - r, 1.0 - g, 1.0 - b];
+    let gray = cmy[0].min(cmy[1]).min(cmy[2]);
+    let chroma = cmy[0].max(cmy[1]).max(cmy[2]) - gray;
+
+    let neutrality = match options.mode {
+        SeparationMode::Gcr => 1.0,
+        SeparationMode::Ucr => 1.0 - chroma,
+    };
+    let strength = options.strength.clamp(0.0, 1.0);
+    let k_max = gray.min(options.black_ink_limit.clamp(0.0, 1.0));
+    let mut k = (options.black_generation.black_of(gray) * strength * neutrality).min(k_max);
 
-    let rgb_max = f64::max(f64::max(r, g), b);
+    let ink = |k: f64| k + remove_under_color(cmy, k).iter().sum::<f64>();
 
-    let k = 1.0 - rgb_max;
-    let c = (1.0 - r - k) * (1.0 - k);
-    let m = (1.0 - g - k) * (1.0 - k);
-    let y = (1.0 - b - k) * (1.0 - k);
+    let limit = options.total_ink_limit.max(0.0);
+    if ink(k) > limit {
+        if ink(k_max) <= limit {
+            // more black means less ink in the shadows, find the least black that fits
+            let (mut lo, mut hi) = (k, k_max);
+            for _ in 0..50 {
+                let mid = (lo + hi) / 2.0;
+                if ink(mid) > limit {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            k = hi;
+        } else {
+            k = k_max;
+        }
+    }
 
-    vec![c, m, y, k]
+    // even the darkest separation is over the limit, scale the colored inks down
+    let mut cmy = remove_under_color(cmy, k);
+    let k = k.min(limit);
+    let cmy_sum: f64 = cmy.iter().sum();
+    if k + cmy_sum > limit && cmy_sum > 0.0 {
+        let factor = (limit - k) / cmy_sum;
+        cmy.iter_mut().for_each(|v| *v *= factor);
+    }
+
+    [cmy[0], cmy[1], cmy[2], k]
 }
 
-/// [CMYK to RGB color conversion](https://www.rapidtables.com/convert/color/cmyk-to-rgb.html)
-pub fn cmyk2rgb(color: &[f64]) -> Vec<f64> {
-    let c = color[0];
-    let m = color[1];
-    let y = color[2];
-    let k = color[3];
+/// Convert CMYK produced by [`rgb2cmyk_with`] back to RGB.
+///
+/// The rich black recipe of the options maps back to pure black.
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+pub fn cmyk2rgb_with(color: [f64; 4], options: &SeparationOptions) -> [f64; 3] {
+    if let Some(rich_black) = options.rich_black {
+        let is_rich_black = rich_black
+            .iter()
+            .zip(color.iter())
+            .all(|(a, b)| (a - b).abs() < 1e-6);
+        if is_rich_black {
+            return [0.0, 0.0, 0.0];
+        }
+    }
+    cmyk2rgb(color)
+}
+
+/// Remove `k` of black from the CMY inks so that `(1 - c') * (1 - k) == 1 - c`.
+fn remove_under_color(cmy: [f64; 3], k: f64) -> [f64; 3] {
+    if k >= 1.0 {
+        [0.0, 0.0, 0.0]
+    } else {
+        cmy.map(|v| ((v - k) / (1.0 - k)).clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_vec<const N: usize>(vec: [f64; N]) -> [f64; N] {
+        vec.map(|v| round(v, 4))
+    }
+
+    #[test]
+    fn test_rgb2cmyk() {
+        assert_eq!(rgb2cmyk([255.0, 51.0, 153.0]), [0.0, 0.8, 0.4, 0.0]);
+        assert_eq!(rgb2cmyk([0.0, 0.0, 0.0]), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(
+            round_vec(rgb2cmyk([127.5, 63.75, 0.0])),
+            [0.0, 0.5, 1.0, 0.5]
+        );
+    }
+
+    #[test]
+    fn test_rgb2cmyk_with_no_black() {
+        let options = SeparationOptions {
+            black_generation: BlackGeneration::None,
+            ..Default::default()
+        };
+        assert_eq!(
+            round_vec(rgb2cmyk_with([127.5, 127.5, 127.5], &options)),
+            [0.5, 0.5, 0.5, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_rgb2cmyk_with_ucr() {
+        let options = SeparationOptions {
+            mode: SeparationMode::Ucr,
+            ..Default::default()
+        };
+        // neutral colors get the full black
+        assert_eq!(
+            round_vec(rgb2cmyk_with([127.5, 127.5, 127.5], &options)),
+            [0.0, 0.0, 0.0, 0.5]
+        );
+        // saturated colors get less black than with GCR
+        let cmyk = rgb2cmyk_with([127.5, 0.0, 0.0], &options);
+        assert_eq!(round_vec(cmyk), [0.3333, 1.0, 1.0, 0.25]);
+    }
+
+    #[test]
+    fn test_rgb2cmyk_with_ink_limit() {
+        let options = SeparationOptions {
+            black_generation: BlackGeneration::None,
+            total_ink_limit: 2.4,
+            ..Default::default()
+        };
+        let cmyk = rgb2cmyk_with([10.0, 10.0, 10.0], &options);
+        assert!(cmyk.iter().sum::<f64>() <= 2.4 + 1e-9);
+
+        let options = SeparationOptions {
+            black_generation: BlackGeneration::Light,
+            total_ink_limit: 3.0,
+            ..Default::default()
+        };
+        let cmyk = rgb2cmyk_with([20.0, 10.0, 5.0], &options);
+        assert!(cmyk.iter().sum::<f64>() <= 3.0 + 1e-9);
+        // the ink limit is reached by adding black, so the color is unchanged
+        assert_eq!(round_vec(cmyk2rgb(cmyk)), [20.0, 10.0, 5.0]);
+    }
 
-    let r = (1.0 - c) * (1.0 - k) * 255.0;
-    let g = (1.0 - m) * (1.0 - k) * 255.0;
-    let b = (1.0 - y) * (1.0 - k) * 255.0;
+    #[test]
+    fn test_rich_black() {
+        let options = SeparationOptions {
+            rich_black: Some([0.6, 0.4, 0.4, 1.0]),
+            ..Default::default()
+        };
+        assert_eq!(
+            rgb2cmyk_with([0.0, 0.0, 0.0], &options),
+            [0.6, 0.4, 0.4, 1.0]
+        );
+        assert_eq!(
+            cmyk2rgb_with([0.6, 0.4, 0.4, 1.0], &options),
+            [0.0, 0.0, 0.0]
+        );
+    }
 
-    vec![r, g, b]
+    #[test]
+    fn test_black_generation_curve() {
+        assert_eq!(BlackGeneration::None.black_of(1.0), 0.0);
+        assert_eq!(BlackGeneration::Maximum.black_of(0.3), 0.3);
+        assert_eq!(BlackGeneration::Light.black_of(0.4), 0.0);
+        assert_eq!(BlackGeneration::Light.black_of(0.75), 0.25);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::utils::*;
+use alloc::{format, string::String};
 
 pub fn rgb2hex(color: [f64; 3]) -> String {
     let [r, g, b] = color;
@@ -12,48 +13,65 @@ pub fn rgba2hex(color: [f64; 4]) -> String {
     let [r, g, b, a] = color;
 
     let hex = rgb2hex([r, g, b]);
-    let alpha = round(a * 255.0, 0);
+    let alpha = round(a.clamp(0.0, 1.0) * 255.0, 0) as u8;
 
-    format!("{}{:02x}", hex, alpha as i64)
+    format!("{}{:02x}", hex, alpha)
 }
 
-pub fn hex2rgb(hex: &str) -> Vec<f64> {
-    let mut hex = String::from(hex);
-    // #rgb -> #rrggbb
-    if hex.len() == 4 {
-        let mut hex_new = String::from("#");
-        for c in hex[1..].chars() {
-            hex_new.push(c);
-            hex_new.push(c);
+/// Reads the hex digits of `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` as 0..255 channels.
+///
+/// The string is expected to be a valid hex color, missing channels are 255.
+fn hex_channels(hex: &str) -> [u8; 4] {
+    let digits = hex.trim_start_matches('#').as_bytes();
+    let digit = |i: usize| (digits[i] as char).to_digit(16).unwrap() as u8;
+
+    let mut channels = [255; 4];
+    match digits.len() {
+        3 | 4 => {
+            for (i, channel) in channels.iter_mut().take(digits.len()).enumerate() {
+                *channel = digit(i) * 17;
+            }
+        }
+        _ => {
+            for (i, channel) in channels.iter_mut().take(digits.len() / 2).enumerate() {
+                *channel = digit(i * 2) * 16 + digit(i * 2 + 1);
+            }
         }
-        hex = hex_new;
     }
+    channels
+}
 
-    let r = u8::from_str_radix(&hex[1..3], 16).unwrap();
-    let g = u8::from_str_radix(&hex[3..5], 16).unwrap();
-    let b = u8::from_str_radix(&hex[5..7], 16).unwrap();
-
-    vec![r as f64, g as f64, b as f64]
+/// Reads the X11 `#rrrgggbbb` or `#rrrrggggbbbb` as 0..255 channels, or `None` for the other
+/// lengths.
+///
+/// The digits are the high bits of 16 bit channels like in `XParseColor`, `#800800800` is
+/// `#800080008000`.
+fn x11_hex_channels(hex: &str) -> Option<[f64; 3]> {
+    let digits = hex.trim_start_matches('#');
+    let len = match digits.len() {
+        9 | 12 => digits.len() / 3,
+        _ => return None,
+    };
+    Some([0, 1, 2].map(|i| {
+        let value = u32::from_str_radix(&digits[i * len..(i + 1) * len], 16).unwrap();
+        (value << (16 - 4 * len)) as f64 / 65535.0 * 255.0
+    }))
 }
 
-pub fn hex2rgba(hex: &str) -> Vec<f64> {
-    let mut hex = String::from(hex);
-    // #rgba -> #rrggbbaa
-    if hex.len() == 5 {
-        let mut hex_new = String::from("#");
-        for c in hex[1..].chars() {
-            hex_new.push(c);
-            hex_new.push(c);
-        }
-        hex = hex_new;
+pub fn hex2rgb(hex: &str) -> [f64; 3] {
+    if let Some(rgb) = x11_hex_channels(hex) {
+        return rgb;
     }
+    let [r, g, b, _] = hex_channels(hex);
+    [r as f64, g as f64, b as f64]
+}
 
-    let r = u8::from_str_radix(&hex[1..3], 16).unwrap();
-    let g = u8::from_str_radix(&hex[3..5], 16).unwrap();
-    let b = u8::from_str_radix(&hex[5..7], 16).unwrap();
-    let a = u8::from_str_radix(&hex[7..9], 16).unwrap();
-
-    vec![r as f64, g as f64, b as f64, (a as f64) / 255.0]
+pub fn hex2rgba(hex: &str) -> [f64; 4] {
+    if let Some([r, g, b]) = x11_hex_channels(hex) {
+        return [r, g, b, 1.0];
+    }
+    let [r, g, b, a] = hex_channels(hex);
+    [r as f64, g as f64, b as f64, (a as f64) / 255.0]
 }
 
 #[cfg(test)]
@@ -64,11 +82,38 @@ mod tests {
     fn test_hex2rgb() {
         let hex = "#ffffff";
         let rgb = hex2rgb(hex);
-        assert_eq!(rgb, vec![255.0, 255.0, 255.0]);
+        assert_eq!(rgb, [255.0, 255.0, 255.0]);
 
         let hex = "#000";
         let rgb = hex2rgb(hex);
-        assert_eq!(rgb, vec![0.0, 0.0, 0.0]);
+        assert_eq!(rgb, [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_hex2rgba() {
+        assert_eq!(hex2rgba("#ff339980"), [255.0, 51.0, 153.0, 128.0 / 255.0]);
+        assert_eq!(hex2rgba("#f398"), [255.0, 51.0, 153.0, 136.0 / 255.0]);
+        assert_eq!(hex2rgba("#f39"), [255.0, 51.0, 153.0, 1.0]);
+        assert_eq!(hex2rgba("#ffff00008080"), [255.0, 0.0, 128.0, 1.0]);
+    }
+
+    #[test]
+    fn test_rgba2hex_clamps_alpha() {
+        assert_eq!(rgba2hex([255.0, 0.0, 0.0, 1.6]), "#ff0000ff");
+        assert_eq!(rgba2hex([255.0, 0.0, 0.0, -0.5]), "#ff000000");
+    }
+
+    #[test]
+    fn test_x11_hex2rgb() {
+        assert_eq!(hex2rgb("#ffff00000000"), [255.0, 0.0, 0.0]);
+        assert_eq!(
+            hex2rgb("#800000000").map(|v| round(v, 4)),
+            [127.5019, 0.0, 0.0]
+        );
+        assert_eq!(
+            hex2rgb("#fff000000").map(|v| round(v, 2)),
+            [254.94, 0.0, 0.0]
+        );
     }
 
     #[test]
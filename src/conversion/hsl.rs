@@ -1,61 +1,71 @@
 use crate::utils::*;
 
-/// [HSL to RGB color conversion](https://www.rapidtables.com/convert/color/hsl-to-rgb.html)
-pub fn hsl2rgb(color: &[f64]) -> Vec<f64> {
-    let h = color[0];
-    let s = color[1];
-    let l = color[2];
-
-    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
-    let x = c * (1.0 - (((h / 60.0) % 2.0) - 1.0).abs());
-    let m = l - c / 2.0;
-
-    let rgb = match h {
-        h if (0.0..60.0).contains(&h) => vec![c, x, 0.0],
-        h if (60.0..120.0).contains(&h) => vec![x, c, 0.0],
-        h if (120.0..180.0).contains(&h) => vec![0.0, c, x],
-        h if (180.0..240.0).contains(&h) => vec![0.0, x, c],
-        h if (240.0..300.0).contains(&h) => vec![x, 0.0, c],
-        h if (300.0..360.0).contains(&h) => vec![c, 0.0, x],
-        _ => panic!(),
+/// Picks the RGB channels of a hue sector, shared by the HSL and HSV conversions.
+///
+/// The hue is expected in `0..360`, anything else ends up in the last sector.
+pub(crate) fn hue_sector<T: Float>(h: T, c: T, x: T) -> [T; 3] {
+    let zero = T::ZERO;
+    match h.to_f64() {
+        h if (0.0..60.0).contains(&h) => [c, x, zero],
+        h if (60.0..120.0).contains(&h) => [x, c, zero],
+        h if (120.0..180.0).contains(&h) => [zero, c, x],
+        h if (180.0..240.0).contains(&h) => [zero, x, c],
+        h if (240.0..300.0).contains(&h) => [x, zero, c],
+        // also the rounding of `rem_euclid` up to 360 and NaN
+        _ => [c, zero, x],
+    }
+}
+
+/// Hue of the RGB values in 0..1 with the given max channel and chroma.
+pub(crate) fn hue_of<T: Float>([r, g, b]: [T; 3], max: T, delta: T) -> T {
+    let mut h = if max == r {
+        lit::<T>(60.0) * (((g - b) / delta) % lit(6.0))
+    } else if max == g {
+        lit::<T>(60.0) * ((b - r) / delta + lit(2.0))
+    } else {
+        lit::<T>(60.0) * ((r - g) / delta + lit(4.0))
     };
 
-    rgb.iter().map(|x| (x + m) * 255.0).collect()
+    if h < T::ZERO {
+        h += lit(360.0);
+    }
+    h
+}
+
+/// [HSL to RGB color conversion](https://www.rapidtables.com/convert/color/hsl-to-rgb.html)
+pub fn hsl2rgb<T: Float>(color: [T; 3]) -> [T; 3] {
+    let [h, s, l] = color;
+    let h = h.rem_euclid(lit(360.0));
+
+    let c = (T::ONE - (lit::<T>(2.0) * l - T::ONE).abs()) * s;
+    let x = c * (T::ONE - (((h / lit(60.0)) % lit(2.0)) - T::ONE).abs());
+    let m = l - c / lit(2.0);
+
+    hue_sector(h, c, x).map(|x| (x + m) * lit(255.0))
 }
 
 /// [RGB to HSL color conversion](https://www.rapidtables.com/convert/color/rgb-to-hsl.html)
-pub fn rgb2hsl(color: &[f64]) -> Vec<f64> {
+pub fn rgb2hsl<T: Float>(color: [T; 3]) -> [T; 3] {
     let color = normalize_color(color);
-    let r = color[0];
-    let g = color[1];
-    let b = color[2];
+    let [r, g, b] = color;
 
     let max = r.max(g).max(b);
     let min = r.min(g).min(b);
 
-    let mut h = 0.0;
-    let mut s = 0.0;
-    let l = (max + min) / 2.0;
+    let mut h = T::ZERO;
+    let mut s = T::ZERO;
+    let l = (max + min) / lit(2.0);
 
     let delta = max - min;
 
-    if delta != 0.0 {
-        h = match max {
-            x if x == r => 60.0 * (((g - b) / delta) % 6.0),
-            x if x == g => 60.0 * ((b - r) / delta + 2.0),
-            x if x == b => 60.0 * ((r - g) / delta + 4.0),
-            _ => panic!(),
-        };
-
-        if h < 0.0 {
-            h += 360.0;
-        }
+    if delta != T::ZERO {
+        h = hue_of(color, max, delta);
 
-        s = delta / (1.0 - (2.0 * l - 1.0).abs());
-        s = s.max(0.0).min(1.0);
+        s = delta / (T::ONE - (lit::<T>(2.0) * l - T::ONE).abs());
+        s = s.clamp(T::ZERO, T::ONE);
     }
 
-    vec![h, s, l]
+    [h, s, l]
 }
 
 #[cfg(test)]
@@ -65,7 +75,14 @@ mod tests {
     #[test]
     fn test_rgb2hsl() {
         let rgb = [41.0, 121.0, 255.0];
-        let hsl = rgb2hsl(&rgb);
+        let hsl = rgb2hsl(rgb);
         assert_eq!(hsl[1], 1.0);
     }
+
+    #[test]
+    fn test_hsl2rgb_wraps_hue() {
+        assert_eq!(hsl2rgb([360.0, 1.0, 0.5]), hsl2rgb([0.0, 1.0, 0.5]));
+        assert_eq!(hsl2rgb([-60.0, 1.0, 0.5]), hsl2rgb([300.0, 1.0, 0.5]));
+        assert!(hsl2rgb([f64::NAN, 1.0, 0.5]).iter().any(|v| v.is_nan()));
+    }
 }
@@ -1,61 +1,40 @@
+use super::hsl::{hue_of, hue_sector};
 use crate::utils::*;
 
 /// [HSV to RGB color conversion](https://www.rapidtables.com/convert/color/hsv-to-rgb.html)
-pub fn hsv2rgb(color: &[f64]) -> Vec<f64> {
-    let h = color[0];
-    let s = color[1];
-    let v = color[2];
+pub fn hsv2rgb<T: Float>(color: [T; 3]) -> [T; 3] {
+    let [h, s, v] = color;
+    let h = h.rem_euclid(lit(360.0));
 
     let c = v * s;
-    let x = c * (1.0 - (((h / 60.0) % 2.0) - 1.0).abs());
+    let x = c * (T::ONE - (((h / lit(60.0)) % lit(2.0)) - T::ONE).abs());
     let m = v - c;
 
-    let rgb = match h {
-        h if (0.0..60.0).contains(&h) => vec![c, x, 0.0],
-        h if (60.0..120.0).contains(&h) => vec![x, c, 0.0],
-        h if (120.0..180.0).contains(&h) => vec![0.0, c, x],
-        h if (180.0..240.0).contains(&h) => vec![0.0, x, c],
-        h if (240.0..300.0).contains(&h) => vec![x, 0.0, c],
-        h if (300.0..360.0).contains(&h) => vec![c, 0.0, x],
-        _ => panic!("Hue must be between 0 and 360"),
-    };
-
-    rgb.iter().map(|&x| (x + m) * 255.0).collect()
+    hue_sector(h, c, x).map(|x| (x + m) * lit(255.0))
 }
 
 /// [RGB to HSV color conversion](https://www.rapidtables.com/convert/color/rgb-to-hsv.html)
-pub fn rgb2hsv(color: &[f64]) -> Vec<f64> {
+pub fn rgb2hsv<T: Float>(color: [T; 3]) -> [T; 3] {
     let color = normalize_color(color);
-    let r = color[0];
-    let g = color[1];
-    let b = color[2];
+    let [r, g, b] = color;
 
     let max = r.max(g).max(b);
     let min = r.min(g).min(b);
     let delta = max - min;
 
-    let mut h = 0.0;
-    let mut s = 0.0;
+    let mut h = T::ZERO;
+    let mut s = T::ZERO;
     let v = max;
 
-    if delta != 0.0 {
-        h = match max {
-            x if x == r => 60.0 * (((g - b) / delta) % 6.0),
-            x if x == g => 60.0 * ((b - r) / delta + 2.0),
-            x if x == b => 60.0 * ((r - g) / delta + 4.0),
-            _ => panic!(),
-        };
-
-        if h < 0.0 {
-            h += 360.0;
-        }
+    if delta != T::ZERO {
+        h = hue_of(color, max, delta);
     }
 
-    if max != 0.0 {
+    if max != T::ZERO {
         s = delta / max;
     }
 
-    vec![h, s, v]
+    [h, s, v]
 }
 
 #[cfg(test)]
@@ -64,31 +43,31 @@ mod tests {
 
     #[test]
     fn test_hsv2rgb() {
-        let hsv = vec![330.0, 0.8, 1.0];
-        let rgb = hsv2rgb(&hsv);
-        assert_eq!(rgb, vec![255.0, 50.999999999999986, 153.0]);
+        let hsv = [330.0, 0.8, 1.0];
+        let rgb = hsv2rgb(hsv);
+        assert_eq!(rgb, [255.0, 50.999999999999986, 153.0]);
 
-        let hsv = vec![0.0, 0.0, 0.0];
-        let rgb = hsv2rgb(&hsv);
-        assert_eq!(rgb, vec![0.0, 0.0, 0.0]);
+        let hsv = [0.0, 0.0, 0.0];
+        let rgb = hsv2rgb(hsv);
+        assert_eq!(rgb, [0.0, 0.0, 0.0]);
 
-        let hsv = vec![0.0, 0.0, 1.0];
-        let rgb = hsv2rgb(&hsv);
-        assert_eq!(rgb, vec![255.0, 255.0, 255.0]);
+        let hsv = [0.0, 0.0, 1.0];
+        let rgb = hsv2rgb(hsv);
+        assert_eq!(rgb, [255.0, 255.0, 255.0]);
     }
 
     #[test]
     fn test_rgb2hsv() {
-        let rgb = vec![255.0, 51.0, 153.0];
-        let hsv = rgb2hsv(&rgb);
-        assert_eq!(hsv, vec![330.0, 0.8, 1.0]);
+        let rgb = [255.0, 51.0, 153.0];
+        let hsv = rgb2hsv(rgb);
+        assert_eq!(hsv, [330.0, 0.8, 1.0]);
 
-        let rgb = vec![0.0, 0.0, 0.0];
-        let hsv = rgb2hsv(&rgb);
-        assert_eq!(hsv, vec![0.0, 0.0, 0.0]);
+        let rgb = [0.0, 0.0, 0.0];
+        let hsv = rgb2hsv(rgb);
+        assert_eq!(hsv, [0.0, 0.0, 0.0]);
 
-        let rgb = vec![255.0, 255.0, 255.0];
-        let hsv = rgb2hsv(&rgb);
-        assert_eq!(hsv, vec![0.0, 0.0, 1.0]);
+        let rgb = [255.0, 255.0, 255.0];
+        let hsv = rgb2hsv(rgb);
+        assert_eq!(hsv, [0.0, 0.0, 1.0]);
     }
 }
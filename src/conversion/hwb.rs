@@ -4,42 +4,31 @@ use crate::utils::*;
 /// Convert RGB to HWB.
 ///
 /// Reference from [Converting sRGB Colors to HWB](https://w3c.github.io/csswg-drafts/css-color/#rgb-to-hwb)
-pub fn rgb2hwb(color: &[f64]) -> Vec<f64> {
-    let color = normalize_color(color);
-    let r = color[0];
-    let g = color[1];
-    let b = color[2];
+pub fn rgb2hwb<T: Float>(color: [T; 3]) -> [T; 3] {
+    let hsl = hsl::rgb2hsl(color);
+    let [r, g, b] = normalize_color(color);
 
     let max = r.max(g).max(b);
     let min = r.min(g).min(b);
 
-    let hsl = hsl::rgb2hsl(&color);
-
     let hue = hsl[0];
     let whiteness = min;
-    let blackness = 1.0 - max;
+    let blackness = T::ONE - max;
 
-    vec![hue, whiteness, blackness]
+    [hue, whiteness, blackness]
 }
 
 /// Reference from [Converting HWB Colors to sRGB](https://w3c.github.io/csswg-drafts/css-color/#hsl-to-rgb)
-pub fn hwb2rgb(color: &[f64]) -> Vec<f64> {
-    let hue = color[0];
-    let whiteness = color[1];
-    let blackness = color[2];
+pub fn hwb2rgb<T: Float>(color: [T; 3]) -> [T; 3] {
+    let [hue, whiteness, blackness] = color;
 
-    if whiteness + blackness >= 1.0 {
-        let gray = whiteness / (whiteness + blackness);
-        return vec![gray, gray, gray];
+    if whiteness + blackness >= T::ONE {
+        let gray = whiteness / (whiteness + blackness) * lit(255.0);
+        return [gray, gray, gray];
     }
-    let mut rgb_vec = hsl::hsl2rgb(&[hue, 1.0, 0.5]);
 
-    for channel in rgb_vec.iter_mut().take(3) {
-        *channel *= 1.0 - whiteness - blackness;
-        *channel += whiteness * 255.0;
-        *channel = round(*channel, 0);
-    }
-    rgb_vec
+    hsl::hsl2rgb([hue, T::ONE, lit(0.5)])
+        .map(|channel| channel * (T::ONE - whiteness - blackness) + whiteness * lit(255.0))
 }
 
 #[cfg(test)]
@@ -48,19 +37,27 @@ mod tests {
 
     #[test]
     fn test_rgb2hwb() {
-        assert_eq!(rgb2hwb(&[0.0, 255.0, 102.0]), vec![144.0, 0.0, 0.0]);
+        assert_eq!(rgb2hwb([0.0, 255.0, 102.0]), [144.0, 0.0, 0.0]);
         assert_eq!(
-            rgb2hwb(&[86.0, 59.0, 133.0]),
-            vec![261.8918918918919, 0.23137254901960785, 0.4784313725490196]
+            rgb2hwb([86.0, 59.0, 133.0]),
+            [261.8918918918919, 0.23137254901960785, 0.4784313725490196]
         );
     }
 
     #[test]
     fn test_hwb2rgb() {
-        assert_eq!(hwb2rgb(&[144.0, 0.0, 0.0]), vec![0.0, 255.0, 102.0]);
         assert_eq!(
-            hwb2rgb(&[261.8918918918919, 0.23137254901960785, 0.4784313725490196]),
-            vec![86.0, 59.0, 133.0]
+            hwb2rgb([144.0, 0.0, 0.0]).map(|v| round(v, 0)),
+            [0.0, 255.0, 102.0]
+        );
+        assert_eq!(
+            hwb2rgb([261.8918918918919, 0.23137254901960785, 0.4784313725490196])
+                .map(|v| round(v, 0)),
+            [86.0, 59.0, 133.0]
+        );
+        assert_eq!(
+            hwb2rgb([0.0, 0.6, 0.6]).map(|v| round(v, 0)),
+            [128.0, 128.0, 128.0]
         );
     }
 }
@@ -1,10 +1,13 @@
 pub(crate) mod cmyk;
+#[cfg(feature = "alloc")]
 pub(crate) mod hex;
 pub(crate) mod hsi;
 pub(crate) mod hsl;
 pub(crate) mod hsv;
 pub(crate) mod hwb;
 pub(crate) mod lab;
+pub(crate) mod oklab;
+pub(crate) mod predefined;
 pub(crate) mod utils;
 pub(crate) mod xyz;
 pub(crate) mod ycbcr;
@@ -0,0 +1,83 @@
+//! Conversions of Oklab and the polar forms of Lab and Oklab.
+//!
+//! The matrices are the ones of the
+//! [sample code](https://www.w3.org/TR/css-color-4/#color-conversion-code) of CSS Color 4.
+
+use super::xyz::{rgb2xyz, xyz2rgb};
+use crate::utils::{lit, transform3, Float, Matrix3};
+
+const XYZ_TO_LMS_MATRIX: Matrix3 = [
+    [0.819022437996703, 0.3619062600528904, -0.1288737815209879],
+    [0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+    [0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+
+const LMS_TO_XYZ_MATRIX: Matrix3 = [
+    [1.2268798758459243, -0.5578149944602171, 0.2813910456659647],
+    [-0.0405757452148008, 1.112286803280317, -0.0717110580655164],
+    [-0.0763729366746601, -0.4214933324022432, 1.5869240198367816],
+];
+
+const LMS_TO_OKLAB_MATRIX: Matrix3 = [
+    [0.210454268309314, 0.7936177747023054, -0.0040720430116193],
+    [1.9779985324311684, -2.42859224204858, 0.450593709617411],
+    [0.0259040424655478, 0.7827717124575296, -0.8086757549230774],
+];
+
+const OKLAB_TO_LMS_MATRIX: Matrix3 = [
+    [1.0, 0.3963377773761749, 0.2158037573099136],
+    [1.0, -0.1055613458156586, -0.0638541728258133],
+    [1.0, -0.0894841775298119, -1.2914855480194092],
+];
+
+pub fn rgb2oklab<T: Float>(color: [T; 3]) -> [T; 3] {
+    let lms = transform3(XYZ_TO_LMS_MATRIX, rgb2xyz(color));
+    transform3(LMS_TO_OKLAB_MATRIX, lms.map(|v| v.cbrt()))
+}
+
+pub fn oklab2rgb<T: Float>(color: [T; 3]) -> [T; 3] {
+    let lms = transform3(OKLAB_TO_LMS_MATRIX, color).map(|v| v * v * v);
+    xyz2rgb(transform3(LMS_TO_XYZ_MATRIX, lms))
+}
+
+/// Convert the rectangular `a` and `b` of Lab or Oklab to the chroma and hue in degrees.
+pub(crate) fn lab2lch<T: Float>([l, a, b]: [T; 3]) -> [T; 3] {
+    let c = (a * a + b * b).sqrt();
+    let h = b.atan2(a).to_degrees().rem_euclid(lit(360.0));
+    [l, c, h]
+}
+
+/// Convert the chroma and hue in degrees of LCH or Oklch to `a` and `b`.
+pub(crate) fn lch2lab<T: Float>([l, c, h]: [T; 3]) -> [T; 3] {
+    let h = h.to_radians();
+    [l, c * h.cos(), c * h.sin()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::round;
+
+    #[test]
+    fn test_rgb2oklab() {
+        let red = rgb2oklab([255.0, 0.0, 0.0]).map(|v| round(v, 4));
+        assert_eq!(red, [0.628, 0.2249, 0.1258]);
+
+        let white = rgb2oklab([255.0, 255.0, 255.0]).map(|v| round(v, 4));
+        assert_eq!(white, [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_oklab_round_trip() {
+        let color = [118.0, 84.0, 205.0];
+        let rgb = oklab2rgb(rgb2oklab(color));
+        assert_eq!(rgb.map(|v| round(v, 6)), color);
+    }
+
+    #[test]
+    fn test_lab2lch() {
+        let lch = lab2lch([50.0, 0.0, -20.0]);
+        assert_eq!(lch.map(|v| round(v, 6)), [50.0, 20.0, 270.0]);
+        assert_eq!(lch2lab(lch).map(|v| round(v, 6)), [50.0, 0.0, -20.0]);
+    }
+}
@@ -0,0 +1,240 @@
+//! Conversions of the predefined color spaces of CSS Color 4.
+//!
+//! The matrices and transfer functions are the ones of the
+//! [sample code](https://www.w3.org/TR/css-color-4/#color-conversion-code) of the spec.
+
+use super::utils::{d50_to_d65, d65_to_d50, gam_srgb, lin_srgb, lin_srgb_to_xyz, xyz_to_lin_srgb};
+use crate::{
+    utils::{lit, transform3, Float, Matrix3},
+    PredefinedSpace,
+};
+
+const LIN_P3_TO_XYZ_MATRIX: Matrix3 = [
+    [
+        608311.0 / 1250200.0,
+        189793.0 / 714400.0,
+        198249.0 / 1000160.0,
+    ],
+    [
+        35783.0 / 156275.0,
+        247089.0 / 357200.0,
+        198249.0 / 2500400.0,
+    ],
+    [0.0, 32229.0 / 714400.0, 5220557.0 / 5000800.0],
+];
+
+const XYZ_TO_LIN_P3_MATRIX: Matrix3 = [
+    [
+        446124.0 / 178915.0,
+        -333277.0 / 357830.0,
+        -72051.0 / 178915.0,
+    ],
+    [-14852.0 / 17905.0, 63121.0 / 35810.0, 423.0 / 17905.0],
+    [11844.0 / 330415.0, -50337.0 / 660830.0, 316169.0 / 330415.0],
+];
+
+const LIN_A98_TO_XYZ_MATRIX: Matrix3 = [
+    [
+        573536.0 / 994567.0,
+        263643.0 / 1420810.0,
+        187206.0 / 994567.0,
+    ],
+    [
+        591459.0 / 1989134.0,
+        6239551.0 / 9945670.0,
+        374412.0 / 4972835.0,
+    ],
+    [
+        53769.0 / 1989134.0,
+        351524.0 / 4972835.0,
+        4929758.0 / 4972835.0,
+    ],
+];
+
+const XYZ_TO_LIN_A98_MATRIX: Matrix3 = [
+    [
+        1829569.0 / 896150.0,
+        -506331.0 / 896150.0,
+        -308931.0 / 896150.0,
+    ],
+    [
+        -851781.0 / 878810.0,
+        1648619.0 / 878810.0,
+        36519.0 / 878810.0,
+    ],
+    [
+        16779.0 / 1248040.0,
+        -147721.0 / 1248040.0,
+        1266979.0 / 1248040.0,
+    ],
+];
+
+/// ProPhoto RGB to D50-adapted XYZ.
+const LIN_PROPHOTO_TO_XYZ_MATRIX: Matrix3 = [
+    [0.7977666449006423, 0.1351812974005331, 0.0313477341283922],
+    [0.2880748288194013, 0.711835234241873, 0.00008993693872564],
+    [0.0, 0.0, 0.8251046025104602],
+];
+
+const XYZ_TO_LIN_PROPHOTO_MATRIX: Matrix3 = [
+    [
+        1.3457868816471583,
+        -0.25557208737979464,
+        -0.05110186497554526,
+    ],
+    [-0.5446307051249019, 1.5082477428451468, 0.02052744743642139],
+    [0.0, 0.0, 1.2119675456389452],
+];
+
+const LIN_2020_TO_XYZ_MATRIX: Matrix3 = [
+    [
+        63426534.0 / 99577255.0,
+        20160776.0 / 139408157.0,
+        47086771.0 / 278816314.0,
+    ],
+    [
+        26158966.0 / 99577255.0,
+        472592308.0 / 697040785.0,
+        8267143.0 / 139408157.0,
+    ],
+    [0.0, 19567812.0 / 697040785.0, 295819943.0 / 278816314.0],
+];
+
+const XYZ_TO_LIN_2020_MATRIX: Matrix3 = [
+    [
+        30757411.0 / 17917100.0,
+        -6372589.0 / 17917100.0,
+        -4539589.0 / 17917100.0,
+    ],
+    [
+        -19765991.0 / 29648200.0,
+        47925759.0 / 29648200.0,
+        467509.0 / 29648200.0,
+    ],
+    [
+        792561.0 / 44930125.0,
+        -1921689.0 / 44930125.0,
+        42328811.0 / 44930125.0,
+    ],
+];
+
+const REC2020_ALPHA: f64 = 1.09929682680944;
+const REC2020_BETA: f64 = 0.018053968510807;
+
+/// Convert RGB to the values of a predefined color space.
+pub fn rgb2predefined<T: Float>(space: PredefinedSpace, color: [T; 3]) -> [T; 3] {
+    if space == PredefinedSpace::Srgb {
+        return color.map(|v| v / lit(255.0));
+    }
+    let lin = lin_srgb(color.map(|v| v / lit(255.0)));
+    if space == PredefinedSpace::SrgbLinear {
+        return lin;
+    }
+    let xyz = lin_srgb_to_xyz(lin);
+    match space {
+        PredefinedSpace::Srgb => gam_srgb(lin),
+        PredefinedSpace::SrgbLinear => lin,
+        PredefinedSpace::DisplayP3 => gam_srgb(transform3(XYZ_TO_LIN_P3_MATRIX, xyz)),
+        PredefinedSpace::A98Rgb => transform3(XYZ_TO_LIN_A98_MATRIX, xyz)
+            .map(|v| v.signum() * v.abs().powf(lit(256.0 / 563.0))),
+        PredefinedSpace::ProphotoRgb => transform3(XYZ_TO_LIN_PROPHOTO_MATRIX, d65_to_d50(xyz))
+            .map(|v| {
+                if v.abs() >= lit(1.0 / 512.0) {
+                    v.signum() * v.abs().powf(lit(1.0 / 1.8))
+                } else {
+                    lit::<T>(16.0) * v
+                }
+            }),
+        PredefinedSpace::Rec2020 => transform3(XYZ_TO_LIN_2020_MATRIX, xyz).map(|v| {
+            if v.abs() > lit(REC2020_BETA) {
+                v.signum()
+                    * (lit::<T>(REC2020_ALPHA) * v.abs().powf(lit(0.45)) - lit(REC2020_ALPHA - 1.0))
+            } else {
+                lit::<T>(4.5) * v
+            }
+        }),
+        PredefinedSpace::XyzD50 => d65_to_d50(xyz),
+        PredefinedSpace::XyzD65 => xyz,
+    }
+}
+
+/// Convert the values of a predefined color space to RGB.
+pub fn predefined2rgb<T: Float>(space: PredefinedSpace, color: [T; 3]) -> [T; 3] {
+    let xyz = match space {
+        PredefinedSpace::Srgb => return color.map(|v| v * lit(255.0)),
+        PredefinedSpace::SrgbLinear => return gam_srgb(color).map(|v| v * lit(255.0)),
+        PredefinedSpace::DisplayP3 => transform3(LIN_P3_TO_XYZ_MATRIX, lin_srgb(color)),
+        PredefinedSpace::A98Rgb => transform3(
+            LIN_A98_TO_XYZ_MATRIX,
+            color.map(|v| v.signum() * v.abs().powf(lit(563.0 / 256.0))),
+        ),
+        PredefinedSpace::ProphotoRgb => d50_to_d65(transform3(
+            LIN_PROPHOTO_TO_XYZ_MATRIX,
+            color.map(|v| {
+                if v.abs() <= lit(16.0 / 512.0) {
+                    v / lit(16.0)
+                } else {
+                    v.signum() * v.abs().powf(lit(1.8))
+                }
+            }),
+        )),
+        PredefinedSpace::Rec2020 => transform3(
+            LIN_2020_TO_XYZ_MATRIX,
+            color.map(|v| {
+                if v.abs() < lit(REC2020_BETA * 4.5) {
+                    v / lit(4.5)
+                } else {
+                    v.signum()
+                        * ((v.abs() + lit(REC2020_ALPHA - 1.0)) / lit(REC2020_ALPHA))
+                            .powf(lit(1.0 / 0.45))
+                }
+            }),
+        ),
+        PredefinedSpace::XyzD50 => d50_to_d65(color),
+        PredefinedSpace::XyzD65 => color,
+    };
+    gam_srgb(xyz_to_lin_srgb(xyz)).map(|v| v * lit(255.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::round;
+
+    #[test]
+    fn test_rgb2predefined() {
+        let red = [255.0, 0.0, 0.0];
+        let p3 = rgb2predefined(PredefinedSpace::DisplayP3, red).map(|v| round(v, 4));
+        assert_eq!(p3, [0.9175, 0.2003, 0.1386]);
+
+        let rec2020 = rgb2predefined(PredefinedSpace::Rec2020, red).map(|v| round(v, 4));
+        assert_eq!(rec2020, [0.792, 0.231, 0.0738]);
+
+        let a98 = rgb2predefined(PredefinedSpace::A98Rgb, red).map(|v| round(v, 4));
+        assert_eq!(a98, [0.8586, 0.0, 0.0]);
+
+        let prophoto = rgb2predefined(PredefinedSpace::ProphotoRgb, red).map(|v| round(v, 4));
+        assert_eq!(prophoto, [0.7022, 0.2757, 0.1035]);
+
+        let linear = rgb2predefined(PredefinedSpace::SrgbLinear, [127.5, 0.0, 255.0]);
+        assert_eq!(linear.map(|v| round(v, 4)), [0.2140, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_predefined_round_trip() {
+        let color = [118.0, 84.0, 205.0];
+        for space in [
+            PredefinedSpace::Srgb,
+            PredefinedSpace::SrgbLinear,
+            PredefinedSpace::DisplayP3,
+            PredefinedSpace::A98Rgb,
+            PredefinedSpace::ProphotoRgb,
+            PredefinedSpace::Rec2020,
+            PredefinedSpace::XyzD50,
+            PredefinedSpace::XyzD65,
+        ] {
+            let rgb = predefined2rgb(space, rgb2predefined(space, color));
+            assert_eq!(rgb.map(|v| round(v, 3)), color, "{}", space);
+        }
+    }
+}
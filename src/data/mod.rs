@@ -1,41 +1,106 @@
-pub mod alpha_hex_map;
 pub mod chinese_color;
 pub mod w3cx11;
 
+use chinese_color::CHINESE_COLOR;
+use w3cx11::W3CX11;
+
+const COLOR_COUNT: usize = W3CX11.len() + CHINESE_COLOR.len();
+
+/// Indices of the named colors sorted by name.
+static BY_NAME: [u16; COLOR_COUNT] = sorted_indices(false);
+/// Indices of the named colors sorted by hex.
+static BY_HEX: [u16; COLOR_COUNT] = sorted_indices(true);
+
 pub(crate) fn hex_of_name(color_name: &str) -> Option<&'static str> {
-    let hex_str = crate::W3CX11.get(color_name);
-    match hex_str {
-        Some(hex_str) => Some(hex_str),
-        None => {
-            let res = crate::CHINESE_COLOR.get(color_name);
-            match res {
-                Some(hex_str) => Some(hex_str),
-                None => None,
-            }
+    find(&BY_NAME, false, color_name).map(|(_k, v)| v)
+}
+
+pub(crate) fn name_of_hex(hex_str: &str) -> Option<&'static str> {
+    find(&BY_HEX, true, hex_str).map(|(k, _v)| k)
+}
+
+/// The named color at `index`, the W3C/X11 colors come before the Chinese ones.
+const fn entry(index: usize) -> (&'static str, &'static str) {
+    if index < W3CX11.len() {
+        W3CX11[index]
+    } else {
+        CHINESE_COLOR[index - W3CX11.len()]
+    }
+}
+
+const fn key(index: u16, by_hex: bool) -> &'static str {
+    let (name, hex) = entry(index as usize);
+    if by_hex {
+        hex
+    } else {
+        name
+    }
+}
+
+/// Binary search of `value` in `indices`, the first entry wins when several match.
+fn find(indices: &[u16], by_hex: bool, value: &str) -> Option<(&'static str, &'static str)> {
+    let start = indices.partition_point(|&i| key(i, by_hex) < value);
+    indices
+        .get(start)
+        .filter(|&&i| key(i, by_hex) == value)
+        .map(|&i| entry(i as usize))
+}
+
+/// Byte-wise `a < b`, the order of `str`.
+const fn less(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
         }
+        i += 1;
     }
+    a.len() < b.len()
 }
 
-pub(crate) fn name_of_hex(hex_str: &str) -> Option<&'static str> {
-    let result = crate::W3CX11
-        .clone()
-        .into_iter()
-        .find(|(_k, v)| *v == hex_str)
-        .map(|(k, _v)| k);
-
-    match result {
-        Some(name) => Some(name),
-        None => {
-            let result = crate::CHINESE_COLOR
-                .clone()
-                .into_iter()
-                .find(|(_k, v)| *v == hex_str)
-                .map(|(k, _v)| k);
-            match result {
-                Some(name) => Some(name),
-                None => None,
+/// Stable bottom-up merge sort of the color indices by name or by hex.
+const fn sorted_indices(by_hex: bool) -> [u16; COLOR_COUNT] {
+    let mut sorted = [0; COLOR_COUNT];
+    let mut i = 0;
+    while i < COLOR_COUNT {
+        sorted[i] = i as u16;
+        i += 1;
+    }
+    let mut merged = [0; COLOR_COUNT];
+    let mut width = 1;
+    while width < COLOR_COUNT {
+        let mut start = 0;
+        while start < COLOR_COUNT {
+            let mid = min(start + width, COLOR_COUNT);
+            let end = min(start + 2 * width, COLOR_COUNT);
+            let (mut left, mut right, mut k) = (start, mid, start);
+            while k < end {
+                let take_left = left < mid
+                    && (right >= end
+                        || !less(key(sorted[right], by_hex), key(sorted[left], by_hex)));
+                if take_left {
+                    merged[k] = sorted[left];
+                    left += 1;
+                } else {
+                    merged[k] = sorted[right];
+                    right += 1;
+                }
+                k += 1;
             }
+            start = end;
         }
+        sorted = merged;
+        width *= 2;
+    }
+    sorted
+}
+
+const fn min(a: usize, b: usize) -> usize {
+    if a < b {
+        a
+    } else {
+        b
     }
 }
 
@@ -54,4 +119,18 @@ mod tests {
         let color_name = hex_of_name("没有的颜色");
         assert_eq!(color_name, None);
     }
+
+    #[test]
+    fn test_lookup_matches_tables() {
+        let entries = || W3CX11.iter().chain(CHINESE_COLOR.iter());
+        for &(name, hex) in entries() {
+            let first_hex = entries().find(|(k, _)| *k == name).map(|(_, v)| *v);
+            assert_eq!(hex_of_name(name), first_hex);
+            let first_name = entries().find(|(_, v)| *v == hex).map(|(k, _)| *k);
+            assert_eq!(name_of_hex(hex), first_name);
+        }
+        assert_eq!(name_of_hex("#00ffff"), Some("aqua"));
+        assert_eq!(name_of_hex("#123457"), None);
+        assert_eq!(hex_of_name(""), None);
+    }
 }
@@ -1,18 +1,430 @@
-use thiserror::Error;
+use core::{fmt::Display, ops::Deref};
 
+#[cfg(all(feature = "serde", feature = "alloc"))]
+use serde::{Deserialize, Deserializer};
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{Serialize, Serializer};
+
+#[cfg(feature = "alloc")]
+type MessageRepr = alloc::borrow::Cow<'static, str>;
+#[cfg(not(feature = "alloc"))]
+type MessageRepr = &'static str;
+
+/// The message carried by an [`Error`].
+///
+/// It derefs to a `str`, with the `alloc` feature it can also hold a formatted `String`.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{Color, Error};
+/// use std::str::FromStr;
+///
+/// match Color::from_str("rgb(0, 300, 0)").unwrap_err() {
+///     Error::ChannelOutOfRange { channel, .. } => assert_eq!(channel, "Green"),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ErrorMessage(MessageRepr);
+
+impl ErrorMessage {
+    /// The message as a string slice.
+    pub fn as_str(&self) -> &str {
+        #[cfg(feature = "alloc")]
+        return &self.0;
+        #[cfg(not(feature = "alloc"))]
+        return self.0;
+    }
+}
+
+impl Deref for ErrorMessage {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&'static str> for ErrorMessage {
+    fn from(message: &'static str) -> Self {
+        #[cfg(feature = "alloc")]
+        return ErrorMessage(alloc::borrow::Cow::Borrowed(message));
+        #[cfg(not(feature = "alloc"))]
+        return ErrorMessage(message);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<alloc::string::String> for ErrorMessage {
+    fn from(message: alloc::string::String) -> Self {
+        ErrorMessage(message.into())
+    }
+}
+
+impl PartialEq<str> for ErrorMessage {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ErrorMessage {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl core::fmt::Debug for ErrorMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for ErrorMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ErrorMessage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl<'de> Deserialize<'de> for ErrorMessage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        alloc::string::String::deserialize(deserializer).map(ErrorMessage::from)
+    }
+}
+
+/// A byte range of the parsed input.
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{Color, Error, Span};
+/// use std::str::FromStr;
+///
+/// let input = "rgb(255, 300, 0)";
+/// let err = Color::from_str(input).unwrap_err();
+/// let span = err.span().unwrap();
+/// assert_eq!(span, Span::new(9, 12));
+/// assert_eq!(&input[span.range()], "300");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(all(feature = "serde", feature = "alloc"), derive(Deserialize))]
+pub struct Span {
+    /// The byte offset of the first character.
+    pub start: usize,
+    /// The byte offset after the last character.
+    pub end: usize,
+}
+
+impl Span {
+    /// Creates a new [`Span`].
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+    /// The span as a range, to slice the input with.
+    pub fn range(&self) -> core::ops::Range<usize> {
+        self.start..self.end
+    }
+    /// The span moved `offset` bytes to the right.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn shift(self, offset: usize) -> Self {
+        Span::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
 
 /// Error info enum
-#[derive(Error, Debug, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+///
+/// The `f64` fields compare by their bits, so that the error stays [`Eq`], a NaN value
+/// equals itself.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(all(feature = "serde", feature = "alloc"), derive(Deserialize))]
 pub enum Error {
-    #[error("{0}")]
-    ColorParserError(String),
+    ColorParserError(ErrorMessage),
+
+    InvalidParamsError(ErrorMessage),
 
-    #[error("{0}")]
-    InvalidParamsError(String),
+    /// The input names a color function that does not exist, e.g. `rgbx(0, 0, 0)`.
+    UnknownFunction {
+        name: ErrorMessage,
+        span: Span,
+    },
+
+    /// A color function got the wrong number of values.
+    WrongArity {
+        function: ErrorMessage,
+        expected: usize,
+        found: usize,
+        span: Span,
+    },
+
+    /// A channel value is outside of its valid range `min..=max`.
+    ChannelOutOfRange {
+        channel: ErrorMessage,
+        value: f64,
+        min: f64,
+        max: f64,
+        span: Span,
+    },
+
+    /// A hex color does not have 3, 4, 6, 8, 9 or 12 digits.
+    BadHexLength {
+        length: usize,
+        span: Span,
+    },
+
+    /// The input is malformed, e.g. an unmatched parenthesis or trailing input after a color.
+    Syntax {
+        message: ErrorMessage,
+        span: Span,
+    },
 
-    #[error("unknown error, please report this error to the developers")]
     Unknown,
 }
+
+impl Error {
+    /// The span of the input the error points at, for the errors of the parser.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Error::UnknownFunction { span, .. }
+            | Error::WrongArity { span, .. }
+            | Error::ChannelOutOfRange { span, .. }
+            | Error::BadHexLength { span, .. }
+            | Error::Syntax { span, .. } => Some(*span),
+            _ => None,
+        }
+    }
+    /// Moves the span of the error `offset` bytes to the right.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn shift_span(mut self, offset: usize) -> Self {
+        match &mut self {
+            Error::UnknownFunction { span, .. }
+            | Error::WrongArity { span, .. }
+            | Error::ChannelOutOfRange { span, .. }
+            | Error::BadHexLength { span, .. }
+            | Error::Syntax { span, .. } => *span = span.shift(offset),
+            _ => {}
+        }
+        self
+    }
+    /// Points the error at `span`, also the errors that already have a span, e.g. the errors
+    /// in the value of a variable at the reference of the variable.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn replace_span(self, span: Span) -> Self {
+        let mut err = self.with_span(span);
+        match &mut err {
+            Error::UnknownFunction { span: old, .. }
+            | Error::WrongArity { span: old, .. }
+            | Error::ChannelOutOfRange { span: old, .. }
+            | Error::BadHexLength { span: old, .. }
+            | Error::Syntax { span: old, .. } => *old = span,
+            _ => {}
+        }
+        err
+    }
+    /// Points a [`Error::ColorParserError`] without a position at `span`, the errors that
+    /// already have a span keep it.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn with_span(self, span: Span) -> Self {
+        match self {
+            Error::ColorParserError(message) => Error::Syntax { message, span },
+            err => err,
+        }
+    }
+}
+
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Error::ColorParserError(a), Error::ColorParserError(b))
+            | (Error::InvalidParamsError(a), Error::InvalidParamsError(b)) => a == b,
+            (
+                Error::UnknownFunction { name, span },
+                Error::UnknownFunction {
+                    name: other_name,
+                    span: other_span,
+                },
+            ) => name == other_name && span == other_span,
+            (
+                Error::WrongArity {
+                    function,
+                    expected,
+                    found,
+                    span,
+                },
+                Error::WrongArity {
+                    function: other_function,
+                    expected: other_expected,
+                    found: other_found,
+                    span: other_span,
+                },
+            ) => {
+                function == other_function
+                    && expected == other_expected
+                    && found == other_found
+                    && span == other_span
+            }
+            (
+                Error::ChannelOutOfRange {
+                    channel,
+                    value,
+                    min,
+                    max,
+                    span,
+                },
+                Error::ChannelOutOfRange {
+                    channel: other_channel,
+                    value: other_value,
+                    min: other_min,
+                    max: other_max,
+                    span: other_span,
+                },
+            ) => {
+                channel == other_channel
+                    && value.to_bits() == other_value.to_bits()
+                    && min.to_bits() == other_min.to_bits()
+                    && max.to_bits() == other_max.to_bits()
+                    && span == other_span
+            }
+            (
+                Error::BadHexLength { length, span },
+                Error::BadHexLength {
+                    length: other_length,
+                    span: other_span,
+                },
+            ) => length == other_length && span == other_span,
+            (
+                Error::Syntax { message, span },
+                Error::Syntax {
+                    message: other_message,
+                    span: other_span,
+                },
+            ) => message == other_message && span == other_span,
+            (Error::Unknown, Error::Unknown) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::ColorParserError(msg) | Error::InvalidParamsError(msg) => write!(f, "{}", msg),
+            Error::UnknownFunction { name, span } => {
+                write!(f, "Unknown color function '{}' at {}", name, span)
+            }
+            Error::WrongArity {
+                function,
+                expected,
+                found,
+                span,
+            } => write!(
+                f,
+                "{} requires {} values, got {} at {}",
+                function, expected, found, span
+            ),
+            Error::ChannelOutOfRange {
+                channel,
+                value,
+                min,
+                max,
+                span,
+            } => {
+                if min.is_finite() && max.is_finite() {
+                    write!(f, "{} must be between {} and {}", channel, min, max)?;
+                } else if min.is_finite() {
+                    write!(f, "{} must be a number of at least {}", channel, min)?;
+                } else {
+                    write!(f, "{} must be a finite number", channel)?;
+                }
+                write!(f, ", got {} at {}", value, span)
+            }
+            Error::BadHexLength { length, span } => write!(
+                f,
+                "Hex color must have 3, 4, 6, 8, 9 or 12 digits, got {} at {}",
+                length, span
+            ),
+            Error::Syntax { message, span } => write!(f, "{} at {}", message, span),
+            Error::Unknown => write!(
+                f,
+                "unknown error, please report this error to the developers"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_display() {
+        let err = Error::ChannelOutOfRange {
+            channel: "Red".into(),
+            value: 256.0,
+            min: 0.0,
+            max: 255.0,
+            span: Span::new(4, 7),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Red must be between 0 and 255, got 256 at 4..7"
+        );
+        assert_eq!(err.shift_span(2).span(), Some(Span::new(6, 9)));
+
+        let err = Error::BadHexLength {
+            length: 5,
+            span: Span::new(0, 6),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Hex color must have 3, 4, 6, 8, 9 or 12 digits, got 5 at 0..6"
+        );
+        assert_eq!(Error::Unknown.span(), None);
+    }
+
+    #[test]
+    fn test_error_eq() {
+        fn assert_eq_trait<T: Eq>() {}
+        assert_eq_trait::<Error>();
+
+        let err = |value| Error::ChannelOutOfRange {
+            channel: "Red".into(),
+            value,
+            min: 0.0,
+            max: 255.0,
+            span: Span::new(4, 7),
+        };
+        assert_eq!(err(256.0), err(256.0));
+        assert_eq!(err(f64::NAN), err(f64::NAN));
+        assert_ne!(err(256.0), err(257.0));
+        assert_ne!(err(256.0), Error::Unknown);
+        assert_eq!(
+            Error::ColorParserError("a".into()),
+            Error::ColorParserError("a".into())
+        );
+        assert_ne!(
+            Error::ColorParserError("a".into()),
+            Error::InvalidParamsError("a".into())
+        );
+    }
+}
@@ -5,27 +5,74 @@
 //! [docs-rs]: https://img.shields.io/badge/docs.rs-66c2a5?style=for-the-badge&labelColor=555555&logo=docs.rs
 //!
 //! A rust crate for working with colors and color spaces.
+//!
+//! # Features
+//!
+//! - `std` (default): implies `alloc`, adds [`Color::random`] and user-defined color spaces.
+//! - `alloc`: parsing, stringify, the color name tables, [`scan_colors`] and [`Color::vec_of`].
+//! - `libm`: the float math for `no_std` builds, required without `std`.
+//! - `rayon`: converts pixel buffers in parallel, see [`convert_buffer`].
+//! - `serde` (default): `Serialize` and `Deserialize` for the public types.
+//!
+//! Without `std` the crate is `no_std`, the conversions, the color space structs, [`colors`],
+//! [`blend`], [`contrast_ratio`], [`delta_e`], [`Color::mix`] and [`Color::color_mix`] are still
+//! available.
 
-#[macro_use]
-extern crate lazy_static;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("color-art requires either the `std` or the `libm` feature");
+
+pub mod chromatic_adaptation;
 mod color;
+#[cfg(feature = "alloc")]
+mod color_buffer;
 mod color_calc;
 mod color_generator;
 mod color_ops;
+#[cfg(feature = "alloc")]
+mod color_scan;
 mod color_space;
+pub mod colors;
 mod conversion;
+#[cfg(feature = "alloc")]
 mod data;
 mod error;
+#[cfg(feature = "alloc")]
 mod parser;
 mod utils;
 
-pub use color::Color;
+pub use color::{
+    equality::ColorKey, linear::LinearRgb, packed::PixelFormat, premultiplied::PremultipliedColor,
+    Color,
+};
+#[cfg(feature = "alloc")]
+pub use color_buffer::*;
 pub use color_calc::blend::*;
 pub use color_calc::contrast_ratio::*;
 pub use color_calc::delta_e::*;
 pub use color_calc::distance::*;
-pub use color_space::ColorSpace;
-use data::chinese_color::CHINESE_COLOR_HASHMAP as CHINESE_COLOR;
-use data::w3cx11::W3CX11_HASHMAP as W3CX11;
-pub use error::Error;
+pub use color_generator::mix::{HueInterpolation, MixOptions, MixSpace};
+#[cfg(feature = "alloc")]
+pub use color_scan::*;
+#[cfg(feature = "std")]
+pub use color_space::{register_color_space, ColorSpaceDef, CustomSpace, ReferenceSpace};
+pub use color_space::{
+    Cmyk, ColorSpace, Hsi, Hsl, Hsla, Hsv, Hwb, Lab, PredefinedSpace, Rgb, Rgba, Xyz, YCbCr, Yiq,
+    Yuv,
+};
+pub use conversion::cmyk::{BlackGeneration, SeparationMode, SeparationOptions};
+pub use error::{Error, ErrorMessage, Span};
+#[cfg(feature = "alloc")]
+pub use parser::context::{ColorScheme, ParseContext};
+pub use utils::float::Float;
+
+/// Re-exports for the exported macros.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::{format, vec};
+}
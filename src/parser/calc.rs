@@ -0,0 +1,195 @@
+use super::core::{Token, TokenKind};
+use crate::Error;
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+};
+
+/// A channel of a color function, a number, a channel keyword or a `calc()` expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// A number and its unit, e.g. `50%`.
+    Number(f64, String),
+    /// A channel keyword of the relative color syntax, e.g. `l` in `hsl(from red h s l)`.
+    Keyword(String),
+    /// An arithmetic operation, the operator is one of `+`, `-`, `*` and `/`.
+    Binary(Box<Expr>, char, Box<Expr>),
+}
+
+/// The result of an [`Expr`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    /// A plain number, it is scaled to the channel at the end like a literal.
+    Number(f64),
+    /// A value already in the range of the channel.
+    Channel(f64),
+}
+
+impl Expr {
+    /// Evaluates the expression.
+    ///
+    /// `scale` converts a number with a unit to the range of the channel, `keyword` resolves
+    /// the channel keywords. Sums of a plain number and a channel value take the plain number as
+    /// a literal of the channel, `calc(l - 20)` is `calc(l - 20%)` for `hsl`, while products
+    /// keep it as a factor.
+    pub fn eval<S, K>(&self, scale: &S, keyword: &K) -> Result<Value, Error>
+    where
+        S: Fn(f64, &str) -> Option<f64>,
+        K: Fn(&str) -> Option<f64>,
+    {
+        match self {
+            Expr::Number(value, unit) if unit.is_empty() => Ok(Value::Number(*value)),
+            Expr::Number(value, unit) => scale(*value, unit).map(Value::Channel).ok_or_else(|| {
+                Error::ColorParserError(format!("Invalid unit of '{}{}'", value, unit).into())
+            }),
+            Expr::Keyword(name) => keyword(name).map(Value::Channel).ok_or_else(|| {
+                Error::ColorParserError(format!("Unknown channel keyword '{}'", name).into())
+            }),
+            Expr::Binary(lhs, op, rhs) => {
+                let lhs = lhs.eval(scale, keyword)?;
+                let rhs = rhs.eval(scale, keyword)?;
+                let as_channel = |value: Value| match value {
+                    Value::Number(value) => scale(value, "").unwrap_or(value),
+                    Value::Channel(value) => value,
+                };
+                Ok(match (lhs, *op, rhs) {
+                    (Value::Number(a), op, Value::Number(b)) => Value::Number(apply(a, op, b)),
+                    (Value::Channel(a), op @ ('*' | '/'), Value::Number(b))
+                    | (Value::Number(a), op @ '*', Value::Channel(b)) => {
+                        Value::Channel(apply(a, op, b))
+                    }
+                    // the ratio of two channel values is a factor
+                    (Value::Channel(a), '/', Value::Channel(b)) => Value::Number(a / b),
+                    (lhs, op, rhs) => Value::Channel(apply(as_channel(lhs), op, as_channel(rhs))),
+                })
+            }
+        }
+    }
+}
+
+fn apply(a: f64, op: char, b: f64) -> f64 {
+    match op {
+        '+' => a + b,
+        '-' => a - b,
+        '*' => a * b,
+        _ => a / b,
+    }
+}
+
+/// Parses the `calc()` starting at the `calc` identifier at `current`.
+///
+/// `current` is left at the closing parenthesis.
+pub fn parse_calc(tokens: &[Token], current: &mut usize) -> Result<Expr, Error> {
+    *current += 1;
+    expect(tokens, current, TokenKind::LeftParen)?;
+    let expr = parse_sum(tokens, current)?;
+    skip_whitespace(tokens, current);
+    expect(tokens, current, TokenKind::RightParen)?;
+    *current -= 1;
+    Ok(expr)
+}
+
+fn parse_sum(tokens: &[Token], current: &mut usize) -> Result<Expr, Error> {
+    let mut expr = parse_product(tokens, current)?;
+    loop {
+        skip_whitespace(tokens, current);
+        match tokens.get(*current) {
+            Some(token) if token.kind == TokenKind::Operator && token.value != "*" => {
+                let op = if token.value == "+" { '+' } else { '-' };
+                *current += 1;
+                let rhs = parse_product(tokens, current)?;
+                expr = Expr::Binary(Box::new(expr), op, Box::new(rhs));
+            }
+            _ => return Ok(expr),
+        }
+    }
+}
+
+fn parse_product(tokens: &[Token], current: &mut usize) -> Result<Expr, Error> {
+    let mut expr = parse_factor(tokens, current)?;
+    loop {
+        skip_whitespace(tokens, current);
+        let op = match tokens.get(*current) {
+            Some(token) if token.kind == TokenKind::Operator && token.value == "*" => '*',
+            Some(token) if token.kind == TokenKind::Slash => '/',
+            _ => return Ok(expr),
+        };
+        *current += 1;
+        let rhs = parse_factor(tokens, current)?;
+        expr = Expr::Binary(Box::new(expr), op, Box::new(rhs));
+    }
+}
+
+fn parse_factor(tokens: &[Token], current: &mut usize) -> Result<Expr, Error> {
+    skip_whitespace(tokens, current);
+    let token = tokens
+        .get(*current)
+        .ok_or_else(|| Error::ColorParserError("Unexpected end of calc()".into()))?;
+    let expr = match token.kind {
+        TokenKind::Value => {
+            let (value, unit) = split_unit(&token.value)?;
+            Expr::Number(value, unit.to_string())
+        }
+        TokenKind::LeftParen => {
+            *current += 1;
+            let expr = parse_sum(tokens, current)?;
+            skip_whitespace(tokens, current);
+            expect(tokens, current, TokenKind::RightParen)?;
+            return Ok(expr);
+        }
+        TokenKind::Identifier if token.value == "calc" => parse_calc(tokens, current)?,
+        TokenKind::Identifier => match token.value.as_str() {
+            "pi" => Expr::Number(core::f64::consts::PI, String::new()),
+            "e" => Expr::Number(core::f64::consts::E, String::new()),
+            "none" => Expr::Number(0.0, String::new()),
+            name => Expr::Keyword(name.to_string()),
+        },
+        _ => {
+            return Err(Error::ColorParserError(
+                format!("Unexpected '{}' in calc()", token.value).into(),
+            ))
+        }
+    };
+    *current += 1;
+    Ok(expr)
+}
+
+/// Splits a number token into the number and its unit, e.g. `50%` or `0.5turn`.
+pub fn split_unit(value: &str) -> Result<(f64, &str), Error> {
+    let number = value.trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '%');
+    let unit = &value[number.len()..];
+    match number.parse::<f64>() {
+        Ok(number) => Ok((number, unit)),
+        Err(_) => Err(Error::ColorParserError("Invalid value".into())),
+    }
+}
+
+fn skip_whitespace(tokens: &[Token], current: &mut usize) {
+    while tokens
+        .get(*current)
+        .is_some_and(|token| token.kind == TokenKind::Whitespace)
+    {
+        *current += 1;
+    }
+}
+
+fn expect(tokens: &[Token], current: &mut usize, kind: TokenKind) -> Result<(), Error> {
+    match tokens.get(*current) {
+        Some(token) if token.kind == kind => {
+            *current += 1;
+            Ok(())
+        }
+        _ => Err(Error::ColorParserError(
+            format!(
+                "Expected '{}' in calc()",
+                if kind == TokenKind::LeftParen {
+                    "("
+                } else {
+                    ")"
+                }
+            )
+            .into(),
+        )),
+    }
+}
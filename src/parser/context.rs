@@ -0,0 +1,185 @@
+use super::mix::split_args;
+use crate::{Color, Error, Span};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The color scheme of the document, it picks the color of `light-dark()` and the default
+/// system colors.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColorScheme {
+    #[default]
+    Light,
+    Dark,
+}
+
+/// The system colors of CSS Color 4 in a light scheme, close to the defaults of the browsers.
+const LIGHT_SYSTEM_COLORS: &[(&str, Color)] = &[
+    ("AccentColor", Color::new_const(0.0, 117.0, 255.0, 1.0)),
+    (
+        "AccentColorText",
+        Color::new_const(255.0, 255.0, 255.0, 1.0),
+    ),
+    ("ActiveText", Color::new_const(255.0, 0.0, 0.0, 1.0)),
+    ("ButtonBorder", Color::new_const(118.0, 118.0, 118.0, 1.0)),
+    ("ButtonFace", Color::new_const(239.0, 239.0, 239.0, 1.0)),
+    ("ButtonText", Color::new_const(0.0, 0.0, 0.0, 1.0)),
+    ("Canvas", Color::new_const(255.0, 255.0, 255.0, 1.0)),
+    ("CanvasText", Color::new_const(0.0, 0.0, 0.0, 1.0)),
+    ("Field", Color::new_const(255.0, 255.0, 255.0, 1.0)),
+    ("FieldText", Color::new_const(0.0, 0.0, 0.0, 1.0)),
+    ("GrayText", Color::new_const(128.0, 128.0, 128.0, 1.0)),
+    ("Highlight", Color::new_const(181.0, 213.0, 255.0, 1.0)),
+    ("HighlightText", Color::new_const(0.0, 0.0, 0.0, 1.0)),
+    ("LinkText", Color::new_const(0.0, 0.0, 238.0, 1.0)),
+    ("Mark", Color::new_const(255.0, 255.0, 0.0, 1.0)),
+    ("MarkText", Color::new_const(0.0, 0.0, 0.0, 1.0)),
+    ("SelectedItem", Color::new_const(0.0, 117.0, 255.0, 1.0)),
+    (
+        "SelectedItemText",
+        Color::new_const(255.0, 255.0, 255.0, 1.0),
+    ),
+    ("VisitedText", Color::new_const(85.0, 26.0, 139.0, 1.0)),
+];
+
+/// The system colors of CSS Color 4 in a dark scheme, close to the defaults of the browsers.
+const DARK_SYSTEM_COLORS: &[(&str, Color)] = &[
+    ("AccentColor", Color::new_const(153.0, 200.0, 255.0, 1.0)),
+    ("AccentColorText", Color::new_const(0.0, 0.0, 0.0, 1.0)),
+    ("ActiveText", Color::new_const(255.0, 158.0, 158.0, 1.0)),
+    ("ButtonBorder", Color::new_const(107.0, 107.0, 107.0, 1.0)),
+    ("ButtonFace", Color::new_const(107.0, 107.0, 107.0, 1.0)),
+    ("ButtonText", Color::new_const(255.0, 255.0, 255.0, 1.0)),
+    ("Canvas", Color::new_const(18.0, 18.0, 18.0, 1.0)),
+    ("CanvasText", Color::new_const(255.0, 255.0, 255.0, 1.0)),
+    ("Field", Color::new_const(59.0, 59.0, 59.0, 1.0)),
+    ("FieldText", Color::new_const(255.0, 255.0, 255.0, 1.0)),
+    ("GrayText", Color::new_const(128.0, 128.0, 128.0, 1.0)),
+    ("Highlight", Color::new_const(153.0, 200.0, 255.0, 1.0)),
+    ("HighlightText", Color::new_const(0.0, 0.0, 0.0, 1.0)),
+    ("LinkText", Color::new_const(158.0, 158.0, 255.0, 1.0)),
+    ("Mark", Color::new_const(255.0, 255.0, 0.0, 1.0)),
+    ("MarkText", Color::new_const(0.0, 0.0, 0.0, 1.0)),
+    ("SelectedItem", Color::new_const(153.0, 200.0, 255.0, 1.0)),
+    ("SelectedItemText", Color::new_const(0.0, 0.0, 0.0, 1.0)),
+    ("VisitedText", Color::new_const(208.0, 173.0, 240.0, 1.0)),
+];
+
+/// What the keywords depending on the document resolve to, see
+/// [`Color::from_str_with_context`].
+///
+/// # Examples
+///
+/// ```
+/// use color_art::{Color, ColorScheme, ParseContext};
+///
+/// let context = ParseContext {
+///     current_color: Some(Color::new(255, 0, 0, 1.0)),
+///     color_scheme: ColorScheme::Dark,
+///     ..Default::default()
+/// };
+/// assert_eq!(context.system_color("canvas").unwrap().hex(), "#121212");
+///
+/// let color = Color::from_str_with_context("rgb(from currentColor r g b / 50%)", &context);
+/// assert_eq!(color.unwrap(), Color::new(255, 0, 0, 0.5));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct ParseContext<'a> {
+    /// The color of `currentColor`, `currentColor` is an error without it.
+    pub current_color: Option<Color>,
+    /// Picks the color of `light-dark()` and the default system colors.
+    pub color_scheme: ColorScheme,
+    /// The system colors like `Canvas` by their name, ignoring the case. The defaults of the
+    /// color scheme are used without it.
+    pub system_colors: Option<&'a [(&'a str, Color)]>,
+}
+
+impl ParseContext<'_> {
+    /// Looks up a system color like `Canvas` or `CanvasText`, ignoring the case.
+    pub fn system_color(&self, name: &str) -> Option<Color> {
+        let table = self.system_colors.unwrap_or(match self.color_scheme {
+            ColorScheme::Light => LIGHT_SYSTEM_COLORS,
+            ColorScheme::Dark => DARK_SYSTEM_COLORS,
+        });
+        table
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, color)| *color)
+    }
+}
+
+/// Resolves `transparent`, `currentColor` and the system colors.
+pub fn parse_keyword(input: &str, context: &ParseContext) -> Option<Result<Color, Error>> {
+    match input {
+        "transparent" => Some(Ok(Color::new(0.0, 0.0, 0.0, 0.0))),
+        "currentcolor" => {
+            Some(context.current_color.ok_or_else(|| {
+                Error::ColorParserError("No current color for 'currentColor'".into())
+            }))
+        }
+        name => context.system_color(name).map(Ok),
+    }
+}
+
+/// Parses `light-dark(<light color>, <dark color>)`, picking the color of the color scheme.
+pub fn parse_light_dark(input: &str, context: &ParseContext) -> Result<Color, Error> {
+    let args_start = "light-dark(".len();
+    let args = input
+        .get(args_start..)
+        .and_then(|args| args.strip_suffix(')'))
+        .ok_or_else(|| Error::ColorParserError("Unmatched left parenthesis".into()))?;
+    let args = split_args(args, args_start);
+    if args.len() != 2 {
+        return Err(Error::WrongArity {
+            function: "light-dark".into(),
+            expected: 2,
+            found: args.len(),
+            span: Span::new(0, input.len()),
+        });
+    }
+
+    let (offset, arg) = match context.color_scheme {
+        ColorScheme::Light => args[0],
+        ColorScheme::Dark => args[1],
+    };
+    Color::from_str_with_context(arg, context).map_err(|err| err.shift_span(offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_color() {
+        let context = ParseContext::default();
+        assert_eq!(context.system_color("CanvasText").unwrap().hex(), "#000");
+        assert_eq!(context.system_color("canvastext").unwrap().hex(), "#000");
+        assert_eq!(context.system_color("red"), None);
+
+        let table = [("Canvas", Color::new(1.0, 2.0, 3.0, 1.0))];
+        let context = ParseContext {
+            system_colors: Some(&table),
+            ..Default::default()
+        };
+        assert_eq!(context.system_color("canvas").unwrap().hex(), "#010203");
+        assert_eq!(context.system_color("CanvasText"), None);
+    }
+
+    #[test]
+    fn test_parse_light_dark() {
+        let dark = ParseContext {
+            color_scheme: ColorScheme::Dark,
+            ..Default::default()
+        };
+        let input = "light-dark(#fff, rgb(0 0 0 / 50%))";
+        let color = parse_light_dark(input, &ParseContext::default()).unwrap();
+        assert_eq!(color.hex(), "#fff");
+        let color = parse_light_dark(input, &dark).unwrap();
+        assert_eq!(color.rgba(), "rgba(0, 0, 0, 0.5)");
+
+        assert!(parse_light_dark("light-dark(#fff)", &dark).is_err());
+        let err = parse_light_dark("light-dark(#fff, rgb(0 0 300))", &dark).unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(25, 28)));
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{ColorSpace, Error};
+use super::calc::{self, Expr, Value};
+use crate::{
+    conversion, utils::Float, Color, ColorSpace, Error, ParseContext, PredefinedSpace, Span,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,6 +16,7 @@ use serde::{Deserialize, Serialize};
 pub struct Token {
     pub kind: TokenKind,
     pub value: String,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
@@ -18,41 +27,69 @@ pub enum TokenKind {
     LeftParen,
     RightParen,
     Comma,
+    Slash,
+    /// `+`, `-` or `*` of a `calc()`, the `/` is a [`TokenKind::Slash`].
+    Operator,
+    /// A hex color, e.g. `#0af`.
+    Hash,
     Whitespace,
     Eof,
 }
 
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Parser {
+pub struct Parser<'a> {
     pub tokens: Vec<Token>,
     pub current: usize,
     pub values: Vec<f64>,
+    pub alpha: Option<f64>,
     pub color_space: ColorSpace,
+    /// The space of the `color()` function, `color_space` is then unknown.
+    pub predefined: Option<PredefinedSpace>,
+    /// The origin color of the relative color syntax, e.g. `red` in `hsl(from red h s l)`.
+    pub origin: Option<Color>,
+    /// The tokenized input, to parse the origin color with.
+    pub input: String,
+    /// Resolves the keywords of the origin color, e.g. `currentColor`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub context: ParseContext<'a>,
 }
 
-type PeekableChars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+type PeekableChars<'a> = core::iter::Peekable<core::str::CharIndices<'a>>;
 
-impl Parser {
+impl<'a> Parser<'a> {
+    #[cfg(test)]
     pub fn new() -> Self {
+        Self::with_context(ParseContext::default())
+    }
+    pub fn with_context(context: ParseContext<'a>) -> Self {
         Self {
             tokens: Vec::new(),
             current: 0,
             values: Vec::new(),
+            alpha: None,
             color_space: ColorSpace::Unknown,
+            predefined: None,
+            origin: None,
+            input: String::new(),
+            context,
         }
     }
     pub fn tokenize(&mut self, input: &str) -> &mut Self {
-        let mut chars = input.chars().peekable();
+        let mut chars = input.char_indices().peekable();
+        self.input = input.to_string();
 
-        while let Some(&c) = chars.peek() {
+        while let Some(&(_, c)) = chars.peek() {
             match c {
-                '0'..='9' | '%' | '.' | '-' => self.tokenize_number(&mut chars),
+                '-' | '+' | '*' if !is_number_start(&chars) => self.tokenize_arithmetic(&mut chars),
+                '0'..='9' | '%' | '.' | '-' | '+' => self.tokenize_number(&mut chars),
+                '#' => self.tokenize_hash(&mut chars),
                 'a'..='z' | 'A'..='Z' => self.tokenize_identifier(&mut chars),
                 '(' => self.tokenize_left_paren(&mut chars),
                 ')' => self.tokenize_right_paren(&mut chars),
                 ',' => self.tokenize_comma(&mut chars),
-                ' ' | '\t' | '\n' => self.tokenize_whitespace(&mut chars),
+                '/' => self.tokenize_slash(&mut chars),
+                ' ' | '\t' | '\n' | '\r' => self.tokenize_whitespace(&mut chars),
                 _ => self.tokenize_operator(&mut chars),
             }
         }
@@ -60,85 +97,319 @@ impl Parser {
         self.tokens.push(Token {
             kind: TokenKind::Eof,
             value: String::new(),
+            span: Span::new(input.len(), input.len()),
         });
 
         self
     }
 
+    /// Validates the tokens and collects the values.
+    ///
+    /// Both the legacy syntax `rgba(255, 0, 0, 0.5)` and the CSS Color 4 syntax
+    /// `rgb(255 0 0 / 50%)` are accepted, see [`css_value`] for the units.
+    /// `rgba` and `hsla` are aliases of `rgb` and `hsl`, the alpha ends up in `alpha`.
+    ///
+    /// The `color()` function like `color(display-p3 1 0 0)` sets `predefined`.
+    ///
+    /// The relative color syntax like `hsl(from red h s calc(l - 20%))` sets `origin`, the
+    /// channel keywords are resolved from it, see [`channel_keywords`]. The alpha defaults to the
+    /// alpha of the origin.
+    ///
+    /// The legacy syntax separates every value by exactly one comma, it cannot be mixed with
+    /// spaces or a slash.
     pub fn validate(&mut self) -> Result<(), Error> {
         let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut slash = None;
+        let mut legacy = false;
+        // the comma after the last value, it must be followed by another value
+        let mut comma: Option<Span> = None;
+        let mut css_color = false;
+        let mut start = 0;
 
         while let Some(token) = self.tokens.get(self.current) {
+            let is_function = self.color_space != ColorSpace::Unknown || css_color;
+            let count = components.len();
             match token.kind {
                 TokenKind::LeftParen => {
-                    stack.push(token);
+                    stack.push(token.span);
                 }
                 TokenKind::RightParen => {
-                    if stack.pop().is_some() {
-                        // do nothing
-                    } else {
-                        return Err(Error::ColorParserError(
-                            "Unmatched right parenthesis".to_string(),
-                        ));
+                    if stack.pop().is_none() {
+                        return Err(Error::Syntax {
+                            message: "Unmatched right parenthesis".into(),
+                            span: token.span,
+                        });
+                    }
+                    if let Some(span) = comma {
+                        return Err(Error::Syntax {
+                            message: "Unexpected trailing ','".into(),
+                            span,
+                        });
+                    }
+                    // nothing may follow the closing parenthesis of the function
+                    if stack.is_empty() {
+                        if let Some(span) = trailing_span(&self.tokens[self.current + 1..]) {
+                            return Err(Error::Syntax {
+                                message: format!(
+                                    "Unexpected '{}' after the color",
+                                    &self.input[span.range()]
+                                )
+                                .into(),
+                                span,
+                            });
+                        }
+                    }
+                }
+                TokenKind::Comma => {
+                    if components.is_empty() || comma.is_some() {
+                        return Err(Error::Syntax {
+                            message: "Unexpected ','".into(),
+                            span: token.span,
+                        });
                     }
+                    if slash.is_some() {
+                        return Err(Error::Syntax {
+                            message: "Mixed comma and slash separators".into(),
+                            span: token.span,
+                        });
+                    }
+                    // the values before the first comma were separated by spaces
+                    if !legacy && components.len() > 1 {
+                        return Err(Error::Syntax {
+                            message: "Mixed comma and space separators".into(),
+                            span: token.span,
+                        });
+                    }
+                    legacy = true;
+                    comma = Some(token.span);
+                }
+                TokenKind::Slash => {
+                    if legacy {
+                        return Err(Error::Syntax {
+                            message: "Mixed comma and slash separators".into(),
+                            span: token.span,
+                        });
+                    }
+                    if slash.is_some() {
+                        return Err(Error::Syntax {
+                            message: "Only one slash is allowed".into(),
+                            span: token.span,
+                        });
+                    }
+                    slash = Some(components.len());
                 }
                 TokenKind::Value => {
-                    if token.value.contains('%') {
-                        let value = token.value.replace('%', "");
-                        if let Ok(value) = value.parse::<f64>() {
-                            self.values.push(value / 100.0);
-                        } else {
-                            return Err(Error::ColorParserError("Invalid value".to_string()));
+                    let (value, unit) =
+                        calc::split_unit(&token.value).map_err(|err| err.with_span(token.span))?;
+                    components.push((Expr::Number(value, unit.to_string()), token.span));
+                }
+                TokenKind::Identifier
+                    if is_function
+                        && token.value == "from"
+                        && self.origin.is_none()
+                        && self.predefined.is_none()
+                        && components.is_empty() =>
+                {
+                    let span = origin_span(&self.tokens, &mut self.current)?;
+                    let origin =
+                        Color::from_str_with_context(&self.input[span.range()], &self.context)
+                            .map_err(|err| err.shift_span(span.start))?;
+                    self.origin = Some(origin);
+                }
+                TokenKind::Identifier if is_function && token.value == "calc" => {
+                    let start = token.span.start;
+                    let expr = calc::parse_calc(&self.tokens, &mut self.current)?;
+                    let end = self.tokens[self.current].span.end;
+                    components.push((expr, Span::new(start, end)));
+                }
+                TokenKind::Identifier
+                    if self.color_space != ColorSpace::Unknown || self.predefined.is_some() =>
+                {
+                    // `none` is a missing value, it is used as `0`
+                    let expr = match token.value.as_str() {
+                        "none" => Expr::Number(0.0, String::new()),
+                        name => Expr::Keyword(name.to_string()),
+                    };
+                    components.push((expr, token.span));
+                }
+                TokenKind::Identifier if css_color => {
+                    match PredefinedSpace::from_name(&token.value) {
+                        Some(space) => self.predefined = Some(space),
+                        None => {
+                            return Err(Error::UnknownFunction {
+                                name: token.value.clone().into(),
+                                span: token.span,
+                            })
                         }
-                    } else if let Ok(value) = token.value.parse::<f64>() {
-                        self.values.push(value);
-                    } else {
-                        return Err(Error::ColorParserError("Invalid value".to_string()));
                     }
                 }
+                TokenKind::Identifier if token.value == "color" => {
+                    css_color = true;
+                    start = token.span.start;
+                }
                 TokenKind::Identifier => {
                     let color_space = ColorSpace::from(&token.value);
                     if color_space == ColorSpace::Unknown {
-                        return Err(Error::ColorParserError("Invalid input".to_string()));
+                        return Err(Error::UnknownFunction {
+                            name: token.value.clone().into(),
+                            span: token.span,
+                        });
                     } else {
                         self.color_space = color_space;
+                        start = token.span.start;
                     }
                 }
+                TokenKind::Operator | TokenKind::Hash => {
+                    return Err(Error::Syntax {
+                        message: "Invalid value".into(),
+                        span: token.span,
+                    })
+                }
                 _ => {}
             }
+            if components.len() > count {
+                let span = components[count].1;
+                if legacy && comma.take().is_none() {
+                    return Err(Error::Syntax {
+                        message: "Mixed comma and space separators".into(),
+                        span,
+                    });
+                }
+            }
 
             self.current += 1;
         }
 
-        match self.color_space {
-            ColorSpace::Unknown => {
-                return Err(Error::ColorParserError("No color space found".to_string()))
+        // an unclosed function has no meaningful number of values
+        if let Some(&paren) = stack.last() {
+            return Err(Error::Syntax {
+                message: "Unmatched left parenthesis".into(),
+                span: paren,
+            });
+        }
+
+        // the whole color, from the function name to the last token
+        let end = self
+            .tokens
+            .iter()
+            .rev()
+            .find(|token| !matches!(token.kind, TokenKind::Eof | TokenKind::Whitespace))
+            .map_or(start, |token| token.span.end);
+        let span = Span::new(start, end);
+
+        let color_space = match self.color_space {
+            ColorSpace::Unknown if self.predefined.is_none() => {
+                return Err(Error::Syntax {
+                    message: "No color space found".into(),
+                    span: trailing_span(&self.tokens).unwrap_or_default(),
+                })
             }
-            _ => {
-                if self.values.len() != self.color_space.value_count() {
-                    return Err(Error::ColorParserError(
-                        "Invalid number of values".to_string(),
-                    ));
-                }
-                self.color_space.valid(&self.values)?;
+            ColorSpace::RGBA => ColorSpace::RGB,
+            ColorSpace::HSLA => ColorSpace::HSL,
+            color_space => color_space,
+        };
+
+        let alpha = match slash {
+            Some(index) if index + 1 == components.len() => components.pop(),
+            Some(_) => {
+                return Err(Error::WrongArity {
+                    function: match self.predefined {
+                        Some(space) => format!("color({})", space.name()).into(),
+                        None => self.color_space.function_name().into(),
+                    },
+                    expected: match self.predefined {
+                        Some(_) => 3 + 1,
+                        None => color_space.value_count() + 1,
+                    },
+                    found: components.len(),
+                    span,
+                })
+            }
+            // the legacy `rgba(255, 0, 0, 0.5)`
+            None if matches!(color_space, ColorSpace::RGB | ColorSpace::HSL)
+                && components.len() == color_space.value_count() + 1 =>
+            {
+                components.pop()
             }
+            None => None,
+        };
+
+        // the channel values of the origin color, and its alpha last
+        let origin = self.origin.map(|origin| match self.predefined {
+            Some(space) => {
+                let [a, b, c] = conversion::predefined::rgb2predefined(space, origin.rgb);
+                [a, b, c, origin.alpha]
+            }
+            None => {
+                let mut values = origin.values_of(color_space);
+                values[color_space.value_count().min(3)] = origin.alpha;
+                values
+            }
+        });
+        let keywords = channel_keywords(color_space, self.predefined);
+        let keyword = |name: &str| {
+            let origin = origin?;
+            match keywords.iter().position(|&keyword| keyword == name) {
+                Some(index) => Some(origin[index]),
+                None if name == "alpha" => Some(origin[3]),
+                None => None,
+            }
+        };
+
+        let mut value_spans = Vec::new();
+        for (index, (expr, span)) in components.iter().enumerate() {
+            let scale = |value: f64, unit: &str| match self.predefined {
+                // the channels of `color()` are `0..=1` for the gamut, also as percentages
+                Some(_) => match unit {
+                    "" => Some(value),
+                    "%" => Some(value / 100.0),
+                    _ => None,
+                },
+                None => css_value(color_space, index, value, unit, legacy),
+            };
+            let value = eval(expr, &scale, &keyword).map_err(|err| err.with_span(*span))?;
+            // also the hues computed by a `calc()`
+            self.values.push(match hue_index(color_space) {
+                Some(hue) if hue == index && self.predefined.is_none() => wrap_hue(value),
+                _ => value,
+            });
+            value_spans.push(*span);
+        }
+        match self.predefined {
+            Some(space) => space.valid_spanned(&self.values, &value_spans, span)?,
+            None => color_space.valid_spanned(&self.values, &value_spans, span)?,
         }
 
-        if !self.values.is_empty() && stack.is_empty() {
-            Ok(())
-        } else if self.values.is_empty() {
-            Err(Error::ColorParserError("No values found".to_string()))
+        if let Some((expr, span)) = alpha {
+            let scale = |value: f64, unit: &str| match unit {
+                "" => Some(value),
+                "%" => Some(value / 100.0),
+                _ => None,
+            };
+            let alpha = eval(&expr, &scale, &keyword).map_err(|err| err.with_span(span))?;
+            ColorSpace::valid_alpha(alpha, span)?;
+            self.alpha = Some(alpha);
+        } else if let Some(origin) = self.origin {
+            self.alpha = Some(origin.alpha);
+        }
+        self.color_space = color_space;
+
+        if self.values.is_empty() {
+            Err(Error::Syntax {
+                message: "No values found".into(),
+                span,
+            })
         } else {
-            Err(Error::ColorParserError(
-                "Unmatched left parenthesis".to_string(),
-            ))
+            Ok(())
         }
     }
 
     fn tokenize_number(&mut self, chars: &mut PeekableChars) {
         let mut value = String::new();
+        let start = next_index(chars);
 
-        while let Some(&c) = chars.peek() {
+        while let Some(&(_, c)) = chars.peek() {
             match c {
                 '0'..='9' | '.' => {
                     value.push(c);
@@ -150,13 +421,29 @@ impl Parser {
                     chars.next();
                     break;
                 }
-                '-' => {
+                '-' | '+' => {
                     if !value.is_empty() {
                         break;
                     }
-                    // - must be the first character in the string
+                    // the sign must be the first character in the string
+                    value.push(c);
+                    chars.next();
+                }
+                'e' | 'E' if is_exponent(chars) => {
                     value.push(c);
                     chars.next();
+                    if let Some(&(_, sign @ ('-' | '+'))) = chars.peek() {
+                        value.push(sign);
+                        chars.next();
+                    }
+                }
+                'a'..='z' | 'A'..='Z' => {
+                    // a unit like `deg` must be the last part of the string
+                    while let Some(&(_, c @ ('a'..='z' | 'A'..='Z'))) = chars.peek() {
+                        value.push(c);
+                        chars.next();
+                    }
+                    break;
                 }
                 _ => {
                     break;
@@ -166,16 +453,19 @@ impl Parser {
 
         self.tokens.push(Token {
             kind: TokenKind::Value,
+            span: Span::new(start, start + value.len()),
             value,
         });
     }
 
     fn tokenize_identifier(&mut self, chars: &mut PeekableChars) {
         let mut value = String::new();
+        let start = next_index(chars);
 
-        while let Some(&c) = chars.peek() {
+        while let Some(&(_, c)) = chars.peek() {
             match c {
-                'a'..='z' | 'A'..='Z' => {
+                // digits and dashes only after the first letter, e.g. `display-p3`
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '-' => {
                     value.push(c);
                     chars.next();
                 }
@@ -187,43 +477,94 @@ impl Parser {
 
         self.tokens.push(Token {
             kind: TokenKind::Identifier,
+            span: Span::new(start, start + value.len()),
             value,
         });
     }
 
     fn tokenize_left_paren(&mut self, chars: &mut PeekableChars) {
+        let start = next_index(chars);
         chars.next();
 
         self.tokens.push(Token {
             kind: TokenKind::LeftParen,
             value: String::from("("),
+            span: Span::new(start, start + 1),
         });
     }
 
     fn tokenize_right_paren(&mut self, chars: &mut PeekableChars) {
+        let start = next_index(chars);
         chars.next();
 
         self.tokens.push(Token {
             kind: TokenKind::RightParen,
             value: String::from(")"),
+            span: Span::new(start, start + 1),
         });
     }
 
     fn tokenize_comma(&mut self, chars: &mut PeekableChars) {
+        let start = next_index(chars);
         chars.next();
 
         self.tokens.push(Token {
             kind: TokenKind::Comma,
             value: String::from(","),
+            span: Span::new(start, start + 1),
+        });
+    }
+
+    fn tokenize_slash(&mut self, chars: &mut PeekableChars) {
+        let start = next_index(chars);
+        chars.next();
+
+        self.tokens.push(Token {
+            kind: TokenKind::Slash,
+            value: String::from("/"),
+            span: Span::new(start, start + 1),
+        });
+    }
+
+    fn tokenize_arithmetic(&mut self, chars: &mut PeekableChars) {
+        let start = next_index(chars);
+        if let Some((_, c)) = chars.next() {
+            self.tokens.push(Token {
+                kind: TokenKind::Operator,
+                value: c.to_string(),
+                span: Span::new(start, start + 1),
+            });
+        }
+    }
+
+    fn tokenize_hash(&mut self, chars: &mut PeekableChars) {
+        let mut value = String::new();
+        let start = next_index(chars);
+
+        while let Some(&(_, c)) = chars.peek() {
+            if (c == '#' && value.is_empty()) || c.is_ascii_alphanumeric() {
+                value.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        self.tokens.push(Token {
+            kind: TokenKind::Hash,
+            span: Span::new(start, start + value.len()),
+            value,
         });
     }
 
     fn tokenize_whitespace(&mut self, chars: &mut PeekableChars) {
+        let start = next_index(chars);
         chars.next();
 
         self.tokens.push(Token {
             kind: TokenKind::Whitespace,
             value: String::from(" "),
+            span: Span::new(start, start + 1),
         });
     }
 
@@ -232,6 +573,185 @@ impl Parser {
     }
 }
 
+/// Byte offset of the next character.
+fn next_index(chars: &mut PeekableChars) -> usize {
+    chars.peek().map(|&(i, _)| i).unwrap_or_default()
+}
+
+/// Whether the sign at the start of `chars` belongs to a number like `-5` or `+.5`.
+fn is_number_start(chars: &PeekableChars) -> bool {
+    let mut ahead = chars.clone().map(|(_, c)| c);
+    matches!(ahead.next(), Some('-' | '+'))
+        && ahead.next().is_some_and(|c| c.is_ascii_digit() || c == '.')
+}
+
+/// The span from the first token to the end of the last one, without the whitespace around,
+/// `None` for no tokens.
+fn trailing_span(tokens: &[Token]) -> Option<Span> {
+    let is_input = |token: &&Token| !matches!(token.kind, TokenKind::Whitespace | TokenKind::Eof);
+    let first = tokens.iter().find(is_input)?;
+    let last = tokens.iter().rfind(is_input)?;
+    Some(Span::new(first.span.start, last.span.end))
+}
+
+/// The span of the origin color after the `from` at `current`, e.g. `#0af`, `red` or
+/// `rgb(0 170 255)`.
+///
+/// `current` is left at the last token of the origin.
+fn origin_span(tokens: &[Token], current: &mut usize) -> Result<Span, Error> {
+    let from = tokens[*current].span;
+    *current += 1;
+    while tokens
+        .get(*current)
+        .is_some_and(|token| token.kind == TokenKind::Whitespace)
+    {
+        *current += 1;
+    }
+    let first = match tokens.get(*current) {
+        Some(token) if matches!(token.kind, TokenKind::Hash | TokenKind::Identifier) => token,
+        _ => {
+            return Err(Error::Syntax {
+                message: "Expected an origin color after 'from'".into(),
+                span: from,
+            })
+        }
+    };
+    if tokens.get(*current + 1).map(|token| &token.kind) != Some(&TokenKind::LeftParen) {
+        return Ok(first.span);
+    }
+
+    // a color function, up to its closing parenthesis
+    let mut depth = 0;
+    for (index, token) in tokens.iter().enumerate().skip(*current + 1) {
+        match token.kind {
+            TokenKind::LeftParen => depth += 1,
+            TokenKind::RightParen => depth -= 1,
+            _ => continue,
+        }
+        if depth == 0 {
+            *current = index;
+            return Ok(Span::new(first.span.start, token.span.end));
+        }
+    }
+    Err(Error::Syntax {
+        message: "Unmatched left parenthesis".into(),
+        span: tokens[*current + 1].span,
+    })
+}
+
+/// Evaluates a channel, a plain number is scaled like a literal.
+fn eval<S, K>(expr: &Expr, scale: &S, keyword: &K) -> Result<f64, Error>
+where
+    S: Fn(f64, &str) -> Option<f64>,
+    K: Fn(&str) -> Option<f64>,
+{
+    match expr.eval(scale, keyword)? {
+        Value::Number(value) => {
+            scale(value, "").ok_or_else(|| Error::ColorParserError("Invalid value".into()))
+        }
+        Value::Channel(value) => Ok(value),
+    }
+}
+
+/// The channel keywords of the relative color syntax, `alpha` is available for every function.
+fn channel_keywords(
+    color_space: ColorSpace,
+    predefined: Option<PredefinedSpace>,
+) -> &'static [&'static str] {
+    match predefined {
+        Some(space) if space.is_xyz() => return &["x", "y", "z"],
+        Some(_) => return &["r", "g", "b"],
+        None => {}
+    }
+    match color_space {
+        ColorSpace::RGB | ColorSpace::RGBA => &["r", "g", "b"],
+        ColorSpace::HSI => &["h", "s", "i"],
+        ColorSpace::HSL | ColorSpace::HSLA => &["h", "s", "l"],
+        ColorSpace::HSV => &["h", "s", "v"],
+        ColorSpace::HWB => &["h", "w", "b"],
+        ColorSpace::CMYK => &["c", "m", "y", "k"],
+        ColorSpace::XYZ => &["x", "y", "z"],
+        ColorSpace::YIQ => &["y", "i", "q"],
+        ColorSpace::YUV => &["y", "u", "v"],
+        ColorSpace::YCbCr => &["y", "cb", "cr"],
+        ColorSpace::Lab | ColorSpace::Oklab => &["l", "a", "b"],
+        ColorSpace::Lch | ColorSpace::Oklch => &["l", "c", "h"],
+        _ => &[],
+    }
+}
+
+/// Whether the next `e` starts an exponent like `e3` or `e-3`, and not a unit like `em`.
+fn is_exponent(chars: &PeekableChars) -> bool {
+    let mut ahead = chars.clone().skip(1).map(|(_, c)| c);
+    match ahead.next() {
+        Some('-' | '+') => ahead.next().is_some_and(|c| c.is_ascii_digit()),
+        Some(c) => c.is_ascii_digit(),
+        None => false,
+    }
+}
+
+/// Scales a CSS value to the range of its channel, `None` for a unit the channel does not take.
+///
+/// - Percentages are relative to `255` for RGB, to `100` and `125` for Lab, to `100` and `150`
+///   for LCH, to `0.4` for the a, b and chroma of Oklab and Oklch, and to `1` otherwise.
+/// - Hues take the angle units `deg`, `rad`, `grad` and `turn`.
+/// - Plain numbers are kept, except the percentages of `hsl` and `hwb` in the modern syntax,
+///   `hsl(120 50 50)` is `hsl(120, 50%, 50%)`.
+fn css_value(
+    color_space: ColorSpace,
+    index: usize,
+    value: f64,
+    unit: &str,
+    legacy: bool,
+) -> Option<f64> {
+    let is_hue = hue_index(color_space) == Some(index);
+    match unit {
+        "" if !legacy && index > 0 && matches!(color_space, ColorSpace::HSL | ColorSpace::HWB) => {
+            Some(value / 100.0)
+        }
+        "" => Some(value),
+        // a hue is an angle, it has no percentages
+        "%" if is_hue => None,
+        "%" => Some(match (color_space, index) {
+            (ColorSpace::RGB, _) => value / 100.0 * 255.0,
+            (ColorSpace::Lab | ColorSpace::Lch, 0) => value,
+            (ColorSpace::Lab, _) => value / 100.0 * 125.0,
+            (ColorSpace::Lch, _) => value / 100.0 * 150.0,
+            (ColorSpace::Oklab | ColorSpace::Oklch, 1..) => value / 100.0 * 0.4,
+            _ => value / 100.0,
+        }),
+        "deg" if is_hue => Some(value),
+        "rad" if is_hue => Some(value.to_degrees()),
+        "grad" if is_hue => Some(value * 0.9),
+        "turn" if is_hue => Some(value * 360.0),
+        _ => None,
+    }
+}
+
+/// The index of the hue channel of a color space.
+fn hue_index(color_space: ColorSpace) -> Option<usize> {
+    match color_space {
+        ColorSpace::HSI
+        | ColorSpace::HSL
+        | ColorSpace::HSLA
+        | ColorSpace::HSV
+        | ColorSpace::HWB => Some(0),
+        ColorSpace::Lch | ColorSpace::Oklch => Some(2),
+        _ => None,
+    }
+}
+
+/// Wraps a hue into `0..360`, `400` is `40` and `-30` is `330`.
+fn wrap_hue(hue: f64) -> f64 {
+    let hue = Float::rem_euclid(hue, 360.0);
+    // `rem_euclid` of a tiny negative hue rounds up to 360
+    if hue == 360.0 {
+        0.0
+    } else {
+        hue
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,4 +777,86 @@ mod tests {
 
         assert!(parser.validate().is_ok());
     }
+
+    #[test]
+    fn test_parse_err_span() {
+        let cases = [
+            ("rgb(255, 256, 0)", Span::new(9, 12)),
+            ("hsl(60, 80%)", Span::new(0, 12)),
+            ("rgba(0, 0) ", Span::new(0, 10)),
+            ("rgb(0 0 0 / 150%)", Span::new(12, 16)),
+            ("rgbx(0, 0, 0)", Span::new(0, 4)),
+            ("hsl(60, 180%, 50%)", Span::new(8, 12)),
+        ];
+        for (input, span) in cases {
+            let mut parser = Parser::new();
+            let err = parser.tokenize(input).validate().unwrap_err();
+            assert_eq!(err.span(), Some(span), "{}", input);
+        }
+
+        let mut parser = Parser::new();
+        let err = parser.tokenize("hsl(60, 80%)").validate().unwrap_err();
+        assert_eq!(
+            err,
+            Error::WrongArity {
+                function: "hsl".into(),
+                expected: 3,
+                found: 2,
+                span: Span::new(0, 12),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_err_separators() {
+        let cases = [
+            ("rgba(0,0,0,)", "Unexpected trailing ','", Span::new(10, 11)),
+            (
+                "rgb(255 0 0, 0.5)",
+                "Mixed comma and space separators",
+                Span::new(11, 12),
+            ),
+            (
+                "rgb(255, 0 0)",
+                "Mixed comma and space separators",
+                Span::new(11, 12),
+            ),
+            ("rgb(, 255, 0, 0)", "Unexpected ','", Span::new(4, 5)),
+            ("rgb(255,, 0, 0)", "Unexpected ','", Span::new(8, 9)),
+            (
+                "rgb(255, 0, 0 / 0.5)",
+                "Mixed comma and slash separators",
+                Span::new(14, 15),
+            ),
+            (
+                "rgb(255 0 0 / 0.5, 1)",
+                "Mixed comma and slash separators",
+                Span::new(17, 18),
+            ),
+            ("rgb(((", "Unmatched left parenthesis", Span::new(5, 6)),
+            (
+                "rgb(255, 0, 0",
+                "Unmatched left parenthesis",
+                Span::new(3, 4),
+            ),
+        ];
+        for (input, message, span) in cases {
+            let mut parser = Parser::new();
+            let err = parser.tokenize(input).validate().unwrap_err();
+            assert_eq!(
+                err,
+                Error::Syntax {
+                    message: message.into(),
+                    span,
+                },
+                "{}",
+                input
+            );
+        }
+
+        for input in ["rgba(0, 0, 0, 0.5)", "rgb(0 0 0 / 0.5)", "rgb(0,0,0)"] {
+            let mut parser = Parser::new();
+            assert!(parser.tokenize(input).validate().is_ok(), "{}", input);
+        }
+    }
 }
@@ -0,0 +1,566 @@
+use super::calc::split_unit;
+use crate::{conversion::hsl, Color, Error, Span};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::FromStr;
+
+/// How deep the values of variables may reference other variables, to catch cycles.
+const MAX_DEPTH: usize = 32;
+
+/// How deep the function calls may be nested, also through variables, to bound the recursion.
+const MAX_NESTING: usize = 64;
+
+/// The functions of Less and Sass, with the names of both.
+const FUNCTIONS: &[&str] = &[
+    "darken",
+    "lighten",
+    "saturate",
+    "desaturate",
+    "fade",
+    "fadein",
+    "fadeout",
+    "fade-in",
+    "fade-out",
+    "opacify",
+    "transparentize",
+    "spin",
+    "adjust-hue",
+    "mix",
+    "tint",
+    "shade",
+    "greyscale",
+    "grayscale",
+];
+
+/// A value of an expression.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Color(Color),
+    /// A number, and whether it is a percentage.
+    Number(f64, bool),
+    /// The `relative` method of the Less functions.
+    Relative,
+}
+
+/// Evaluates an expression of the Less and Sass color functions, e.g.
+/// `darken(spin(#f00, 30), 10%)`.
+///
+/// `resolve` gets the name of a variable with its `$` or `@`, its value is an expression too.
+pub fn evaluate<F>(input: &str, resolve: &F) -> Result<Color, Error>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    match eval(input, resolve, 0, 0)? {
+        Value::Color(color) => Ok(color),
+        _ => {
+            let start = input.len() - input.trim_start().len();
+            Err(Error::Syntax {
+                message: format!("The expression '{}' is not a color", input.trim()).into(),
+                span: Span::new(start, input.trim_end().len()),
+            })
+        }
+    }
+}
+
+fn eval<F>(input: &str, resolve: &F, depth: usize, nesting: usize) -> Result<Value, Error>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if depth > MAX_DEPTH {
+        return Err(Error::ColorParserError(
+            "Cyclic or too deeply nested variable".into(),
+        ));
+    }
+    let mut evaluator = Evaluator {
+        input,
+        pos: 0,
+        resolve,
+        depth,
+        nesting,
+        too_deep: false,
+    };
+    let (value, _) = evaluator.value()?;
+    evaluator.skip_whitespace();
+    match input[evaluator.pos..].chars().next() {
+        None => Ok(value),
+        Some(c) => Err(Error::Syntax {
+            message: format!("Unexpected '{}'", c).into(),
+            span: Span::new(evaluator.pos, evaluator.pos + c.len_utf8()),
+        }),
+    }
+}
+
+struct Evaluator<'a, F> {
+    input: &'a str,
+    pos: usize,
+    resolve: &'a F,
+    depth: usize,
+    /// The number of enclosing function calls, see [`MAX_NESTING`].
+    nesting: usize,
+    /// Whether the calls went deeper than [`MAX_NESTING`].
+    too_deep: bool,
+}
+
+impl<F> Evaluator<'_, F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn value(&mut self) -> Result<(Value, Span), Error> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let rest = &self.input[start..];
+
+        if rest.starts_with(['$', '@']) {
+            let name = &rest[..1 + identifier_len(&rest[1..])];
+            self.pos += name.len();
+            let span = Span::new(start, self.pos);
+            let value = (self.resolve)(name).ok_or_else(|| Error::Syntax {
+                message: format!("Unresolved variable '{}'", name).into(),
+                span,
+            })?;
+            // the spans of the value are relative to the value, not to the input
+            let value = eval(&value, self.resolve, self.depth + 1, self.nesting)
+                .map_err(|err| err.replace_span(span))?;
+            return Ok((value, span));
+        }
+
+        let name = rest[..identifier_len(rest)].to_ascii_lowercase();
+        if rest[name.len()..].starts_with('(') {
+            if FUNCTIONS.contains(&name.as_str()) {
+                self.pos += name.len() + 1;
+                let args = self.nested_args(start)?;
+                let span = Span::new(start, self.pos);
+                return Ok((apply(&name, &args, span)?, span));
+            }
+            // `rgba($color, 0.5)` of Sass
+            if name == "rgb" || name == "rgba" {
+                self.pos += name.len() + 1;
+                match self.nested_args(start) {
+                    Ok(args) => {
+                        if let [(Value::Color(color), _), (Value::Number(alpha, percent), _)] =
+                            args[..]
+                        {
+                            let alpha = if percent { alpha / 100.0 } else { alpha };
+                            let value = Value::Color(with_alpha(color, alpha));
+                            return Ok((value, Span::new(start, self.pos)));
+                        }
+                    }
+                    // a plain color would not get deeper
+                    Err(err) if self.too_deep => return Err(err),
+                    Err(_) => {}
+                }
+                self.pos = start;
+            }
+        }
+        self.literal()
+    }
+
+    /// Parses the arguments of the function call at `start`, after its left parenthesis.
+    ///
+    /// A call nested deeper than [`MAX_NESTING`] is a syntax error at its name and parenthesis.
+    fn nested_args(&mut self, start: usize) -> Result<Vec<(Value, Span)>, Error> {
+        if self.nesting == MAX_NESTING {
+            self.too_deep = true;
+            return Err(Error::Syntax {
+                message: "Too deeply nested function calls".into(),
+                span: Span::new(start, self.pos),
+            });
+        }
+        self.nesting += 1;
+        let args = self.args();
+        self.nesting -= 1;
+        args
+    }
+
+    /// Parses the arguments after the left parenthesis, up to the right one.
+    fn args(&mut self) -> Result<Vec<(Value, Span)>, Error> {
+        let mut args = Vec::new();
+        self.skip_whitespace();
+        if self.input[self.pos..].starts_with(')') {
+            self.pos += 1;
+            return Ok(args);
+        }
+        loop {
+            args.push(self.value()?);
+            self.skip_whitespace();
+            match self.input[self.pos..].chars().next() {
+                Some(',') => self.pos += 1,
+                Some(')') => {
+                    self.pos += 1;
+                    return Ok(args);
+                }
+                next => {
+                    let len = next.map_or(0, char::len_utf8);
+                    return Err(Error::Syntax {
+                        message: "Expected ',' or ')'".into(),
+                        span: Span::new(self.pos, self.pos + len),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Parses a number, `relative` or a color up to the next comma or parenthesis.
+    fn literal(&mut self) -> Result<(Value, Span), Error> {
+        let start = self.pos;
+        let mut depth = 0;
+        let mut end = self.input.len();
+        for (i, c) in self.input[start..].char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' | ',' if depth == 0 => {
+                    end = start + i;
+                    break;
+                }
+                ')' => depth -= 1,
+                _ => {}
+            }
+        }
+        let text = self.input[start..end].trim_end();
+        let span = Span::new(start, start + text.len());
+        self.pos = span.end;
+
+        if text.is_empty() {
+            return Err(Error::Syntax {
+                message: "Expected a value".into(),
+                span,
+            });
+        }
+        if text.eq_ignore_ascii_case("relative") {
+            return Ok((Value::Relative, span));
+        }
+        let value = match split_unit(text) {
+            Ok((number, "%")) => Value::Number(number, true),
+            Ok((number, "" | "deg")) => Value::Number(number, false),
+            Ok(_) => {
+                return Err(Error::Syntax {
+                    message: format!("Invalid unit of '{}'", text).into(),
+                    span,
+                })
+            }
+            Err(_) => Value::Color(
+                Color::from_str(text).map_err(|err| err.shift_span(start).with_span(span))?,
+            ),
+        };
+        Ok((value, span))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+}
+
+fn identifier_len(s: &str) -> usize {
+    s.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+        .unwrap_or(s.len())
+}
+
+/// Runs a function of [`FUNCTIONS`] on its arguments.
+fn apply(name: &str, args: &[(Value, Span)], span: Span) -> Result<Value, Error> {
+    let (min, max) = match name {
+        "greyscale" | "grayscale" => (1, 1),
+        "tint" | "shade" => (1, 2),
+        "fade" | "spin" | "adjust-hue" => (2, 2),
+        _ => (2, 3),
+    };
+    if args.len() < min || args.len() > max {
+        return Err(Error::WrongArity {
+            function: name.to_string().into(),
+            expected: if args.len() < min { min } else { max },
+            found: args.len(),
+            span,
+        });
+    }
+
+    // the Less functions take percentages, `fadein(@c, 10)` is `fadein(@c, 10%)`, while the
+    // alpha functions of Sass take fractions
+    let percentage = |index: usize| amount(&args[index], true);
+    let relative = || match args.get(2) {
+        None => Ok(false),
+        Some((Value::Relative, _)) => Ok(true),
+        Some((_, span)) => Err(Error::Syntax {
+            message: "Expected 'relative'".into(),
+            span: *span,
+        }),
+    };
+    let color = color_of(&args[0])?;
+
+    let color = match name {
+        "darken" => adjust_hsl(color, 2, -percentage(1)?, relative()?),
+        "lighten" => adjust_hsl(color, 2, percentage(1)?, relative()?),
+        "saturate" => adjust_hsl(color, 1, percentage(1)?, relative()?),
+        "desaturate" => adjust_hsl(color, 1, -percentage(1)?, relative()?),
+        "greyscale" | "grayscale" => adjust_hsl(color, 1, -1.0, false),
+        "fade" => with_alpha(color, percentage(1)?),
+        "fadein" | "fadeout" | "fade-in" | "fade-out" | "opacify" | "transparentize" => {
+            let amount = amount(&args[1], matches!(name, "fadein" | "fadeout"))?;
+            let amount = if relative()? {
+                color.alpha * amount
+            } else {
+                amount
+            };
+            match name {
+                "fadein" | "fade-in" | "opacify" => with_alpha(color, color.alpha + amount),
+                _ => with_alpha(color, color.alpha - amount),
+            }
+        }
+        "spin" | "adjust-hue" => {
+            let [h, s, l] = hsl::rgb2hsl(color.rgb);
+            let h = (h + amount(&args[1], false)?) % 360.0;
+            let h = if h < 0.0 { h + 360.0 } else { h };
+            with_hsl(color, [h, s, l])
+        }
+        "mix" => {
+            let weight = match args.get(2) {
+                Some(arg) => amount(arg, true)?,
+                None => 0.5,
+            };
+            mix(color, color_of(&args[1])?, weight)
+        }
+        _ => {
+            let weight = match args.get(1) {
+                Some(arg) => amount(arg, true)?,
+                None => 0.5,
+            };
+            let base = match name {
+                "tint" => Color::new(255.0, 255.0, 255.0, 1.0),
+                _ => Color::new(0.0, 0.0, 0.0, 1.0),
+            };
+            mix(base, color, weight)
+        }
+    };
+    Ok(Value::Color(color))
+}
+
+fn color_of((value, span): &(Value, Span)) -> Result<Color, Error> {
+    match value {
+        Value::Color(color) => Ok(*color),
+        _ => Err(Error::Syntax {
+            message: "Expected a color".into(),
+            span: *span,
+        }),
+    }
+}
+
+/// The number of an argument, a percentage is a fraction. `percent` reads plain numbers as
+/// percentages too.
+fn amount((value, span): &(Value, Span), percent: bool) -> Result<f64, Error> {
+    match value {
+        Value::Number(number, true) => Ok(number / 100.0),
+        Value::Number(number, false) if percent => Ok(number / 100.0),
+        Value::Number(number, false) => Ok(*number),
+        _ => Err(Error::Syntax {
+            message: "Expected a number".into(),
+            span: *span,
+        }),
+    }
+}
+
+/// Adds `amount` to a channel of HSL, or `amount` times the channel in the relative mode.
+fn adjust_hsl(color: Color, index: usize, amount: f64, relative: bool) -> Color {
+    let mut values = hsl::rgb2hsl(color.rgb);
+    let amount = if relative {
+        values[index] * amount
+    } else {
+        amount
+    };
+    values[index] = (values[index] + amount).clamp(0.0, 1.0);
+    with_hsl(color, values)
+}
+
+fn with_hsl(color: Color, values: [f64; 3]) -> Color {
+    let [r, g, b] = hsl::hsl2rgb(values);
+    Color::new(r, g, b, color.alpha)
+}
+
+fn with_alpha(color: Color, alpha: f64) -> Color {
+    let [r, g, b] = color.rgb;
+    Color::new(r, g, b, alpha.clamp(0.0, 1.0))
+}
+
+/// The `mix` of Less and Sass, the weight of the first color is adjusted by the alphas.
+fn mix(color1: Color, color2: Color, weight: f64) -> Color {
+    let w = weight * 2.0 - 1.0;
+    let a = color1.alpha - color2.alpha;
+    let w1 = (if w * a == -1.0 {
+        w
+    } else {
+        (w + a) / (1.0 + w * a)
+    } + 1.0)
+        / 2.0;
+    let w2 = 1.0 - w1;
+
+    let [r, g, b] = [0, 1, 2].map(|i| color1.rgb[i] * w1 + color2.rgb[i] * w2);
+    let alpha = color1.alpha * weight + color2.alpha * (1.0 - weight);
+    Color::new(r, g, b, alpha)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(name: &str) -> Option<String> {
+        match name {
+            "$brand" => Some("#0af".to_string()),
+            "@brand" => Some("$brand".to_string()),
+            "$amount" => Some("25%".to_string()),
+            "$loop" => Some("lighten($loop, 10%)".to_string()),
+            "$bad" => Some("darken(#ff0000, 10%) x".to_string()),
+            "$out" => Some("rgb(0, 0, 300)".to_string()),
+            _ => None,
+        }
+    }
+
+    fn hex(input: &str) -> String {
+        evaluate(input, &resolve).unwrap().hex()
+    }
+
+    fn rgba(input: &str) -> String {
+        evaluate(input, &resolve).unwrap().rgba()
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(hex("darken(spin(#f00, 30), 10%)"), "#c60");
+        assert_eq!(hex("darken(hsl(90, 80%, 50%), 20%)"), "#4d8a0f");
+        assert_eq!(hex("lighten(hsl(90, 80%, 50%), 20%)"), "#b3f075");
+        assert_eq!(hex("saturate(hsl(90, 80%, 50%), 20%)"), "#80ff00");
+        assert_eq!(hex("desaturate(#80e619, 20)"), "#80cd32");
+        assert_eq!(hex("greyscale(hsl(90, 90%, 50%))"), "#808080");
+        assert_eq!(
+            hex("spin(hsl(10, 90%, 50%), -30)"),
+            hex("hsl(340, 90%, 50%)")
+        );
+        assert_eq!(
+            hex("adjust-hue(hsl(10, 90%, 50%), 30deg)"),
+            hex("hsl(40, 90%, 50%)")
+        );
+        assert_eq!(hex("tint(#007fff, 50%)"), "#80bfff");
+        assert_eq!(hex("shade(#007fff)"), "#004080");
+        assert_eq!(hex("mix(#ff0000, #0000ff, 50%)"), "#800080");
+        assert_eq!(
+            rgba("mix($brand, white, $amount)"),
+            "rgba(191, 234, 255, 1)"
+        );
+        assert_eq!(hex("DARKEN( @brand , 0% )"), "#0af");
+    }
+
+    #[test]
+    fn test_evaluate_alpha() {
+        assert_eq!(
+            rgba("mix(rgba(100, 0, 0, 1.0), rgba(0, 100, 0, 0.5), 50%)"),
+            "rgba(75, 25, 0, 0.75)"
+        );
+        assert_eq!(
+            rgba("fade(hsl(90, 90%, 50%), 10%)"),
+            "rgba(128, 242, 13, 0.1)"
+        );
+        assert_eq!(
+            rgba("fadein(hsla(90, 90%, 50%, 0.5), 10)"),
+            "rgba(128, 242, 13, 0.6)"
+        );
+        assert_eq!(
+            rgba("fadeout(hsla(90, 90%, 50%, 0.5), 10%, relative)"),
+            "rgba(128, 242, 13, 0.45)"
+        );
+        assert_eq!(
+            rgba("transparentize(rgba(0, 0, 0, 0.5), 0.1)"),
+            "rgba(0, 0, 0, 0.4)"
+        );
+        assert_eq!(
+            rgba("opacify(rgba(0, 0, 0, 0.5), 0.2)"),
+            "rgba(0, 0, 0, 0.7)"
+        );
+        assert_eq!(rgba("rgba($brand, 0.5)"), "rgba(0, 170, 255, 0.5)");
+        assert_eq!(
+            rgba("darken(rgba(255, 0, 0, 0.5), 10%)"),
+            "rgba(204, 0, 0, 0.5)"
+        );
+    }
+
+    #[test]
+    fn test_evaluate_relative() {
+        assert_eq!(hex("darken(hsl(90, 80%, 50%), 20%, relative)"), "#66b814");
+        assert_eq!(
+            hex("lighten(hsl(90, 80%, 50%), 20%, relative)"),
+            hex("hsl(90, 80%, 60%)")
+        );
+        assert!(evaluate("darken(#f00, 10%, absolute)", &resolve).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_err() {
+        assert!(matches!(
+            evaluate("darken(#f00)", &resolve),
+            Err(Error::WrongArity { found: 1, .. })
+        ));
+        let err = |input| evaluate(input, &resolve).unwrap_err().to_string();
+        assert_eq!(err("darken(10%, #f00)"), "Expected a color at 7..10");
+        assert_eq!(err("darken(#f00, 10%"), "Expected ',' or ')' at 16..16");
+        assert_eq!(
+            err("darken(#f00 10%)"),
+            "Invalid hex string of '#f00 10%' at 7..15"
+        );
+        assert_eq!(err("darken(#f00, 10%) red"), "Unexpected 'r' at 18..19");
+        assert_eq!(err(" 10% "), "The expression '10%' is not a color at 1..4");
+        assert_eq!(err("darken(#f00, #0af)"), "Expected a number at 13..17");
+        assert_eq!(err("darken(#f00, 10%, 5)"), "Expected 'relative' at 18..19");
+        assert_eq!(
+            err("darken(#f00, 10em)"),
+            "Invalid unit of '10em' at 13..17"
+        );
+        assert_eq!(err("darken(#f00, )"), "Expected a value at 13..13");
+        assert_eq!(err("$missing"), "Unresolved variable '$missing' at 0..8");
+
+        // the errors in the value of a variable point at the variable
+        assert_eq!(
+            err("mix($loop, red)"),
+            "Cyclic or too deeply nested variable at 4..9"
+        );
+        assert_eq!(err("spin($bad, 10)"), "Unexpected 'x' at 5..9");
+        let err = evaluate("lighten($out, 10%)", &resolve).unwrap_err();
+        assert!(matches!(err, Error::ChannelOutOfRange { .. }));
+        assert_eq!(err.span(), Some(Span::new(8, 12)));
+
+        let err = evaluate("lighten(rgb(0, 0, 300), 10%)", &resolve).unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(18, 21)));
+    }
+
+    #[test]
+    fn test_evaluate_nesting() {
+        let nested = |function: &str, depth: usize| {
+            let mut input = function.repeat(depth);
+            input.push_str("#f00");
+            input.push_str(&", 1%)".repeat(depth));
+            input
+        };
+        assert!(evaluate(&nested("darken(", MAX_NESTING), &resolve).is_ok());
+
+        let input = nested("darken(", 100_000);
+        let err = evaluate(&input, &resolve).unwrap_err();
+        assert_eq!(
+            err,
+            Error::Syntax {
+                message: "Too deeply nested function calls".into(),
+                span: Span::new(MAX_NESTING * 7, MAX_NESTING * 7 + 7),
+            }
+        );
+
+        let input = nested("rgba(", 100_000);
+        let err = evaluate(&input, &resolve).unwrap_err();
+        assert_eq!(
+            err.span(),
+            Some(Span::new(MAX_NESTING * 5, MAX_NESTING * 5 + 5))
+        );
+
+        // the calls in the value of a variable count too, the error points at the variable
+        let deep = nested("darken(", MAX_NESTING);
+        let resolve = |name: &str| (name == "$deep").then(|| deep.clone());
+        let err = evaluate("spin($deep, 10)", &resolve).unwrap_err();
+        assert_eq!(err.to_string(), "Too deeply nested function calls at 5..10");
+    }
+}
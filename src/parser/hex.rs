@@ -1,17 +1,22 @@
-use crate::Error;
+use crate::{Error, Span};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 
 pub fn parse_hex_str(s: impl ToString) -> Result<String, crate::Error> {
     let s = s.to_string();
     let len = s.len();
-    // #rgb #rgba #rrggbb #rrggbbaa
-    if !s.starts_with('#')
-        || (len != 9 && len != 7 && len != 5 && len != 4)
-        || !s.chars().skip(1).all(|c| c.is_ascii_hexdigit())
-    {
-        Err(Error::ColorParserError(format!(
-            "Invalid hex string of '{}'",
-            s
-        )))
+    if !s.starts_with('#') || !s.chars().skip(1).all(|c| c.is_ascii_hexdigit()) {
+        Err(Error::ColorParserError(
+            format!("Invalid hex string of '{}'", s).into(),
+        ))
+    } else if ![4, 5, 7, 9, 10, 13].contains(&len) {
+        // #rgb #rgba #rrggbb #rrggbbaa and the X11 #rrrgggbbb #rrrrggggbbbb
+        Err(Error::BadHexLength {
+            length: len - 1,
+            span: Span::new(0, len),
+        })
     } else {
         Ok(s)
     }
@@ -38,6 +43,14 @@ mod tests {
         let s = "#00000000";
         let hex = parse_hex_str(s).unwrap();
         assert_eq!(hex, "#00000000");
+
+        let s = "#fff000fff";
+        let hex = parse_hex_str(s).unwrap();
+        assert_eq!(hex, "#fff000fff");
+
+        let s = "#ffff0000ffff";
+        let hex = parse_hex_str(s).unwrap();
+        assert_eq!(hex, "#ffff0000ffff");
     }
 
     #[test]
@@ -46,14 +59,17 @@ mod tests {
         let hex = parse_hex_str(s);
         assert!(hex.is_err());
 
-        let s = "#000000000";
+        let s = "#0000000000";
         let hex = parse_hex_str(s);
 
         if let Err(err) = hex {
             println!("{}", &err);
             assert_eq!(
                 err,
-                Error::ColorParserError("Invalid hex string of '#000000000'".to_string())
+                Error::BadHexLength {
+                    length: 10,
+                    span: Span::new(0, 11)
+                }
             );
         } else {
             panic!("should error");
@@ -0,0 +1,198 @@
+use super::calc::split_unit;
+use crate::{Color, Error, HueInterpolation, MixOptions, MixSpace, ParseContext, Span};
+use alloc::{format, vec::Vec};
+
+/// Parses a `color-mix()` of CSS Color 5, e.g. `color-mix(in oklch longer hue, red 30%, blue)`.
+///
+/// The interpolation space may be omitted, it is Oklab then. The offsets of the errors are
+/// relative to the input.
+pub fn parse_color_mix(input: &str, context: &ParseContext) -> Result<Color, Error> {
+    let args_start = "color-mix(".len();
+    let args = input
+        .get(args_start..)
+        .and_then(|args| args.strip_suffix(')'))
+        .ok_or_else(|| Error::ColorParserError("Unmatched left parenthesis".into()))?;
+    let mut args = split_args(args, args_start);
+
+    let options = match args.first() {
+        Some((_, arg)) if arg.trim_start().starts_with("in ") => {
+            let options = parse_interpolation(arg)?;
+            args.remove(0);
+            options
+        }
+        _ => MixOptions::default(),
+    };
+    if args.len() != 2 {
+        return Err(Error::WrongArity {
+            function: "color-mix".into(),
+            expected: 2,
+            found: args.len(),
+            span: Span::new(0, input.len()),
+        });
+    }
+
+    let (color1, percentage1) = parse_stop(args[0].1, args[0].0, context)?;
+    let (color2, percentage2) = parse_stop(args[1].1, args[1].0, context)?;
+    Color::color_mix(&color1, percentage1, &color2, percentage2, &options)
+}
+
+/// Parses `in <space> [<method> hue]`.
+fn parse_interpolation(arg: &str) -> Result<MixOptions, Error> {
+    let words: Vec<&str> = arg.split_whitespace().collect();
+    let space = MixSpace::from_name(words[1]).ok_or_else(|| {
+        Error::ColorParserError(format!("Unknown interpolation space '{}'", words[1]).into())
+    })?;
+    let hue = match words[2..] {
+        [] => HueInterpolation::default(),
+        [method, "hue"] if space.hue_index().is_some() => HueInterpolation::from_name(method)
+            .ok_or_else(|| {
+                Error::ColorParserError(
+                    format!("Unknown hue interpolation method '{}'", method).into(),
+                )
+            })?,
+        _ => {
+            return Err(Error::ColorParserError(
+                format!("Invalid interpolation of '{}'", arg.trim()).into(),
+            ))
+        }
+    };
+    Ok(MixOptions { space, hue })
+}
+
+/// Parses a color and its optional percentage, which may come before or after it.
+fn parse_stop(
+    arg: &str,
+    offset: usize,
+    context: &ParseContext,
+) -> Result<(Color, Option<f64>), Error> {
+    let offset = offset + arg.len() - arg.trim_start().len();
+    let arg = arg.trim();
+
+    let (color, color_offset, percentage) = match (
+        arg.split_once(char::is_whitespace),
+        arg.rsplit_once(char::is_whitespace),
+    ) {
+        (Some((first, rest)), _) if first.ends_with('%') => {
+            let rest_offset = arg.len() - rest.len();
+            (rest, offset + rest_offset, Some((first, offset)))
+        }
+        (_, Some((rest, last))) if last.ends_with('%') => {
+            let last_offset = offset + arg.len() - last.len();
+            (rest, offset, Some((last, last_offset)))
+        }
+        _ => (arg, offset, None),
+    };
+
+    let percentage = match percentage {
+        Some((value, start)) => {
+            let span = Span::new(start, start + value.len());
+            let (number, unit) = split_unit(value)?;
+            if unit != "%" || !(0.0..=100.0).contains(&number) {
+                return Err(Error::ChannelOutOfRange {
+                    channel: "Percentage".into(),
+                    value: number,
+                    min: 0.0,
+                    max: 100.0,
+                    span,
+                });
+            }
+            Some(number / 100.0)
+        }
+        None => None,
+    };
+    let color =
+        Color::from_str_with_context(color, context).map_err(|err| err.shift_span(color_offset))?;
+    Ok((color, percentage))
+}
+
+/// Splits the arguments at the commas outside of parentheses, with their offsets.
+pub(crate) fn split_args(args: &str, offset: usize) -> Vec<(usize, &str)> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push((offset + start, &args[start..i]));
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push((offset + start, &args[start..]));
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color_mix() {
+        let color = parse_color_mix(
+            "color-mix(in srgb, red 30%, blue)",
+            &ParseContext::default(),
+        )
+        .unwrap();
+        assert_eq!(color.rgb(), "rgb(77, 0, 179)");
+
+        let color = parse_color_mix(
+            "color-mix(in srgb, 30% red, blue)",
+            &ParseContext::default(),
+        )
+        .unwrap();
+        assert_eq!(color.rgb(), "rgb(77, 0, 179)");
+
+        let color = parse_color_mix(
+            "color-mix(in hsl longer hue, red, blue)",
+            &ParseContext::default(),
+        )
+        .unwrap();
+        assert_eq!(color.hex(), "#0f0");
+
+        let color = parse_color_mix("color-mix(red, blue)", &ParseContext::default()).unwrap();
+        assert_eq!(color.hex(), "#8c53a2");
+
+        let color = parse_color_mix(
+            "color-mix(in srgb, rgb(255 0 0 / 50%) 20%, blue 20%)",
+            &ParseContext::default(),
+        )
+        .unwrap();
+        assert_eq!(color.rgba(), "rgba(85, 0, 170, 0.3)");
+    }
+
+    #[test]
+    fn test_parse_color_mix_err() {
+        assert!(matches!(
+            parse_color_mix("color-mix(in srgb, red)", &ParseContext::default()),
+            Err(Error::WrongArity { found: 1, .. })
+        ));
+        assert!(parse_color_mix("color-mix(in hsv, red, blue)", &ParseContext::default()).is_err());
+        assert!(parse_color_mix(
+            "color-mix(in srgb longer hue, red, blue)",
+            &ParseContext::default()
+        )
+        .is_err());
+        assert!(parse_color_mix(
+            "color-mix(in srgb, red 0%, blue 0%)",
+            &ParseContext::default()
+        )
+        .is_err());
+
+        let err = parse_color_mix(
+            "color-mix(in srgb, red 120%, blue)",
+            &ParseContext::default(),
+        )
+        .unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(23, 27)));
+
+        let err = parse_color_mix(
+            "color-mix(in srgb, red, rgb(0, 0, 300))",
+            &ParseContext::default(),
+        )
+        .unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(34, 37)));
+    }
+}
@@ -1,4 +1,10 @@
+pub(crate) mod calc;
+pub(crate) mod context;
 pub(crate) mod core;
+pub(crate) mod expr;
 pub(crate) mod hex;
+pub(crate) mod mix;
+pub(crate) mod var;
+pub(crate) mod x11;
 
 pub(crate) use self::core::Parser;
@@ -0,0 +1,137 @@
+use crate::Error;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+/// How deep the values of variables may reference other variables, to catch cycles.
+const MAX_DEPTH: usize = 32;
+
+/// Replaces the `var(--name)` and `var(--name, fallback)` references of the input.
+///
+/// The values from `resolve` may reference other variables. The fallback is used when
+/// `resolve` returns `None`.
+pub fn substitute_vars<F>(input: &str, resolve: &F) -> Result<String, Error>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    substitute(input, resolve, 0)
+}
+
+fn substitute<F>(input: &str, resolve: &F, depth: usize) -> Result<String, Error>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if depth > MAX_DEPTH {
+        return Err(Error::ColorParserError(
+            "Cyclic or too deeply nested var()".into(),
+        ));
+    }
+
+    let mut output = String::new();
+    let mut rest = input;
+    // ASCII only, so the byte offsets match `rest`
+    while let Some(start) = rest.to_ascii_lowercase().find("var(") {
+        output.push_str(&rest[..start]);
+        let args_start = start + "var(".len();
+        let end = closing_paren(&rest[args_start..])
+            .ok_or_else(|| Error::ColorParserError("Unmatched left parenthesis".into()))?
+            + args_start;
+
+        let args = &rest[args_start..end];
+        let (name, fallback) = match top_level_comma(args) {
+            Some(comma) => (args[..comma].trim(), Some(args[comma + 1..].trim())),
+            None => (args.trim(), None),
+        };
+        if !name.starts_with("--") {
+            return Err(Error::ColorParserError(
+                format!("Invalid variable name of '{}'", name).into(),
+            ));
+        }
+
+        let value = match (resolve(name), fallback) {
+            (Some(value), _) => value,
+            (None, Some(fallback)) => fallback.to_string(),
+            (None, None) => {
+                return Err(Error::ColorParserError(
+                    format!("Unresolved variable '{}'", name).into(),
+                ))
+            }
+        };
+        output.push_str(&substitute(&value, resolve, depth + 1)?);
+        rest = &rest[end + 1..];
+    }
+    output.push_str(rest);
+
+    Ok(output)
+}
+
+/// Byte offset of the parenthesis closing the one before `s`.
+fn closing_paren(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Byte offset of the first comma outside of parentheses.
+fn top_level_comma(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(name: &str) -> Option<String> {
+        match name {
+            "--brand" => Some("#0af".to_string()),
+            "--accent" => Some("var(--brand)".to_string()),
+            "--Lightness" => Some("40%".to_string()),
+            "--loop" => Some("var(--loop)".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_substitute_vars() {
+        assert_eq!(substitute_vars("var(--brand)", &resolve).unwrap(), "#0af");
+        assert_eq!(
+            substitute_vars("hsl(from VAR( --accent ) h s var(--Lightness))", &resolve).unwrap(),
+            "hsl(from #0af h s 40%)"
+        );
+        assert_eq!(
+            substitute_vars("var(--missing, rgb(0, 0, 0))", &resolve).unwrap(),
+            "rgb(0, 0, 0)"
+        );
+        assert_eq!(substitute_vars("red", &resolve).unwrap(), "red");
+    }
+
+    #[test]
+    fn test_substitute_vars_err() {
+        assert_eq!(
+            substitute_vars("var(--missing)", &resolve)
+                .unwrap_err()
+                .to_string(),
+            "Unresolved variable '--missing'"
+        );
+        assert!(substitute_vars("var(--loop)", &resolve).is_err());
+        assert!(substitute_vars("var(--brand", &resolve).is_err());
+        assert!(substitute_vars("var(brand)", &resolve).is_err());
+    }
+}
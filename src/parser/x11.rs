@@ -0,0 +1,113 @@
+use crate::{Color, Error, Span};
+use alloc::{format, string::ToString, vec::Vec};
+
+/// Parses the `rgb:<red>/<green>/<blue>` of `XParseColor`, e.g. `rgb:ff/80/00`.
+///
+/// A channel has 1 to 4 hex digits, `f`, `ff`, `fff` and `ffff` are all 255.
+pub fn parse_x11_rgb(input: &str) -> Result<Color, Error> {
+    let [r, g, b] = parse_channels(input, "rgb:", |channel| {
+        if !(1..=4).contains(&channel.len()) || !channel.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let max = (1_u32 << (4 * channel.len())) - 1;
+        let value = u32::from_str_radix(channel, 16).ok()?;
+        Some(value as f64 / max as f64 * 255.0)
+    })?;
+    Ok(Color::new(r, g, b, 1.0))
+}
+
+/// Parses the `rgbi:<red>/<green>/<blue>` of `XParseColor`, e.g. `rgbi:1.0/0.5/0.0`.
+///
+/// The channels are intensities between 0 and 1.
+pub fn parse_x11_rgbi(input: &str) -> Result<Color, Error> {
+    let [r, g, b] = parse_channels(input, "rgbi:", |channel| channel.parse::<f64>().ok())?;
+    for (value, (name, span)) in [r, g, b].into_iter().zip(channel_spans(input, "rgbi:")) {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(Error::ChannelOutOfRange {
+                channel: name.into(),
+                value,
+                min: 0.0,
+                max: 1.0,
+                span,
+            });
+        }
+    }
+    Ok(Color::new(r * 255.0, g * 255.0, b * 255.0, 1.0))
+}
+
+/// Splits the three channels after `prefix` and reads them with `read`.
+fn parse_channels<F>(input: &str, prefix: &str, read: F) -> Result<[f64; 3], Error>
+where
+    F: Fn(&str) -> Option<f64>,
+{
+    let spans = channel_spans(input, prefix);
+    if spans.len() != 3 {
+        return Err(Error::WrongArity {
+            function: prefix.to_string().into(),
+            expected: 3,
+            found: spans.len(),
+            span: Span::new(0, input.len()),
+        });
+    }
+
+    let mut values = [0.0; 3];
+    for (value, (_, span)) in values.iter_mut().zip(spans) {
+        let channel = &input[span.range()];
+        *value = read(channel).ok_or_else(|| {
+            Error::ColorParserError(format!("Invalid channel '{}' of {}", channel, prefix).into())
+        })?;
+    }
+    Ok(values)
+}
+
+/// The names and spans of the channels separated by slashes after `prefix`.
+fn channel_spans(input: &str, prefix: &str) -> Vec<(&'static str, Span)> {
+    let mut start = prefix.len();
+    let mut spans = Vec::new();
+    for (i, channel) in input[prefix.len()..].split('/').enumerate() {
+        let name = ["Red", "Green", "Blue"].get(i).copied().unwrap_or_default();
+        spans.push((name, Span::new(start, start + channel.len())));
+        start += channel.len() + 1;
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_x11_rgb() {
+        assert_eq!(parse_x11_rgb("rgb:ff/80/00").unwrap().hex(), "#ff8000");
+        assert_eq!(
+            parse_x11_rgb("rgb:f/8/0").unwrap(),
+            Color::new(255, 136, 0, 1.0)
+        );
+        assert_eq!(
+            parse_x11_rgb("rgb:ffff/0/fff").unwrap(),
+            Color::new(255, 0, 255, 1.0)
+        );
+
+        assert!(parse_x11_rgb("rgb:fffff/0/0").is_err());
+        assert!(parse_x11_rgb("rgb:ff//00").is_err());
+        assert!(parse_x11_rgb("rgb:gg/00/00").is_err());
+        assert!(matches!(
+            parse_x11_rgb("rgb:ff/00"),
+            Err(Error::WrongArity { found: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_x11_rgbi() {
+        let color = parse_x11_rgbi("rgbi:1.0/0.5/0.0").unwrap();
+        assert_eq!(color, Color::new(255.0, 127.5, 0.0, 1.0));
+
+        let err = parse_x11_rgbi("rgbi:1/1.5/0").unwrap_err();
+        assert_eq!(err.span(), Some(Span::new(7, 10)));
+        assert_eq!(
+            err.to_string(),
+            "Green must be between 0 and 1, got 1.5 at 7..10"
+        );
+        assert!(parse_x11_rgbi("rgbi:1/x/0").is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::utils::Float;
+
 pub(crate) fn min(a: f64, b: f64) -> f64 {
     a.min(b)
 }
@@ -58,7 +60,7 @@ pub(crate) fn soft_light(a: f64, b: f64) -> f64 {
             if a <= 0.25 {
                 ((16.0 * a - 12.0) * a + 4.0) * a
             } else {
-                a.sqrt()
+                Float::sqrt(a)
             }
         };
         a + (2.0 * b - 1.0) * (d(a) - a)
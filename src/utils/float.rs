@@ -0,0 +1,163 @@
+use core::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+/// Floating point type a [`Color`](crate::Color) and the color space structs can be stored in.
+///
+/// Implemented for `f32` and `f64`, `f64` is the default everywhere. The trait is sealed, it
+/// cannot be implemented for other types.
+///
+/// Without the `std` feature the math functions come from [`libm`](https://docs.rs/libm).
+pub trait Float:
+    sealed::Sealed
+    + Copy
+    + Debug
+    + Display
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// `0.0`
+    const ZERO: Self;
+    /// `1.0`
+    const ONE: Self;
+
+    /// Converts from `f64`, rounding to the nearest value for `f32`.
+    fn from_f64(v: f64) -> Self;
+    /// Converts to `f64`.
+    fn to_f64(self) -> f64;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn exp(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn round(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn to_degrees(self) -> Self;
+    fn to_radians(self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+}
+
+mod sealed {
+    /// Keeps [`Float`](super::Float) to `f32` and `f64`.
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Forwards to the inherent method with `std`, to `libm` otherwise.
+macro_rules! forward {
+    ($t:ident, $name:ident $(, $arg:ident)*) => {
+        fn $name(self $(, $arg: Self)*) -> Self {
+            #[cfg(feature = "std")]
+            return $t::$name(self $(, $arg)*);
+            #[cfg(not(feature = "std"))]
+            return libm::Libm::<$t>::$name(self $(, $arg)*);
+        }
+    };
+}
+
+macro_rules! impl_float {
+    ($t:ident) => {
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            fn from_f64(v: f64) -> Self {
+                v as $t
+            }
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+            fn signum(self) -> Self {
+                $t::signum(self)
+            }
+            forward!($t, sqrt);
+            forward!($t, cbrt);
+            fn powf(self, n: Self) -> Self {
+                #[cfg(feature = "std")]
+                return $t::powf(self, n);
+                #[cfg(not(feature = "std"))]
+                return libm::Libm::<$t>::pow(self, n);
+            }
+            fn powi(self, n: i32) -> Self {
+                #[cfg(feature = "std")]
+                return $t::powi(self, n);
+                #[cfg(not(feature = "std"))]
+                return libm::Libm::<$t>::pow(self, n as $t);
+            }
+            forward!($t, exp);
+            forward!($t, sin);
+            forward!($t, cos);
+            forward!($t, acos);
+            forward!($t, atan2, other);
+            forward!($t, round);
+            fn rem_euclid(self, rhs: Self) -> Self {
+                let r = self % rhs;
+                if r < 0.0 {
+                    r + rhs.abs()
+                } else {
+                    r
+                }
+            }
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $t::clamp(self, min, max)
+            }
+            fn to_degrees(self) -> Self {
+                $t::to_degrees(self)
+            }
+            fn to_radians(self) -> Self {
+                $t::to_radians(self)
+            }
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+/// Shorthand of [`Float::from_f64`] for constants in generic code.
+pub(crate) fn lit<T: Float>(v: f64) -> T {
+    T::from_f64(v)
+}
@@ -1,3 +1,5 @@
+use alloc::{format, string::String};
+
 /// Simplifies the hex code to a short hex code if possible.
 pub(crate) fn simplify_hex(hex: String) -> String {
     let hex_len = hex.len();
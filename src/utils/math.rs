@@ -1,16 +1,29 @@
-pub type Matrix = Vec<Vec<f64>>;
+use super::float::{lit, Float};
 
-/// <https://www.w3.org/TR/css-color-4/multiply-matrices.js>
+pub type Matrix3 = [[f64; 3]; 3];
+
+/// Multiplies two 3x3 matrices.
+pub fn multiply_matrix3(a: Matrix3, b: Matrix3) -> Matrix3 {
+    let mut m = [[0.0; 3]; 3];
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, v) in row.iter_mut().enumerate() {
+            *v = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+    m
+}
+
+/// Multiplies a 3x3 matrix with a column vector.
 ///
-/// a is m x n. b is n x p. product is m x p.
+/// <https://www.w3.org/TR/css-color-4/multiply-matrices.js>
 ///
-/// a:
+/// m:
 /// ```bash
 /// | 1, 2, 3 |
 /// | 4, 5, 6 |
 /// | 7, 8, 9 |
 /// ```
-/// b:
+/// v:
 /// ```bash
 /// | 1 |
 /// | 2 |
@@ -22,20 +35,35 @@ pub type Matrix = Vec<Vec<f64>>;
 /// | 32 |
 /// | 50 |
 /// ```
-pub fn multiply_matrices(a: Matrix, b: Matrix) -> Matrix {
-    // TODO: check if a and b are valid matrices
-    (0..a.len())
-        .map(|i| {
-            (0..b[i].len()).fold(Vec::with_capacity(b[i].len()), |mut acc, j| {
-                acc.push(
-                    (0..a[i].len())
-                        .map(|k| a[i][k] * b[k][j])
-                        .fold(0.0, |acc, x| acc + x),
-                );
-                acc
-            })
-        })
-        .collect()
+pub fn transform3<T: Float>(m: Matrix3, v: [T; 3]) -> [T; 3] {
+    m.map(|row| lit::<T>(row[0]) * v[0] + lit::<T>(row[1]) * v[1] + lit::<T>(row[2]) * v[2])
+}
+
+/// Inverts a 3x3 matrix with the adjugate method.
+///
+/// The matrix is expected to be invertible.
+pub fn invert_matrix3(m: Matrix3) -> Matrix3 {
+    let [[a, b, c], [d, e, f], [g, h, i]] = m;
+
+    let det = a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g);
+
+    [
+        [
+            (e * i - f * h) / det,
+            (c * h - b * i) / det,
+            (b * f - c * e) / det,
+        ],
+        [
+            (f * g - d * i) / det,
+            (a * i - c * g) / det,
+            (c * d - a * f) / det,
+        ],
+        [
+            (d * h - e * g) / det,
+            (b * g - a * h) / det,
+            (a * e - b * d) / det,
+        ],
+    ]
 }
 
 #[cfg(test)]
@@ -43,10 +71,22 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_multiply_matrices() {
-        let a = vec![vec![1.0, 2.0, 3.0]; 3];
-        let b = vec![vec![1.0], vec![2.0], vec![3.0]];
-        let c = multiply_matrices(a, b);
-        assert_eq!(c, vec![vec![14.0], vec![14.0], vec![14.0]]);
+    fn test_transform3() {
+        let m = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        assert_eq!(transform3(m, [1.0, 2.0, 3.0]), [14.0, 32.0, 50.0]);
+    }
+
+    #[test]
+    fn test_invert_matrix3() {
+        let m = [[2.0, 0.0, 1.0], [1.0, 1.0, 0.0], [0.0, 3.0, 1.0]];
+        let inv = invert_matrix3(m);
+        let identity = multiply_matrix3(m, inv);
+        for (i, row) in identity.iter().enumerate() {
+            for (j, &v) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((v - expected).abs() < 1e-12);
+            }
+        }
+        assert_eq!(transform3(m, [1.0, 2.0, 3.0]), [5.0, 3.0, 9.0]);
     }
 }
@@ -1,7 +1,10 @@
 pub mod blend_fn;
+pub mod float;
+#[cfg(feature = "alloc")]
 pub mod hex;
 pub mod math;
 
+pub use float::*;
 pub use math::*;
 
 pub(crate) fn round(val: f64, precision: u32) -> f64 {
@@ -15,6 +18,6 @@ pub(crate) fn round(val: f64, precision: u32) -> f64 {
 }
 
 /// normalize color values 0..255 to 0..1
-pub(crate) fn normalize_color(color: &[f64]) -> Vec<f64> {
-    color.iter().map(|&c| c / 255.0).collect()
+pub(crate) fn normalize_color<T: Float>(color: [T; 3]) -> [T; 3] {
+    color.map(|c| c / lit(255.0))
 }
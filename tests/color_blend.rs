@@ -2,8 +2,8 @@ use color_art::{blend, color, BlendMode};
 
 #[test]
 fn test_color_blend() {
-    let c1 = color!(#4cbbfc);
-    let c2 = color!(#ee2);
+    let c1 = color!(#4cbbfc).unwrap();
+    let c2 = color!(#ee2).unwrap();
 
     let c3 = blend(&c1, &c2, BlendMode::Multiply);
     assert_eq!(c3.hex(), "#47af22");
@@ -2,8 +2,8 @@ use color_art::{color, distance, distance_with, ColorSpace};
 
 #[test]
 fn test_color_calc() {
-    let color1 = color!(#abcdef);
-    let color2 = color!(#123456);
+    let color1 = color!(#abcdef).unwrap();
+    let color2 = color!(#123456).unwrap();
 
     let d = distance(&color1, &color2);
     assert_eq!(d, 265.00377355803823);
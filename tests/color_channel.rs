@@ -2,7 +2,7 @@ use color_art::color;
 
 #[test]
 fn test_color_channel() {
-    let color = color!(#abcdef);
+    let color = color!(#abcdef).unwrap();
 
     assert_eq!(color.red(), 171);
     assert_eq!(color.green(), 205);
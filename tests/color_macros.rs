@@ -2,18 +2,18 @@ use color_art::color;
 
 #[test]
 fn test_color_marcos() {
-    let color = color!(rgb, 255, 255, 0);
+    let color = color!(rgb, 255, 255, 0).unwrap();
     assert_eq!(color.rgb(), "rgb(255, 255, 0)");
 
-    let color = color!(#1890ff);
+    let color = color!(#1890ff).unwrap();
     assert_eq!(color.hex(), "#1890ff");
 
-    let color = color!(#1890ff33);
+    let color = color!(#1890ff33).unwrap();
     assert_eq!(color.hex(), "#1890ff33");
 
-    let color = color!(rgb(255, 255, 0));
+    let color = color!(rgb(255, 255, 0)).unwrap();
     assert_eq!(color.rgb(), "rgb(255, 255, 0)");
 
-    let color = color!(rgba(255, 255, 0, 0.5));
+    let color = color!(rgba(255, 255, 0, 0.5)).unwrap();
     assert_eq!(color.rgba(), "rgba(255, 255, 0, 0.5)");
 }
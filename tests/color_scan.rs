@@ -0,0 +1,65 @@
+use color_art::{replace_colors, scan_colors, Color, ScanOptions};
+use std::str::FromStr;
+
+#[test]
+fn test_scan_css() {
+    let css = r##"
+:root {
+    --brand: #1890FF;
+    --accent: hsl(330 100% 60%);
+}
+.btn:hover {
+    background: linear-gradient(to right, rgba(0, 0, 0, 0.5), transparent);
+    border: 1px solid color(display-p3 1 0 0);
+}
+"##;
+    let matches = scan_colors(css, &ScanOptions::default());
+    let texts: Vec<_> = matches.iter().map(|m| m.text).collect();
+    assert_eq!(
+        texts,
+        [
+            "#1890FF",
+            "hsl(330 100% 60%)",
+            "rgba(0, 0, 0, 0.5)",
+            "color(display-p3 1 0 0)"
+        ]
+    );
+    for m in &matches {
+        assert_eq!(&css[m.span.range()], m.text);
+        assert_eq!(m.color, Color::from_str(m.text).unwrap());
+    }
+}
+
+#[test]
+fn test_scan_svg_and_json() {
+    let options = ScanOptions {
+        names: true,
+        ..Default::default()
+    };
+    let svg = r##"<svg><circle id="red" fill="red" stroke="#000"/><use href="#red"/></svg>"##;
+    let texts: Vec<_> = scan_colors(svg, &options).iter().map(|m| m.text).collect();
+    assert_eq!(texts, ["red", "red", "#000"]);
+
+    let json = r##"{"primary":"#0af","secondary":"rgb(255 0 0)","name":"blue whale"}"##;
+    let texts: Vec<_> = scan_colors(json, &ScanOptions::default())
+        .iter()
+        .map(|m| m.text)
+        .collect();
+    assert_eq!(texts, ["#0af", "rgb(255 0 0)"]);
+}
+
+#[test]
+fn test_replace_palette() {
+    let source = r##"const theme = { primary: "#FF0000", muted: "rgb(0 0 255)", text: "#333" };"##;
+    let output = replace_colors(source, &ScanOptions::default(), |m| {
+        match m.color.hex().as_str() {
+            "#f00" => "#e11d48".to_string(),
+            "#00f" => "#2563eb".to_string(),
+            _ => m.text.to_lowercase(),
+        }
+    });
+    assert_eq!(
+        output,
+        r##"const theme = { primary: "#e11d48", muted: "#2563eb", text: "#333" };"##
+    );
+}
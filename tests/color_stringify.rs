@@ -2,7 +2,7 @@ use color_art::color;
 
 #[test]
 fn test_color_stringify() {
-    let color = color!(#ffff00);
+    let color = color!(#ffff00).unwrap();
 
     assert_eq!(color.hex(), "#ff0");
     assert_eq!(color.hex_full(), "#ffff00");
@@ -2,7 +2,7 @@ use color_art::{color, ColorSpace};
 
 #[test]
 fn test_color_hex() {
-    let color = color!(#ff00ff);
+    let color = color!(#ff00ff).unwrap();
 
     let vec = color.vec_of("hex");
     assert_eq!(vec, vec![255.0, 0.0, 255.0]);